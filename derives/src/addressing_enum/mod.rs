@@ -26,12 +26,18 @@ pub fn parse_variant(input: Variant) -> TokenStream {
             }
 
             impl crate::cpu::instruction::addressing_mode::IntoAddress for #enum_name {
-                fn into_address(&self, cpu: &crate::cpu::Cpu) -> u16 {
+                fn into_address<B: crate::cpu::bus::Bus>(&self, cpu: &mut crate::cpu::Cpu<B>) -> u16 {
                     let result = match self {
                         #( Self::#address_modes {mode} => mode.into_address(cpu), )*
                     };
                     result
                 }
+
+                fn index<B: crate::cpu::bus::Bus>(&self, cpu: &crate::cpu::Cpu<B>) -> Option<u8> {
+                    match self {
+                        #( Self::#address_modes {mode} => mode.index(cpu), )*
+                    }
+                }
             }
         )
     }
@@ -65,7 +71,7 @@ pub fn parse_variant(input: Variant) -> TokenStream {
                         }
 
                         impl crate::cpu::instruction::addressing_mode::IntoValue for #enum_name {
-                            fn into_value(&self, cpu: &crate::cpu::Cpu) -> u8 {
+                            fn into_value<B: crate::cpu::bus::Bus>(&self, cpu: &mut crate::cpu::Cpu<B>) -> u8 {
                                 match self {
                                     Self::#value_variant { mode } => mode.into_value(cpu),
                                     Self::#address_variant { mode } => mode.into_value(cpu),
@@ -90,7 +96,7 @@ pub fn parse_variant(input: Variant) -> TokenStream {
                         }
 
                         impl crate::cpu::instruction::addressing_mode::IntoValue for #enum_name {
-                        fn into_value(&self, cpu: &crate::cpu::Cpu) -> u8 {
+                        fn into_value<B: crate::cpu::bus::Bus>(&self, cpu: &mut crate::cpu::Cpu<B>) -> u8 {
                             let result = match self {
                                 Self::#value_variant { mode } => mode.into_value(cpu),
                                 Self::#address_enum_name { mode } => mode.into_value(cpu),
@@ -114,6 +120,27 @@ pub fn parse_variant(input: Variant) -> TokenStream {
         }
     }
 
+    /// Enums made of the accumulator and only address modes can be the
+    /// operand of a shift or rotate.
+    fn get_target_impl(
+        enum_name: &Ident,
+        implicit_variant: &TokenStream,
+        address_variant: &TokenStream,
+    ) -> TokenStream {
+        quote!(
+            impl crate::cpu::instruction::addressing_mode::IntoTarget for #enum_name {
+                fn into_target<B: crate::cpu::bus::Bus>(&self, cpu: &mut crate::cpu::Cpu<B>) -> crate::cpu::instruction::addressing_mode::Target {
+                    match self {
+                        Self::#implicit_variant { .. } => crate::cpu::instruction::addressing_mode::Target::Accumulator,
+                        Self::#address_variant { mode } => crate::cpu::instruction::addressing_mode::Target::Memory(
+                            crate::cpu::instruction::addressing_mode::IntoAddress::into_shift_address(mode, cpu),
+                        ),
+                    }
+                }
+            }
+        )
+    }
+
     fn get_enum(name: String, modes: HashSet<AddressingMode>) -> TokenStream {
         let implicit_mode = modes
             .iter()
@@ -129,23 +156,38 @@ pub fn parse_variant(input: Variant) -> TokenStream {
 
         if let Some(implicit_mode) = implicit_mode {
             let implicit_variant: TokenStream = implicit_mode.into();
+            let has_immediate = value_modes
+                .iter()
+                .any(|mode| Immediate::try_from(*mode).is_ok());
             if value_modes.len() == 1 {
                 let value_variant: TokenStream = match value_modes[0] {
                     ValueAddressingMode::Immediate(immediate) => immediate.into(),
                     ValueAddressingMode::AddressAddressingMode(mode) => mode.into(),
                 };
+                let target_impl = if has_immediate {
+                    quote!()
+                } else {
+                    get_target_impl(&enum_name, &implicit_variant, &value_variant)
+                };
                 quote!(
                     #[derive(Debug, Copy, Clone)]
                     pub enum #enum_name {
                         #implicit_variant { mode: crate::cpu::instruction::addressing_mode::#implicit_variant },
                         #value_variant { mode: crate::cpu::instruction::addressing_mode::#value_variant },
                     }
+
+                    #target_impl
                 )
             } else {
                 let value_enum_name = format_ident!("{}ValueAddressingMode", name);
                 let address_enum_name = format_ident!("{}AddressAddressingMode", name);
                 let (value_enum, value_enum_name) =
                     get_value_enum(&value_enum_name, &address_enum_name, true, value_modes);
+                let target_impl = if has_immediate {
+                    quote!()
+                } else {
+                    get_target_impl(&enum_name, &implicit_variant, &quote!(#value_enum_name))
+                };
 
                 quote!(
                     #value_enum
@@ -155,6 +197,8 @@ pub fn parse_variant(input: Variant) -> TokenStream {
                         #implicit_variant { mode: crate::cpu::instruction::addressing_mode::#implicit_variant },
                         #value_enum_name { mode: #value_enum_name }
                     }
+
+                    #target_impl
                 )
             }
         } else {
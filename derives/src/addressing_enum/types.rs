@@ -30,6 +30,8 @@ pub enum AddressAddressingMode {
     Indirect,
     IndirectX,
     IndirectY,
+    ZeroPageIndirect,
+    AbsoluteXIndirect,
 }
 
 /// From AddressingMode into Accumulator and ValueAddressingMode and viceversa
@@ -120,7 +122,9 @@ impl TryFrom<String> for AddressingMode {
             "indirect" => Ok(VAD(AAD(Indirect))),
             "indirect_x" => Ok(VAD(AAD(IndirectX))),
             "indirect_y" => Ok(VAD(AAD(IndirectY))),
-            _ => Err("Invalid type; expected accumulator, immediate, zero_page, zero_page_x, zero_page_y, relative, absolute, absolute_x, absolute_y, indirect, indirect_x or indirect_y"),
+            "zero_page_indirect" => Ok(VAD(AAD(ZeroPageIndirect))),
+            "absolute_x_indirect" => Ok(VAD(AAD(AbsoluteXIndirect))),
+            _ => Err("Invalid type; expected accumulator, immediate, zero_page, zero_page_x, zero_page_y, relative, absolute, absolute_x, absolute_y, indirect, indirect_x, indirect_y, zero_page_indirect or absolute_x_indirect"),
         }
     }
 }
@@ -161,6 +165,8 @@ impl From<AddressAddressingMode> for proc_macro2::TokenStream {
             }
             IndirectX => quote!(IndirectX),
             IndirectY => quote!(IndirectY),
+            ZeroPageIndirect => quote!(ZeroPageIndirect),
+            AbsoluteXIndirect => quote!(AbsoluteXIndirect),
         }
     }
 }
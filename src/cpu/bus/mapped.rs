@@ -0,0 +1,159 @@
+use std::ops::RangeInclusive;
+
+use super::Bus;
+use crate::cpu::memory::RamInit;
+
+/// Hardware mounted on a `MappedBus`.
+pub trait Device {
+    fn read(&mut self, address: u16) -> u8;
+
+    fn write(&mut self, address: u16, value: u8);
+
+    /// Reads without side effects, like `Bus::peek`. Devices that can't
+    /// do it read as 0 when peeked, which only shows while debugging since
+    /// the CPU always reads.
+    fn peek(&self, _address: u16) -> Option<u8> {
+        None
+    }
+}
+
+/// A device made of a pair of closures, for mounting fake peripherals
+/// without declaring a type for them.
+pub struct Callbacks<R, W> {
+    pub read: R,
+    pub write: W,
+}
+
+impl<R: FnMut(u16) -> u8, W: FnMut(u16, u8)> Device for Callbacks<R, W> {
+    fn read(&mut self, address: u16) -> u8 {
+        (self.read)(address)
+    }
+
+    fn write(&mut self, address: u16, value: u8) {
+        (self.write)(address, value)
+    }
+}
+
+/// Where a device is mounted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceMapping {
+    pub range: RangeInclusive<u16>,
+    /// Where ranges overlap, the device with the highest priority gets the
+    /// access. On a tie, the one mounted last does.
+    pub priority: i32,
+    /// Applied to addresses before they reach the device, so a few
+    /// registers can be mirrored over the whole range.
+    pub mask: u16,
+}
+
+impl DeviceMapping {
+    pub fn new(range: RangeInclusive<u16>) -> DeviceMapping {
+        DeviceMapping {
+            range,
+            priority: 0,
+            mask: 0xffff,
+        }
+    }
+}
+
+struct Mounted {
+    mapping: DeviceMapping,
+    device: Box<dyn Device>,
+}
+
+/// A bus with devices mounted over another one, which gets every access
+/// no device claims.
+pub struct MappedBus<B> {
+    inner: B,
+    /// Sorted from highest to lowest priority.
+    devices: Vec<Mounted>,
+}
+
+impl<B: std::fmt::Debug> std::fmt::Debug for MappedBus<B> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mappings: Vec<_> = self
+            .devices
+            .iter()
+            .map(|mounted| &mounted.mapping)
+            .collect();
+        f.debug_struct("MappedBus")
+            .field("inner", &self.inner)
+            .field("devices", &mappings)
+            .finish()
+    }
+}
+
+impl<B: Bus> MappedBus<B> {
+    pub fn new(inner: B) -> MappedBus<B> {
+        MappedBus {
+            inner,
+            devices: Vec::new(),
+        }
+    }
+
+    pub fn mount(&mut self, mapping: DeviceMapping, device: impl Device + 'static) {
+        let index = self
+            .devices
+            .partition_point(|mounted| mounted.mapping.priority > mapping.priority);
+        self.devices.insert(
+            index,
+            Mounted {
+                mapping,
+                device: Box::new(device),
+            },
+        );
+    }
+
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    pub fn inner_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    fn device_index(&self, address: u16) -> Option<usize> {
+        self.devices
+            .iter()
+            .position(|mounted| mounted.mapping.range.contains(&address))
+    }
+}
+
+impl<B: Bus> Bus for MappedBus<B> {
+    fn read(&mut self, address: u16) -> u8 {
+        match self.device_index(address) {
+            Some(index) => {
+                let mounted = &mut self.devices[index];
+                mounted.device.read(address & mounted.mapping.mask)
+            }
+            None => self.inner.read(address),
+        }
+    }
+
+    fn write(&mut self, address: u16, value: u8) {
+        match self.device_index(address) {
+            Some(index) => {
+                let mounted = &mut self.devices[index];
+                mounted.device.write(address & mounted.mapping.mask, value)
+            }
+            None => self.inner.write(address, value),
+        }
+    }
+
+    fn peek(&self, address: u16) -> u8 {
+        match self.device_index(address) {
+            Some(index) => {
+                let mounted = &self.devices[index];
+                mounted
+                    .device
+                    .peek(address & mounted.mapping.mask)
+                    .unwrap_or(0)
+            }
+            None => self.inner.peek(address),
+        }
+    }
+
+    fn power_on(&mut self, init: RamInit) {
+        self.inner.power_on(init)
+    }
+}
@@ -0,0 +1,22 @@
+use super::memory::RamInit;
+
+pub mod mapped;
+
+#[cfg(test)]
+mod tests;
+
+/// Everything the CPU is connected to. Reads take `&mut self` because on
+/// real hardware they can have side effects, like acknowledging a flag or
+/// advancing a shift register.
+pub trait Bus {
+    fn read(&mut self, address: u16) -> u8;
+
+    fn write(&mut self, address: u16, value: u8);
+
+    /// Reads without side effects, to inspect the machine while debugging.
+    /// The CPU itself only ever uses `read`.
+    fn peek(&self, address: u16) -> u8;
+
+    /// Puts whatever the bus holds in its power-on state.
+    fn power_on(&mut self, _init: RamInit) {}
+}
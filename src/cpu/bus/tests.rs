@@ -0,0 +1,158 @@
+use std::{cell::RefCell, rc::Rc};
+
+use super::{mapped::*, *};
+use crate::cpu::{
+    instruction::opcodes::{LDA_ABSOLUTE, LDA_IMMEDIATE, STA_ABSOLUTE},
+    memory::Memory,
+    Cpu, CpuConfig,
+};
+
+type Writes = Rc<RefCell<Vec<(u16, u8)>>>;
+
+/// Device that reads as a fixed value and records what is written to it.
+struct Port {
+    value: u8,
+    writes: Writes,
+}
+
+impl Device for Port {
+    fn read(&mut self, _address: u16) -> u8 {
+        self.value
+    }
+
+    fn write(&mut self, address: u16, value: u8) {
+        self.writes.borrow_mut().push((address, value));
+    }
+
+    fn peek(&self, _address: u16) -> Option<u8> {
+        Some(self.value)
+    }
+}
+
+fn port(value: u8) -> (Port, Writes) {
+    let writes = Rc::new(RefCell::new(Vec::new()));
+    let port = Port {
+        value,
+        writes: writes.clone(),
+    };
+    (port, writes)
+}
+
+#[test]
+fn mount() {
+    let mut bus = MappedBus::new(Memory::new());
+    let (port, writes) = port(0x42);
+    bus.mount(DeviceMapping::new(0x6000..=0x6001), port);
+
+    assert_eq!(bus.read(0x6000), 0x42);
+    assert_eq!(bus.peek(0x6001), 0x42);
+    bus.write(0x6001, 0x01);
+    assert_eq!(*writes.borrow(), [(0x6001, 0x01)]);
+    assert_eq!(bus.inner().read(0x6001), 0x00);
+
+    bus.write(0x6002, 0x02);
+    assert_eq!(bus.read(0x6002), 0x02);
+}
+
+#[test]
+fn mount_priority() {
+    let mut bus = MappedBus::new(Memory::new());
+    bus.mount(
+        DeviceMapping {
+            priority: 1,
+            ..DeviceMapping::new(0x6000..=0x60ff)
+        },
+        port(0x01).0,
+    );
+    bus.mount(DeviceMapping::new(0x6000..=0x6fff), port(0x02).0);
+    bus.mount(DeviceMapping::new(0x6000..=0x6fff), port(0x03).0);
+
+    assert_eq!(bus.read(0x6080), 0x01);
+    assert_eq!(bus.read(0x6100), 0x03);
+}
+
+#[test]
+fn mount_mask() {
+    let mut bus = MappedBus::new(Memory::new());
+    let (port, writes) = port(0x00);
+    bus.mount(
+        DeviceMapping {
+            mask: 0x2007,
+            ..DeviceMapping::new(0x2000..=0x3fff)
+        },
+        port,
+    );
+
+    bus.write(0x2009, 0x01);
+    bus.write(0x3fff, 0x02);
+    assert_eq!(*writes.borrow(), [(0x2001, 0x01), (0x2007, 0x02)]);
+}
+
+#[test]
+fn mount_callbacks() {
+    let reads = Rc::new(RefCell::new(0));
+    let written = Rc::new(RefCell::new(None));
+    let mut bus = MappedBus::new(Memory::new());
+    bus.mount(DeviceMapping::new(0x6000..=0x6000), {
+        let reads = reads.clone();
+        let written = written.clone();
+        Callbacks {
+            read: move |_| {
+                *reads.borrow_mut() += 1;
+                0x42
+            },
+            write: move |_, value| *written.borrow_mut() = Some(value),
+        }
+    });
+
+    // Peeking can't call the read callback, which may have side effects.
+    bus.peek(0x6000);
+    assert_eq!(*reads.borrow(), 0);
+    assert_eq!(bus.read(0x6000), 0x42);
+    assert_eq!(*reads.borrow(), 1);
+    bus.write(0x6000, 0x01);
+    assert_eq!(*written.borrow(), Some(0x01));
+}
+
+#[test]
+fn mount_cpu() {
+    let mut bus = MappedBus::new(Memory::new());
+    bus.inner_mut()
+        .load(
+            0x8000,
+            &[LDA_ABSOLUTE, 0x00, 0x60, STA_ABSOLUTE, 0x01, 0x60],
+        )
+        .unwrap();
+    let (port, writes) = port(0x42);
+    bus.mount(DeviceMapping::new(0x6000..=0x6001), port);
+
+    let mut cpu = Cpu::with_bus(bus, CpuConfig::default());
+    cpu.program_counter = 0x8000;
+    cpu.step().unwrap();
+    cpu.step().unwrap();
+    assert_eq!(cpu.register_a, 0x42);
+    assert_eq!(*writes.borrow(), [(0x6001, 0x42)]);
+}
+
+#[test]
+fn mount_cpu_code() {
+    let program = [LDA_IMMEDIATE, 0x42];
+    let mut bus = MappedBus::new(Memory::new());
+    bus.mount(
+        DeviceMapping::new(0x9000..=0x9fff),
+        Callbacks {
+            read: move |address: u16| program.get(address as usize - 0x9000).copied().unwrap_or(0),
+            write: |_, _| {},
+        },
+    );
+
+    let mut cpu = Cpu::with_bus(bus, CpuConfig::default());
+    cpu.program_counter = 0x9000;
+    let step = cpu.step().unwrap();
+    assert!(matches!(
+        step.executed,
+        crate::cpu::Executed::Instruction(crate::cpu::instruction::Instruction::Ld { .. })
+    ));
+    assert_eq!(cpu.register_a, 0x42);
+    assert_eq!(cpu.program_counter, 0x9002);
+}
@@ -1,21 +1,89 @@
-use crate::cpu::{memory::Memory, Cpu};
+use crate::cpu::{bus::Bus, trace::TraceEvent, Cpu, Variant};
 
+#[allow(clippy::wrong_self_convention)]
 pub trait IntoAddress {
-    fn into_address(&self, cpu: &Cpu) -> u16;
+    fn into_address<B: Bus>(&self, cpu: &mut Cpu<B>) -> u16;
+
+    /// Index added to a 16-bit base address. The CPU adds it to the low byte
+    /// first and spends an extra cycle fixing the high byte, reading from
+    /// the unfixed address meanwhile.
+    fn index<B: Bus>(&self, _cpu: &Cpu<B>) -> Option<u8> {
+        None
+    }
+
+    /// Address for a write or a read-modify-write, which always spend the
+    /// fix-up cycle of indexed modes since they can't undo a wrong write.
+    fn into_write_address<B: Bus>(&self, cpu: &mut Cpu<B>) -> u16 {
+        let address = self.into_address(cpu);
+        if let Some(index) = self.index(cpu) {
+            cpu.dummy_read(unfixed_address(address, index));
+        }
+        address
+    }
+
+    /// Address for a shift or rotate. The 65C02 only spends the fix-up
+    /// cycle on them when the page changes, like on reads.
+    fn into_shift_address<B: Bus>(&self, cpu: &mut Cpu<B>) -> u16 {
+        if cpu.config.variant != Variant::Cmos65C02 {
+            return self.into_write_address(cpu);
+        }
+        let address = self.into_address(cpu);
+        fix_page(cpu, address, self.index(cpu));
+        address
+    }
+}
+
+/// Reads a little endian operand with `fetch`.
+fn fetch_u16(fetch: &mut impl FnMut(u16) -> u8, address: u16) -> u16 {
+    let lo = fetch(address) as u16;
+    let hi = fetch(address.wrapping_add(1)) as u16;
+    (hi << 8) | lo
+}
+
+/// Address read while the high byte of an indexed address is being fixed.
+fn unfixed_address(address: u16, index: u8) -> u16 {
+    let base = address.wrapping_sub(index as u16);
+    (base & 0xff00) | (address & 0x00ff)
 }
 
+/// Spends the fix-up cycle if indexing crossed a page.
+fn fix_page<B: Bus>(cpu: &mut Cpu<B>, address: u16, index: Option<u8>) {
+    if let Some(index) = index {
+        let unfixed = unfixed_address(address, index);
+        if unfixed != address {
+            cpu.cycles += 1;
+            cpu.dummy_read(unfixed);
+        }
+    }
+}
+
+#[allow(clippy::wrong_self_convention)]
 pub trait IntoValue {
-    fn into_value(&self, cpu: &Cpu) -> u8;
+    fn into_value<B: Bus>(&self, cpu: &mut Cpu<B>) -> u8;
 }
 
 impl<T: IntoAddress> IntoValue for T {
-    fn into_value(&self, cpu: &Cpu) -> u8 {
+    fn into_value<B: Bus>(&self, cpu: &mut Cpu<B>) -> u8 {
         let address = self.into_address(cpu);
-        let result = cpu.memory.read(address);
-        result
+        fix_page(cpu, address, self.index(cpu));
+        let value = cpu.read(address);
+        cpu.trace(TraceEvent::Operand { value });
+        value
     }
 }
 
+/// Operand of a read-modify-write instruction.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Target {
+    Accumulator,
+    Memory(u16),
+}
+
+#[allow(clippy::wrong_self_convention)]
+pub trait IntoTarget {
+    fn into_target<B: Bus>(&self, cpu: &mut Cpu<B>) -> Target;
+}
+
 #[derive(Debug, Copy, Clone)]
 pub struct Implicit {}
 
@@ -28,15 +96,18 @@ pub struct Immediate {
 }
 
 impl Immediate {
-    pub fn new(memory: &Memory, program_counter: &mut u16) -> Immediate {
-        let immediate = memory.read(*program_counter);
-        *program_counter += 1;
+    pub fn new(fetch: &mut impl FnMut(u16) -> u8, program_counter: &mut u16) -> Immediate {
+        let immediate = fetch(*program_counter);
+        *program_counter = program_counter.wrapping_add(1);
         Immediate { immediate }
     }
 }
 
 impl IntoValue for Immediate {
-    fn into_value(&self, _cpu: &Cpu) -> u8 {
+    fn into_value<B: Bus>(&self, cpu: &mut Cpu<B>) -> u8 {
+        cpu.trace(TraceEvent::Operand {
+            value: self.immediate,
+        });
         self.immediate
     }
 }
@@ -47,15 +118,15 @@ pub struct ZeroPage {
 }
 
 impl ZeroPage {
-    pub fn new(memory: &Memory, program_counter: &mut u16) -> ZeroPage {
-        let address = memory.read(*program_counter);
-        *program_counter += 1;
+    pub fn new(fetch: &mut impl FnMut(u16) -> u8, program_counter: &mut u16) -> ZeroPage {
+        let address = fetch(*program_counter);
+        *program_counter = program_counter.wrapping_add(1);
         ZeroPage { address }
     }
 }
 
 impl IntoAddress for ZeroPage {
-    fn into_address(&self, _cpu: &Cpu) -> u16 {
+    fn into_address<B: Bus>(&self, _cpu: &mut Cpu<B>) -> u16 {
         self.address as u16
     }
 }
@@ -66,15 +137,17 @@ pub struct ZeroPageX {
 }
 
 impl ZeroPageX {
-    pub fn new(memory: &Memory, program_counter: &mut u16) -> ZeroPageX {
-        let address: u8 = memory.read(*program_counter);
-        *program_counter += 1;
+    pub fn new(fetch: &mut impl FnMut(u16) -> u8, program_counter: &mut u16) -> ZeroPageX {
+        let address: u8 = fetch(*program_counter);
+        *program_counter = program_counter.wrapping_add(1);
         ZeroPageX { address }
     }
 }
 
 impl IntoAddress for ZeroPageX {
-    fn into_address(&self, cpu: &Cpu) -> u16 {
+    fn into_address<B: Bus>(&self, cpu: &mut Cpu<B>) -> u16 {
+        // The base address is read while the index is being added.
+        cpu.dummy_read(self.address as u16);
         let address = self.address.wrapping_add(cpu.register_x);
         address as u16
     }
@@ -86,15 +159,16 @@ pub struct ZeroPageY {
 }
 
 impl ZeroPageY {
-    pub fn new(memory: &Memory, program_counter: &mut u16) -> ZeroPageY {
-        let address: u8 = memory.read(*program_counter);
-        *program_counter += 1;
+    pub fn new(fetch: &mut impl FnMut(u16) -> u8, program_counter: &mut u16) -> ZeroPageY {
+        let address: u8 = fetch(*program_counter);
+        *program_counter = program_counter.wrapping_add(1);
         ZeroPageY { address }
     }
 }
 
 impl IntoAddress for ZeroPageY {
-    fn into_address(&self, cpu: &Cpu) -> u16 {
+    fn into_address<B: Bus>(&self, cpu: &mut Cpu<B>) -> u16 {
+        cpu.dummy_read(self.address as u16);
         let address = self.address.wrapping_add(cpu.register_y);
         address as u16
     }
@@ -106,16 +180,16 @@ pub struct Relative {
 }
 
 impl Relative {
-    pub fn new(memory: &Memory, program_counter: &mut u16) -> Relative {
-        let offset = memory.read(*program_counter) as i8;
-        *program_counter += 1;
+    pub fn new(fetch: &mut impl FnMut(u16) -> u8, program_counter: &mut u16) -> Relative {
+        let offset = fetch(*program_counter) as i8;
+        *program_counter = program_counter.wrapping_add(1);
         Relative { offset }
     }
 }
 
 impl IntoAddress for Relative {
-    fn into_address(&self, cpu: &Cpu) -> u16 {
-        cpu.program_counter.wrapping_add(self.offset as i8 as u16)
+    fn into_address<B: Bus>(&self, cpu: &mut Cpu<B>) -> u16 {
+        cpu.program_counter.wrapping_add(self.offset as u16)
     }
 }
 
@@ -125,15 +199,15 @@ pub struct Absolute {
 }
 
 impl Absolute {
-    pub fn new(memory: &Memory, program_counter: &mut u16) -> Absolute {
-        let address = memory.read_u16(*program_counter);
-        *program_counter += 2;
+    pub fn new(fetch: &mut impl FnMut(u16) -> u8, program_counter: &mut u16) -> Absolute {
+        let address = fetch_u16(fetch, *program_counter);
+        *program_counter = program_counter.wrapping_add(2);
         Absolute { address }
     }
 }
 
 impl IntoAddress for Absolute {
-    fn into_address(&self, _cpu: &Cpu) -> u16 {
+    fn into_address<B: Bus>(&self, _cpu: &mut Cpu<B>) -> u16 {
         self.address
     }
 }
@@ -144,17 +218,20 @@ pub struct AbsoluteX {
 }
 
 impl AbsoluteX {
-    pub fn new(memory: &Memory, program_counter: &mut u16) -> AbsoluteX {
-        let address = memory.read_u16(*program_counter);
-        *program_counter += 2;
+    pub fn new(fetch: &mut impl FnMut(u16) -> u8, program_counter: &mut u16) -> AbsoluteX {
+        let address = fetch_u16(fetch, *program_counter);
+        *program_counter = program_counter.wrapping_add(2);
         AbsoluteX { address }
     }
 }
 
 impl IntoAddress for AbsoluteX {
-    fn into_address(&self, cpu: &Cpu) -> u16 {
-        let address = self.address.wrapping_add(cpu.register_x as u16);
-        address
+    fn into_address<B: Bus>(&self, cpu: &mut Cpu<B>) -> u16 {
+        self.address.wrapping_add(cpu.register_x as u16)
+    }
+
+    fn index<B: Bus>(&self, cpu: &Cpu<B>) -> Option<u8> {
+        Some(cpu.register_x)
     }
 }
 
@@ -164,40 +241,50 @@ pub struct AbsoluteY {
 }
 
 impl AbsoluteY {
-    pub fn new(memory: &Memory, program_counter: &mut u16) -> AbsoluteY {
-        let address = memory.read_u16(*program_counter);
-        *program_counter += 2;
+    pub fn new(fetch: &mut impl FnMut(u16) -> u8, program_counter: &mut u16) -> AbsoluteY {
+        let address = fetch_u16(fetch, *program_counter);
+        *program_counter = program_counter.wrapping_add(2);
         AbsoluteY { address }
     }
 }
 
 impl IntoAddress for AbsoluteY {
-    fn into_address(&self, cpu: &Cpu) -> u16 {
-        let address = self.address.wrapping_add(cpu.register_y as u16);
-        address
+    fn into_address<B: Bus>(&self, cpu: &mut Cpu<B>) -> u16 {
+        self.address.wrapping_add(cpu.register_y as u16)
+    }
+
+    fn index<B: Bus>(&self, cpu: &Cpu<B>) -> Option<u8> {
+        Some(cpu.register_y)
     }
 }
 
 #[derive(Debug, Copy, Clone)]
 pub struct Indirect {
-    pub address: u8,
+    pub address: u16,
 }
 
 impl Indirect {
-    pub fn new(memory: &Memory, program_counter: &mut u16) -> Indirect {
-        let address = memory.read(*program_counter);
-        *program_counter += 1;
+    pub fn new(fetch: &mut impl FnMut(u16) -> u8, program_counter: &mut u16) -> Indirect {
+        let address = fetch_u16(fetch, *program_counter);
+        *program_counter = program_counter.wrapping_add(2);
         Indirect { address }
     }
 }
 
 impl IntoAddress for Indirect {
-    fn into_address(&self, cpu: &Cpu) -> u16 {
+    /// The NMOS 6502 doesn't carry into the high byte when fetching the
+    /// pointer, so a pointer at $xxFF reads its high byte from $xx00. The
+    /// 65C02 fixes it at the cost of an extra cycle.
+    fn into_address<B: Bus>(&self, cpu: &mut Cpu<B>) -> u16 {
         let base = self.address;
-        let lo = cpu.memory.read(base as u16);
-        let hi = cpu.memory.read(base.wrapping_add(1) as u16);
-        let address = (hi as u16) << 8 | lo as u16;
-        address
+        let lo = cpu.read(base);
+        let hi = if cpu.config.variant == Variant::Cmos65C02 {
+            cpu.dummy_read((base & 0xff00) | (base.wrapping_add(1) & 0x00ff));
+            cpu.read(base.wrapping_add(1))
+        } else {
+            cpu.read((base & 0xff00) | (base.wrapping_add(1) & 0x00ff))
+        };
+        (hi as u16) << 8 | lo as u16
     }
 }
 
@@ -207,20 +294,20 @@ pub struct IndirectX {
 }
 
 impl IndirectX {
-    pub fn new(memory: &Memory, program_counter: &mut u16) -> IndirectX {
-        let address = memory.read(*program_counter);
-        *program_counter += 1;
+    pub fn new(fetch: &mut impl FnMut(u16) -> u8, program_counter: &mut u16) -> IndirectX {
+        let address = fetch(*program_counter);
+        *program_counter = program_counter.wrapping_add(1);
         IndirectX { address }
     }
 }
 
 impl IntoAddress for IndirectX {
-    fn into_address(&self, cpu: &Cpu) -> u16 {
+    fn into_address<B: Bus>(&self, cpu: &mut Cpu<B>) -> u16 {
+        cpu.dummy_read(self.address as u16);
         let base = self.address.wrapping_add(cpu.register_x);
-        let lo = cpu.memory.read(base as u16);
-        let hi = cpu.memory.read(base.wrapping_add(1) as u16);
-        let address = (hi as u16) << 8 | lo as u16;
-        address
+        let lo = cpu.read(base as u16);
+        let hi = cpu.read(base.wrapping_add(1) as u16);
+        (hi as u16) << 8 | lo as u16
     }
 }
 
@@ -230,19 +317,70 @@ pub struct IndirectY {
 }
 
 impl IndirectY {
-    pub fn new(memory: &Memory, program_counter: &mut u16) -> IndirectY {
-        let address = memory.read(*program_counter);
-        *program_counter += 1;
+    pub fn new(fetch: &mut impl FnMut(u16) -> u8, program_counter: &mut u16) -> IndirectY {
+        let address = fetch(*program_counter);
+        *program_counter = program_counter.wrapping_add(1);
         IndirectY { address }
     }
 }
 
 impl IntoAddress for IndirectY {
-    fn into_address(&self, cpu: &Cpu) -> u16 {
-        let lo = cpu.memory.read(self.address as u16);
-        let hi = cpu.memory.read(self.address.wrapping_add(1) as u16);
+    fn into_address<B: Bus>(&self, cpu: &mut Cpu<B>) -> u16 {
+        let lo = cpu.read(self.address as u16);
+        let hi = cpu.read(self.address.wrapping_add(1) as u16);
         let address = (hi as u16) << 8 | lo as u16;
-        let address = address.wrapping_add(cpu.register_y as u16);
-        address
+        address.wrapping_add(cpu.register_y as u16)
+    }
+
+    fn index<B: Bus>(&self, cpu: &Cpu<B>) -> Option<u8> {
+        Some(cpu.register_y)
+    }
+}
+
+/// The 65C02 `(zp)` mode, like `IndirectY` without the index.
+#[derive(Debug, Copy, Clone)]
+pub struct ZeroPageIndirect {
+    pub address: u8,
+}
+
+impl ZeroPageIndirect {
+    pub fn new(fetch: &mut impl FnMut(u16) -> u8, program_counter: &mut u16) -> ZeroPageIndirect {
+        let address = fetch(*program_counter);
+        *program_counter = program_counter.wrapping_add(1);
+        ZeroPageIndirect { address }
+    }
+}
+
+impl IntoAddress for ZeroPageIndirect {
+    fn into_address<B: Bus>(&self, cpu: &mut Cpu<B>) -> u16 {
+        let lo = cpu.read(self.address as u16);
+        let hi = cpu.read(self.address.wrapping_add(1) as u16);
+        (hi as u16) << 8 | lo as u16
+    }
+}
+
+/// The 65C02 `(abs,X)` mode of JMP, which indexes the pointer instead of
+/// the address it points to.
+#[derive(Debug, Copy, Clone)]
+pub struct AbsoluteXIndirect {
+    pub address: u16,
+}
+
+impl AbsoluteXIndirect {
+    pub fn new(fetch: &mut impl FnMut(u16) -> u8, program_counter: &mut u16) -> AbsoluteXIndirect {
+        let address = fetch_u16(fetch, *program_counter);
+        *program_counter = program_counter.wrapping_add(2);
+        AbsoluteXIndirect { address }
+    }
+}
+
+impl IntoAddress for AbsoluteXIndirect {
+    fn into_address<B: Bus>(&self, cpu: &mut Cpu<B>) -> u16 {
+        // The high byte of the operand is read again while X is added.
+        cpu.dummy_read(cpu.program_counter.wrapping_sub(1));
+        let pointer = self.address.wrapping_add(cpu.register_x as u16);
+        let lo = cpu.read(pointer);
+        let hi = cpu.read(pointer.wrapping_add(1));
+        (hi as u16) << 8 | lo as u16
     }
 }
@@ -1,8 +1,4 @@
-use super::{
-    memory::{CpuMemoryError, Memory},
-    status::Flag,
-    Register,
-};
+use super::{bus::Bus, memory::CpuMemoryError, status::Flag, CpuConfig, Register, Variant};
 
 use derives::AddressingEnum;
 use thiserror::Error;
@@ -15,7 +11,7 @@ mod tests;
 pub mod addressing_mode;
 use addressing_mode as AM;
 
-#[derive(Debug, AddressingEnum)]
+#[derive(Debug, Clone, Copy, AddressingEnum)]
 pub enum Instruction {
     Break,
     #[modes(
@@ -27,7 +23,8 @@ pub enum Instruction {
         mode = "absolute_x",
         mode = "absolute_y",
         mode = "indirect_x",
-        mode = "indirect_y"
+        mode = "indirect_y",
+        mode = "zero_page_indirect"
     )]
     Adc {
         addressing_mode: AdcAddressingMode,
@@ -40,7 +37,8 @@ pub enum Instruction {
         mode = "absolute_x",
         mode = "absolute_y",
         mode = "indirect_x",
-        mode = "indirect_y"
+        mode = "indirect_y",
+        mode = "zero_page_indirect"
     )]
     And {
         addressing_mode: AndAddressingMode,
@@ -60,7 +58,12 @@ pub enum Instruction {
         flag: Flag,
         branch_if: bool,
     },
-    #[modes(mode = "zero_page", mode = "absolute")]
+    #[modes(
+        mode = "zero_page",
+        mode = "zero_page_x",
+        mode = "absolute",
+        mode = "absolute_x"
+    )]
     Bit {
         addressing_mode: BitAddressingMode,
     },
@@ -76,7 +79,8 @@ pub enum Instruction {
         mode = "absolute_x",
         mode = "absolute_y",
         mode = "indirect_x",
-        mode = "indirect_y"
+        mode = "indirect_y",
+        mode = "zero_page_indirect"
     )]
     Cmp {
         addressing_mode: CmpAddressingMode,
@@ -98,6 +102,36 @@ pub enum Instruction {
     Dec {
         addressing_mode: DecAddressingMode,
     },
+    #[modes(
+        mode = "immediate",
+        mode = "zero_page",
+        mode = "zero_page_x",
+        mode = "absolute",
+        mode = "absolute_x",
+        mode = "absolute_y",
+        mode = "indirect_x",
+        mode = "indirect_y",
+        mode = "zero_page_indirect"
+    )]
+    Eor {
+        addressing_mode: EorAddressingMode,
+    },
+    #[modes(
+        mode = "zero_page",
+        mode = "zero_page_x",
+        mode = "absolute",
+        mode = "absolute_x"
+    )]
+    Inc {
+        addressing_mode: IncAddressingMode,
+    },
+    #[modes(mode = "absolute", mode = "indirect", mode = "absolute_x_indirect")]
+    Jmp {
+        addressing_mode: JmpAddressingMode,
+    },
+    Jsr {
+        addressing_mode: AM::Absolute,
+    },
     #[modes(
         mode = "immediate",
         mode = "zero_page",
@@ -107,12 +141,100 @@ pub enum Instruction {
         mode = "absolute_x",
         mode = "absolute_y",
         mode = "indirect_x",
-        mode = "indirect_y"
+        mode = "indirect_y",
+        mode = "zero_page_indirect"
     )]
     Ld {
         destination: Register,
         addressing_mode: LdAddressingMode,
     },
+    #[modes(
+        mode = "accumulator",
+        mode = "zero_page",
+        mode = "zero_page_x",
+        mode = "absolute",
+        mode = "absolute_x"
+    )]
+    Lsr {
+        addressing_mode: LsrAddressingMode,
+    },
+    #[modes(
+        mode = "zero_page",
+        mode = "zero_page_x",
+        mode = "zero_page_y",
+        mode = "absolute",
+        mode = "absolute_x",
+        mode = "absolute_y",
+        mode = "indirect_x",
+        mode = "indirect_y",
+        mode = "zero_page_indirect"
+    )]
+    St {
+        origin: Register,
+        addressing_mode: StAddressingMode,
+    },
+    #[modes(
+        mode = "immediate",
+        mode = "zero_page",
+        mode = "zero_page_x",
+        mode = "absolute",
+        mode = "absolute_x",
+        mode = "absolute_y",
+        mode = "indirect_x",
+        mode = "indirect_y",
+        mode = "zero_page_indirect"
+    )]
+    Ora {
+        addressing_mode: OraAddressingMode,
+    },
+    Push {
+        origin: Register,
+    },
+    PushStatus,
+    Pull {
+        destination: Register,
+    },
+    PullStatus,
+    #[modes(
+        mode = "accumulator",
+        mode = "zero_page",
+        mode = "zero_page_x",
+        mode = "absolute",
+        mode = "absolute_x"
+    )]
+    Rol {
+        addressing_mode: RolAddressingMode,
+    },
+    #[modes(
+        mode = "accumulator",
+        mode = "zero_page",
+        mode = "zero_page_x",
+        mode = "absolute",
+        mode = "absolute_x"
+    )]
+    Ror {
+        addressing_mode: RorAddressingMode,
+    },
+    Rti,
+    Rts,
+    #[modes(
+        mode = "immediate",
+        mode = "zero_page",
+        mode = "zero_page_x",
+        mode = "zero_page_y",
+        mode = "absolute",
+        mode = "absolute_x",
+        mode = "absolute_y",
+        mode = "indirect_x",
+        mode = "indirect_y",
+        mode = "zero_page_indirect"
+    )]
+    Sbc {
+        addressing_mode: SbcAddressingMode,
+    },
+    Set {
+        flag: Flag,
+    },
     Trr {
         origin: Register,
         destination: Register,
@@ -120,6 +242,150 @@ pub enum Instruction {
     In {
         destination: Register,
     },
+    De {
+        destination: Register,
+    },
+    Nop,
+    /// Unofficial opcodes that lock the CPU up until it is reset.
+    Jam,
+    /// Unofficial NOP that reads its operand and ignores it.
+    #[modes(
+        mode = "immediate",
+        mode = "zero_page",
+        mode = "zero_page_x",
+        mode = "absolute",
+        mode = "absolute_x"
+    )]
+    Ign {
+        addressing_mode: IgnAddressingMode,
+    },
+    #[modes(
+        mode = "zero_page",
+        mode = "zero_page_y",
+        mode = "absolute",
+        mode = "absolute_y",
+        mode = "indirect_x",
+        mode = "indirect_y"
+    )]
+    Lax {
+        addressing_mode: LaxAddressingMode,
+    },
+    #[modes(
+        mode = "zero_page",
+        mode = "zero_page_y",
+        mode = "absolute",
+        mode = "indirect_x"
+    )]
+    Sax {
+        addressing_mode: SaxAddressingMode,
+    },
+    #[modes(
+        mode = "zero_page",
+        mode = "zero_page_x",
+        mode = "absolute",
+        mode = "absolute_x",
+        mode = "absolute_y",
+        mode = "indirect_x",
+        mode = "indirect_y"
+    )]
+    Dcp {
+        addressing_mode: DcpAddressingMode,
+    },
+    #[modes(
+        mode = "zero_page",
+        mode = "zero_page_x",
+        mode = "absolute",
+        mode = "absolute_x",
+        mode = "absolute_y",
+        mode = "indirect_x",
+        mode = "indirect_y"
+    )]
+    Isb {
+        addressing_mode: IsbAddressingMode,
+    },
+    #[modes(
+        mode = "zero_page",
+        mode = "zero_page_x",
+        mode = "absolute",
+        mode = "absolute_x",
+        mode = "absolute_y",
+        mode = "indirect_x",
+        mode = "indirect_y"
+    )]
+    Slo {
+        addressing_mode: SloAddressingMode,
+    },
+    #[modes(
+        mode = "zero_page",
+        mode = "zero_page_x",
+        mode = "absolute",
+        mode = "absolute_x",
+        mode = "absolute_y",
+        mode = "indirect_x",
+        mode = "indirect_y"
+    )]
+    Rla {
+        addressing_mode: RlaAddressingMode,
+    },
+    #[modes(
+        mode = "zero_page",
+        mode = "zero_page_x",
+        mode = "absolute",
+        mode = "absolute_x",
+        mode = "absolute_y",
+        mode = "indirect_x",
+        mode = "indirect_y"
+    )]
+    Sre {
+        addressing_mode: SreAddressingMode,
+    },
+    #[modes(
+        mode = "zero_page",
+        mode = "zero_page_x",
+        mode = "absolute",
+        mode = "absolute_x",
+        mode = "absolute_y",
+        mode = "indirect_x",
+        mode = "indirect_y"
+    )]
+    Rra {
+        addressing_mode: RraAddressingMode,
+    },
+    /// 65C02 branch always.
+    Bra {
+        addressing_mode: AM::Relative,
+    },
+    /// 65C02 store zero.
+    #[modes(
+        mode = "zero_page",
+        mode = "zero_page_x",
+        mode = "absolute",
+        mode = "absolute_x"
+    )]
+    Stz {
+        addressing_mode: StzAddressingMode,
+    },
+    /// 65C02 test and reset bits, clearing in memory the bits set in A.
+    #[modes(mode = "zero_page", mode = "absolute")]
+    Trb {
+        addressing_mode: TrbAddressingMode,
+    },
+    /// 65C02 test and set bits, setting in memory the bits set in A.
+    #[modes(mode = "zero_page", mode = "absolute")]
+    Tsb {
+        addressing_mode: TsbAddressingMode,
+    },
+    /// 65C02 BIT on an immediate operand, which only sets Zero.
+    BitImmediate {
+        addressing_mode: AM::Immediate,
+    },
+    /// 65C02 NOP on an undefined opcode that takes a single cycle, so it
+    /// doesn't even read the byte after it.
+    SingleCycleNop,
+    /// 65C02 NOP on $5C, which takes an absolute operand and eight cycles.
+    LongNop {
+        addressing_mode: AM::Absolute,
+    },
 }
 
 #[derive(Debug, Error)]
@@ -131,25 +397,63 @@ pub enum InstructionError {
 }
 
 impl Instruction {
+    /// Decodes the instruction at `program_counter` without side effects,
+    /// for inspecting memory. Returns it with the address that follows it.
     pub fn get_instruction(
-        memory: &Memory,
+        bus: &impl Bus,
         program_counter: &u16,
+        config: &CpuConfig,
+    ) -> Result<(Instruction, u16), InstructionError> {
+        let code = bus.peek(*program_counter);
+        Self::decode(
+            code,
+            &mut |address| bus.peek(address),
+            program_counter.wrapping_add(1),
+            config,
+        )
+    }
+
+    /// Decodes the instruction with opcode `code`, reading its operands
+    /// from `program_counter` on with `fetch`. Returns it with the address
+    /// that follows it.
+    pub fn decode(
+        code: u8,
+        fetch: &mut impl FnMut(u16) -> u8,
+        program_counter: u16,
+        config: &CpuConfig,
     ) -> Result<(Instruction, u16), InstructionError> {
         use opcodes::*;
-        let mut program_counter = *program_counter;
-        let instruction = memory.read(program_counter);
-        program_counter += 1;
+        let mut program_counter = program_counter;
+        let instruction = code;
+
+        if config.variant == Variant::Cmos65C02 {
+            if let Some(instruction) =
+                Self::get_cmos_instruction(instruction, fetch, &mut program_counter)
+            {
+                return Ok((instruction, program_counter));
+            }
+            // The NMOS unofficial opcodes are all either 65C02 instructions
+            // or NOPs.
+            if is_unofficial(instruction) {
+                return Err(InstructionError::InvalidInstructionCode { code: instruction });
+            }
+        }
+
+        if !config.unofficial_opcodes && is_unofficial(instruction) {
+            return Err(InstructionError::InvalidInstructionCode { code: instruction });
+        }
+
         let instruction = match instruction {
             ADC_IMMEDIATE => {
                 let addressing_mode = AdcAddressingMode::Immediate {
-                    mode: AM::Immediate::new(memory, &mut program_counter),
+                    mode: AM::Immediate::new(fetch, &mut program_counter),
                 };
                 Instruction::Adc { addressing_mode }
             }
             ADC_ZERO_PAGE => {
                 let addressing_mode = AdcAddressingMode::AdcAddressAddressingMode {
                     mode: AdcAddressAddressingMode::ZeroPage {
-                        mode: AM::ZeroPage::new(memory, &mut program_counter),
+                        mode: AM::ZeroPage::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Adc { addressing_mode }
@@ -157,7 +461,7 @@ impl Instruction {
             ADC_ZERO_PAGE_X => {
                 let addressing_mode = AdcAddressingMode::AdcAddressAddressingMode {
                     mode: AdcAddressAddressingMode::ZeroPageX {
-                        mode: AM::ZeroPageX::new(memory, &mut program_counter),
+                        mode: AM::ZeroPageX::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Adc { addressing_mode }
@@ -165,7 +469,7 @@ impl Instruction {
             ADC_ABSOLUTE => {
                 let addressing_mode = AdcAddressingMode::AdcAddressAddressingMode {
                     mode: AdcAddressAddressingMode::Absolute {
-                        mode: AM::Absolute::new(memory, &mut program_counter),
+                        mode: AM::Absolute::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Adc { addressing_mode }
@@ -173,7 +477,7 @@ impl Instruction {
             ADC_ABSOLUTE_X => {
                 let addressing_mode = AdcAddressingMode::AdcAddressAddressingMode {
                     mode: AdcAddressAddressingMode::AbsoluteX {
-                        mode: AM::AbsoluteX::new(memory, &mut program_counter),
+                        mode: AM::AbsoluteX::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Adc { addressing_mode }
@@ -181,7 +485,7 @@ impl Instruction {
             ADC_ABSOLUTE_Y => {
                 let addressing_mode = AdcAddressingMode::AdcAddressAddressingMode {
                     mode: AdcAddressAddressingMode::AbsoluteY {
-                        mode: AM::AbsoluteY::new(memory, &mut program_counter),
+                        mode: AM::AbsoluteY::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Adc { addressing_mode }
@@ -189,7 +493,7 @@ impl Instruction {
             ADC_INDIRECT_X => {
                 let addressing_mode = AdcAddressingMode::AdcAddressAddressingMode {
                     mode: AdcAddressAddressingMode::IndirectX {
-                        mode: AM::IndirectX::new(memory, &mut program_counter),
+                        mode: AM::IndirectX::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Adc { addressing_mode }
@@ -197,21 +501,21 @@ impl Instruction {
             ADC_INDIRECT_Y => {
                 let addressing_mode = AdcAddressingMode::AdcAddressAddressingMode {
                     mode: AdcAddressAddressingMode::IndirectY {
-                        mode: AM::IndirectY::new(memory, &mut program_counter),
+                        mode: AM::IndirectY::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Adc { addressing_mode }
             }
             AND_IMMEDIATE => {
                 let addressing_mode = AndAddressingMode::Immediate {
-                    mode: AM::Immediate::new(memory, &mut program_counter),
+                    mode: AM::Immediate::new(fetch, &mut program_counter),
                 };
                 Instruction::And { addressing_mode }
             }
             AND_ZERO_PAGE => {
                 let addressing_mode = AndAddressingMode::AndAddressAddressingMode {
                     mode: AndAddressAddressingMode::ZeroPage {
-                        mode: AM::ZeroPage::new(memory, &mut program_counter),
+                        mode: AM::ZeroPage::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::And { addressing_mode }
@@ -219,7 +523,7 @@ impl Instruction {
             AND_ZERO_PAGE_X => {
                 let addressing_mode = AndAddressingMode::AndAddressAddressingMode {
                     mode: AndAddressAddressingMode::ZeroPageX {
-                        mode: AM::ZeroPageX::new(memory, &mut program_counter),
+                        mode: AM::ZeroPageX::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::And { addressing_mode }
@@ -227,7 +531,7 @@ impl Instruction {
             AND_ABSOLUTE => {
                 let addressing_mode = AndAddressingMode::AndAddressAddressingMode {
                     mode: AndAddressAddressingMode::Absolute {
-                        mode: AM::Absolute::new(memory, &mut program_counter),
+                        mode: AM::Absolute::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::And { addressing_mode }
@@ -235,7 +539,7 @@ impl Instruction {
             AND_ABSOLUTE_X => {
                 let addressing_mode = AndAddressingMode::AndAddressAddressingMode {
                     mode: AndAddressAddressingMode::AbsoluteX {
-                        mode: AM::AbsoluteX::new(memory, &mut program_counter),
+                        mode: AM::AbsoluteX::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::And { addressing_mode }
@@ -243,7 +547,7 @@ impl Instruction {
             AND_ABSOLUTE_Y => {
                 let addressing_mode = AndAddressingMode::AndAddressAddressingMode {
                     mode: AndAddressAddressingMode::AbsoluteY {
-                        mode: AM::AbsoluteY::new(memory, &mut program_counter),
+                        mode: AM::AbsoluteY::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::And { addressing_mode }
@@ -251,7 +555,7 @@ impl Instruction {
             AND_INDIRECT_X => {
                 let addressing_mode = AndAddressingMode::AndAddressAddressingMode {
                     mode: AndAddressAddressingMode::IndirectX {
-                        mode: AM::IndirectX::new(memory, &mut program_counter),
+                        mode: AM::IndirectX::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::And { addressing_mode }
@@ -259,7 +563,7 @@ impl Instruction {
             AND_INDIRECT_Y => {
                 let addressing_mode = AndAddressingMode::AndAddressAddressingMode {
                     mode: AndAddressAddressingMode::IndirectY {
-                        mode: AM::IndirectY::new(memory, &mut program_counter),
+                        mode: AM::IndirectY::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::And { addressing_mode }
@@ -272,7 +576,7 @@ impl Instruction {
             ASL_ZERO_PAGE => {
                 let addressing_mode = AslAddressingMode::AslAddressAddressingMode {
                     mode: AslAddressAddressingMode::ZeroPage {
-                        mode: AM::ZeroPage::new(memory, &mut program_counter),
+                        mode: AM::ZeroPage::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Asl { addressing_mode }
@@ -280,7 +584,7 @@ impl Instruction {
             ASL_ZERO_PAGE_X => {
                 let addressing_mode = AslAddressingMode::AslAddressAddressingMode {
                     mode: AslAddressAddressingMode::ZeroPageX {
-                        mode: AM::ZeroPageX::new(memory, &mut program_counter),
+                        mode: AM::ZeroPageX::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Asl { addressing_mode }
@@ -288,7 +592,7 @@ impl Instruction {
             ASL_ABSOLUTE => {
                 let addressing_mode = AslAddressingMode::AslAddressAddressingMode {
                     mode: AslAddressAddressingMode::Absolute {
-                        mode: AM::Absolute::new(memory, &mut program_counter),
+                        mode: AM::Absolute::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Asl { addressing_mode }
@@ -296,61 +600,61 @@ impl Instruction {
             ASL_ABSOLUTE_X => {
                 let addressing_mode = AslAddressingMode::AslAddressAddressingMode {
                     mode: AslAddressAddressingMode::AbsoluteX {
-                        mode: AM::AbsoluteX::new(memory, &mut program_counter),
+                        mode: AM::AbsoluteX::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Asl { addressing_mode }
             }
             BCC => Instruction::Branch {
-                addressing_mode: AM::Relative::new(memory, &mut program_counter),
+                addressing_mode: AM::Relative::new(fetch, &mut program_counter),
                 flag: Flag::Carry,
                 branch_if: false,
             },
             BCS => Instruction::Branch {
-                addressing_mode: AM::Relative::new(memory, &mut program_counter),
+                addressing_mode: AM::Relative::new(fetch, &mut program_counter),
                 flag: Flag::Carry,
                 branch_if: true,
             },
             BEQ => Instruction::Branch {
-                addressing_mode: AM::Relative::new(memory, &mut program_counter),
+                addressing_mode: AM::Relative::new(fetch, &mut program_counter),
                 flag: Flag::Zero,
                 branch_if: true,
             },
             BIT_ZERO_PAGE => {
                 let addressing_mode = BitAddressingMode::ZeroPage {
-                    mode: AM::ZeroPage::new(memory, &mut program_counter),
+                    mode: AM::ZeroPage::new(fetch, &mut program_counter),
                 };
                 Instruction::Bit { addressing_mode }
             }
             BIT_ABSOLUTE => {
                 let addressing_mode = BitAddressingMode::Absolute {
-                    mode: AM::Absolute::new(memory, &mut program_counter),
+                    mode: AM::Absolute::new(fetch, &mut program_counter),
                 };
                 Instruction::Bit { addressing_mode }
             }
             BMI => Instruction::Branch {
-                addressing_mode: AM::Relative::new(memory, &mut program_counter),
+                addressing_mode: AM::Relative::new(fetch, &mut program_counter),
                 flag: Flag::Negative,
                 branch_if: true,
             },
             BNE => Instruction::Branch {
-                addressing_mode: AM::Relative::new(memory, &mut program_counter),
+                addressing_mode: AM::Relative::new(fetch, &mut program_counter),
                 flag: Flag::Zero,
                 branch_if: false,
             },
             BPL => Instruction::Branch {
-                addressing_mode: AM::Relative::new(memory, &mut program_counter),
+                addressing_mode: AM::Relative::new(fetch, &mut program_counter),
                 flag: Flag::Negative,
                 branch_if: false,
             },
             BRK => Instruction::Break,
             BVC => Instruction::Branch {
-                addressing_mode: AM::Relative::new(memory, &mut program_counter),
+                addressing_mode: AM::Relative::new(fetch, &mut program_counter),
                 flag: Flag::Overflow,
                 branch_if: false,
             },
             BVS => Instruction::Branch {
-                addressing_mode: AM::Relative::new(memory, &mut program_counter),
+                addressing_mode: AM::Relative::new(fetch, &mut program_counter),
                 flag: Flag::Overflow,
                 branch_if: true,
             },
@@ -366,14 +670,14 @@ impl Instruction {
             },
             CMP_IMMEDIATE => {
                 let addressing_mode = CmpAddressingMode::Immediate {
-                    mode: AM::Immediate::new(memory, &mut program_counter),
+                    mode: AM::Immediate::new(fetch, &mut program_counter),
                 };
                 Instruction::Cmp { addressing_mode }
             }
             CMP_ZERO_PAGE => {
                 let addressing_mode = CmpAddressingMode::CmpAddressAddressingMode {
                     mode: CmpAddressAddressingMode::ZeroPage {
-                        mode: AM::ZeroPage::new(memory, &mut program_counter),
+                        mode: AM::ZeroPage::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Cmp { addressing_mode }
@@ -381,7 +685,7 @@ impl Instruction {
             CMP_ZERO_PAGE_X => {
                 let addressing_mode = CmpAddressingMode::CmpAddressAddressingMode {
                     mode: CmpAddressAddressingMode::ZeroPageX {
-                        mode: AM::ZeroPageX::new(memory, &mut program_counter),
+                        mode: AM::ZeroPageX::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Cmp { addressing_mode }
@@ -389,7 +693,7 @@ impl Instruction {
             CMP_ABSOLUTE => {
                 let addressing_mode = CmpAddressingMode::CmpAddressAddressingMode {
                     mode: CmpAddressAddressingMode::Absolute {
-                        mode: AM::Absolute::new(memory, &mut program_counter),
+                        mode: AM::Absolute::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Cmp { addressing_mode }
@@ -397,7 +701,7 @@ impl Instruction {
             CMP_ABSOLUTE_X => {
                 let addressing_mode = CmpAddressingMode::CmpAddressAddressingMode {
                     mode: CmpAddressAddressingMode::AbsoluteX {
-                        mode: AM::AbsoluteX::new(memory, &mut program_counter),
+                        mode: AM::AbsoluteX::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Cmp { addressing_mode }
@@ -405,7 +709,7 @@ impl Instruction {
             CMP_ABSOLUTE_Y => {
                 let addressing_mode = CmpAddressingMode::CmpAddressAddressingMode {
                     mode: CmpAddressAddressingMode::AbsoluteY {
-                        mode: AM::AbsoluteY::new(memory, &mut program_counter),
+                        mode: AM::AbsoluteY::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Cmp { addressing_mode }
@@ -413,7 +717,7 @@ impl Instruction {
             CMP_INDIRECT_X => {
                 let addressing_mode = CmpAddressingMode::CmpAddressAddressingMode {
                     mode: CmpAddressAddressingMode::IndirectX {
-                        mode: AM::IndirectX::new(memory, &mut program_counter),
+                        mode: AM::IndirectX::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Cmp { addressing_mode }
@@ -421,21 +725,21 @@ impl Instruction {
             CMP_INDIRECT_Y => {
                 let addressing_mode = CmpAddressingMode::CmpAddressAddressingMode {
                     mode: CmpAddressAddressingMode::IndirectY {
-                        mode: AM::IndirectY::new(memory, &mut program_counter),
+                        mode: AM::IndirectY::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Cmp { addressing_mode }
             }
             CPX_IMMEDIATE => {
                 let addressing_mode = CpxAddressingMode::Immediate {
-                    mode: AM::Immediate::new(memory, &mut program_counter),
+                    mode: AM::Immediate::new(fetch, &mut program_counter),
                 };
                 Instruction::Cpx { addressing_mode }
             }
             CPX_ZERO_PAGE => {
                 let addressing_mode = CpxAddressingMode::CpxAddressAddressingMode {
                     mode: CpxAddressAddressingMode::ZeroPage {
-                        mode: AM::ZeroPage::new(memory, &mut program_counter),
+                        mode: AM::ZeroPage::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Cpx { addressing_mode }
@@ -443,21 +747,21 @@ impl Instruction {
             CPX_ABSOLUTE => {
                 let addressing_mode = CpxAddressingMode::CpxAddressAddressingMode {
                     mode: CpxAddressAddressingMode::Absolute {
-                        mode: AM::Absolute::new(memory, &mut program_counter),
+                        mode: AM::Absolute::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Cpx { addressing_mode }
             }
             CPY_IMMEDIATE => {
                 let addressing_mode = CpyAddressingMode::Immediate {
-                    mode: AM::Immediate::new(memory, &mut program_counter),
+                    mode: AM::Immediate::new(fetch, &mut program_counter),
                 };
                 Instruction::Cpy { addressing_mode }
             }
             CPY_ZERO_PAGE => {
                 let addressing_mode = CpyAddressingMode::CpyAddressAddressingMode {
                     mode: CpyAddressAddressingMode::ZeroPage {
-                        mode: AM::ZeroPage::new(memory, &mut program_counter),
+                        mode: AM::ZeroPage::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Cpy { addressing_mode }
@@ -465,38 +769,139 @@ impl Instruction {
             CPY_ABSOLUTE => {
                 let addressing_mode = CpyAddressingMode::CpyAddressAddressingMode {
                     mode: CpyAddressAddressingMode::Absolute {
-                        mode: AM::Absolute::new(memory, &mut program_counter),
+                        mode: AM::Absolute::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Cpy { addressing_mode }
             }
             DEC_ZERO_PAGE => {
                 let addressing_mode = DecAddressingMode::ZeroPage {
-                    mode: AM::ZeroPage::new(memory, &mut program_counter),
+                    mode: AM::ZeroPage::new(fetch, &mut program_counter),
                 };
                 Instruction::Dec { addressing_mode }
             }
             DEC_ZERO_PAGE_X => {
                 let addressing_mode = DecAddressingMode::ZeroPageX {
-                    mode: AM::ZeroPageX::new(memory, &mut program_counter),
+                    mode: AM::ZeroPageX::new(fetch, &mut program_counter),
                 };
                 Instruction::Dec { addressing_mode }
             }
             DEC_ABSOLUTE => {
                 let addressing_mode = DecAddressingMode::Absolute {
-                    mode: AM::Absolute::new(memory, &mut program_counter),
+                    mode: AM::Absolute::new(fetch, &mut program_counter),
                 };
                 Instruction::Dec { addressing_mode }
             }
             DEC_ABSOLUTE_X => {
                 let addressing_mode = DecAddressingMode::AbsoluteX {
-                    mode: AM::AbsoluteX::new(memory, &mut program_counter),
+                    mode: AM::AbsoluteX::new(fetch, &mut program_counter),
                 };
                 Instruction::Dec { addressing_mode }
             }
+            EOR_IMMEDIATE => {
+                let addressing_mode = EorAddressingMode::Immediate {
+                    mode: AM::Immediate::new(fetch, &mut program_counter),
+                };
+                Instruction::Eor { addressing_mode }
+            }
+            EOR_ZERO_PAGE => {
+                let addressing_mode = EorAddressingMode::EorAddressAddressingMode {
+                    mode: EorAddressAddressingMode::ZeroPage {
+                        mode: AM::ZeroPage::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Eor { addressing_mode }
+            }
+            EOR_ZERO_PAGE_X => {
+                let addressing_mode = EorAddressingMode::EorAddressAddressingMode {
+                    mode: EorAddressAddressingMode::ZeroPageX {
+                        mode: AM::ZeroPageX::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Eor { addressing_mode }
+            }
+            EOR_ABSOLUTE => {
+                let addressing_mode = EorAddressingMode::EorAddressAddressingMode {
+                    mode: EorAddressAddressingMode::Absolute {
+                        mode: AM::Absolute::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Eor { addressing_mode }
+            }
+            EOR_ABSOLUTE_X => {
+                let addressing_mode = EorAddressingMode::EorAddressAddressingMode {
+                    mode: EorAddressAddressingMode::AbsoluteX {
+                        mode: AM::AbsoluteX::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Eor { addressing_mode }
+            }
+            EOR_ABSOLUTE_Y => {
+                let addressing_mode = EorAddressingMode::EorAddressAddressingMode {
+                    mode: EorAddressAddressingMode::AbsoluteY {
+                        mode: AM::AbsoluteY::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Eor { addressing_mode }
+            }
+            EOR_INDIRECT_X => {
+                let addressing_mode = EorAddressingMode::EorAddressAddressingMode {
+                    mode: EorAddressAddressingMode::IndirectX {
+                        mode: AM::IndirectX::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Eor { addressing_mode }
+            }
+            EOR_INDIRECT_Y => {
+                let addressing_mode = EorAddressingMode::EorAddressAddressingMode {
+                    mode: EorAddressAddressingMode::IndirectY {
+                        mode: AM::IndirectY::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Eor { addressing_mode }
+            }
+            INC_ZERO_PAGE => {
+                let addressing_mode = IncAddressingMode::ZeroPage {
+                    mode: AM::ZeroPage::new(fetch, &mut program_counter),
+                };
+                Instruction::Inc { addressing_mode }
+            }
+            INC_ZERO_PAGE_X => {
+                let addressing_mode = IncAddressingMode::ZeroPageX {
+                    mode: AM::ZeroPageX::new(fetch, &mut program_counter),
+                };
+                Instruction::Inc { addressing_mode }
+            }
+            INC_ABSOLUTE => {
+                let addressing_mode = IncAddressingMode::Absolute {
+                    mode: AM::Absolute::new(fetch, &mut program_counter),
+                };
+                Instruction::Inc { addressing_mode }
+            }
+            INC_ABSOLUTE_X => {
+                let addressing_mode = IncAddressingMode::AbsoluteX {
+                    mode: AM::AbsoluteX::new(fetch, &mut program_counter),
+                };
+                Instruction::Inc { addressing_mode }
+            }
+            JMP_ABSOLUTE => {
+                let addressing_mode = JmpAddressingMode::Absolute {
+                    mode: AM::Absolute::new(fetch, &mut program_counter),
+                };
+                Instruction::Jmp { addressing_mode }
+            }
+            JMP_INDIRECT => {
+                let addressing_mode = JmpAddressingMode::Indirect {
+                    mode: AM::Indirect::new(fetch, &mut program_counter),
+                };
+                Instruction::Jmp { addressing_mode }
+            }
+            JSR => Instruction::Jsr {
+                addressing_mode: AM::Absolute::new(fetch, &mut program_counter),
+            },
             LDA_IMMEDIATE => {
                 let addressing_mode = LdAddressingMode::Immediate {
-                    mode: AM::Immediate::new(memory, &mut program_counter),
+                    mode: AM::Immediate::new(fetch, &mut program_counter),
                 };
                 Instruction::Ld {
                     destination: Register::A,
@@ -506,7 +911,7 @@ impl Instruction {
             LDA_ZERO_PAGE => {
                 let addressing_mode = LdAddressingMode::LdAddressAddressingMode {
                     mode: LdAddressAddressingMode::ZeroPage {
-                        mode: AM::ZeroPage::new(memory, &mut program_counter),
+                        mode: AM::ZeroPage::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Ld {
@@ -517,7 +922,7 @@ impl Instruction {
             LDA_ZERO_PAGE_X => {
                 let addressing_mode = LdAddressingMode::LdAddressAddressingMode {
                     mode: LdAddressAddressingMode::ZeroPageX {
-                        mode: AM::ZeroPageX::new(memory, &mut program_counter),
+                        mode: AM::ZeroPageX::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Ld {
@@ -528,7 +933,7 @@ impl Instruction {
             LDA_ABSOLUTE => {
                 let addressing_mode = LdAddressingMode::LdAddressAddressingMode {
                     mode: LdAddressAddressingMode::Absolute {
-                        mode: AM::Absolute::new(memory, &mut program_counter),
+                        mode: AM::Absolute::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Ld {
@@ -539,7 +944,7 @@ impl Instruction {
             LDA_ABSOLUTE_X => {
                 let addressing_mode = LdAddressingMode::LdAddressAddressingMode {
                     mode: LdAddressAddressingMode::AbsoluteX {
-                        mode: AM::AbsoluteX::new(memory, &mut program_counter),
+                        mode: AM::AbsoluteX::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Ld {
@@ -550,7 +955,7 @@ impl Instruction {
             LDA_ABSOLUTE_Y => {
                 let addressing_mode = LdAddressingMode::LdAddressAddressingMode {
                     mode: LdAddressAddressingMode::AbsoluteY {
-                        mode: AM::AbsoluteY::new(memory, &mut program_counter),
+                        mode: AM::AbsoluteY::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Ld {
@@ -561,7 +966,7 @@ impl Instruction {
             LDA_INDIRECT_X => {
                 let addressing_mode = LdAddressingMode::LdAddressAddressingMode {
                     mode: LdAddressAddressingMode::IndirectX {
-                        mode: AM::IndirectX::new(memory, &mut program_counter),
+                        mode: AM::IndirectX::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Ld {
@@ -572,7 +977,7 @@ impl Instruction {
             LDA_INDIRECT_Y => {
                 let addressing_mode = LdAddressingMode::LdAddressAddressingMode {
                     mode: LdAddressAddressingMode::IndirectY {
-                        mode: AM::IndirectY::new(memory, &mut program_counter),
+                        mode: AM::IndirectY::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Ld {
@@ -582,7 +987,7 @@ impl Instruction {
             }
             LDX_IMMEDIATE => {
                 let addressing_mode = LdAddressingMode::Immediate {
-                    mode: AM::Immediate::new(memory, &mut program_counter),
+                    mode: AM::Immediate::new(fetch, &mut program_counter),
                 };
                 Instruction::Ld {
                     destination: Register::X,
@@ -592,7 +997,7 @@ impl Instruction {
             LDX_ZERO_PAGE => {
                 let addressing_mode = LdAddressingMode::LdAddressAddressingMode {
                     mode: LdAddressAddressingMode::ZeroPage {
-                        mode: AM::ZeroPage::new(memory, &mut program_counter),
+                        mode: AM::ZeroPage::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Ld {
@@ -603,7 +1008,7 @@ impl Instruction {
             LDX_ZERO_PAGE_Y => {
                 let addressing_mode = LdAddressingMode::LdAddressAddressingMode {
                     mode: LdAddressAddressingMode::ZeroPageY {
-                        mode: AM::ZeroPageY::new(memory, &mut program_counter),
+                        mode: AM::ZeroPageY::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Ld {
@@ -614,7 +1019,7 @@ impl Instruction {
             LDX_ABSOLUTE => {
                 let addressing_mode = LdAddressingMode::LdAddressAddressingMode {
                     mode: LdAddressAddressingMode::Absolute {
-                        mode: AM::Absolute::new(memory, &mut program_counter),
+                        mode: AM::Absolute::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Ld {
@@ -625,7 +1030,7 @@ impl Instruction {
             LDX_ABSOLUTE_Y => {
                 let addressing_mode = LdAddressingMode::LdAddressAddressingMode {
                     mode: LdAddressAddressingMode::AbsoluteY {
-                        mode: AM::AbsoluteY::new(memory, &mut program_counter),
+                        mode: AM::AbsoluteY::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Ld {
@@ -635,7 +1040,7 @@ impl Instruction {
             }
             LDY_IMMEDIATE => {
                 let addressing_mode = LdAddressingMode::Immediate {
-                    mode: AM::Immediate::new(memory, &mut program_counter),
+                    mode: AM::Immediate::new(fetch, &mut program_counter),
                 };
                 Instruction::Ld {
                     destination: Register::Y,
@@ -645,7 +1050,7 @@ impl Instruction {
             LDY_ZERO_PAGE => {
                 let addressing_mode = LdAddressingMode::LdAddressAddressingMode {
                     mode: LdAddressAddressingMode::ZeroPage {
-                        mode: AM::ZeroPage::new(memory, &mut program_counter),
+                        mode: AM::ZeroPage::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Ld {
@@ -656,7 +1061,7 @@ impl Instruction {
             LDY_ZERO_PAGE_X => {
                 let addressing_mode = LdAddressingMode::LdAddressAddressingMode {
                     mode: LdAddressAddressingMode::ZeroPageX {
-                        mode: AM::ZeroPageX::new(memory, &mut program_counter),
+                        mode: AM::ZeroPageX::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Ld {
@@ -667,7 +1072,7 @@ impl Instruction {
             LDY_ABSOLUTE => {
                 let addressing_mode = LdAddressingMode::LdAddressAddressingMode {
                     mode: LdAddressAddressingMode::Absolute {
-                        mode: AM::Absolute::new(memory, &mut program_counter),
+                        mode: AM::Absolute::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Ld {
@@ -678,7 +1083,7 @@ impl Instruction {
             LDY_ABSOLUTE_X => {
                 let addressing_mode = LdAddressingMode::LdAddressAddressingMode {
                     mode: LdAddressAddressingMode::AbsoluteX {
-                        mode: AM::AbsoluteX::new(memory, &mut program_counter),
+                        mode: AM::AbsoluteX::new(fetch, &mut program_counter),
                     },
                 };
                 Instruction::Ld {
@@ -686,18 +1091,959 @@ impl Instruction {
                     addressing_mode,
                 }
             }
-            TAX => Instruction::Trr {
-                origin: Register::A,
-                destination: Register::X,
-            },
-            INX => Instruction::In {
-                destination: Register::X,
-            },
-            code => {
-                return Err(InstructionError::InvalidInstructionCode { code });
+            SBC_IMMEDIATE | SBC_IMMEDIATE_UNOFFICIAL => {
+                let addressing_mode = SbcAddressingMode::Immediate {
+                    mode: AM::Immediate::new(fetch, &mut program_counter),
+                };
+                Instruction::Sbc { addressing_mode }
             }
-        };
-
-        Ok((instruction, program_counter))
+            SBC_ZERO_PAGE => {
+                let addressing_mode = SbcAddressingMode::SbcAddressAddressingMode {
+                    mode: SbcAddressAddressingMode::ZeroPage {
+                        mode: AM::ZeroPage::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Sbc { addressing_mode }
+            }
+            SBC_ZERO_PAGE_X => {
+                let addressing_mode = SbcAddressingMode::SbcAddressAddressingMode {
+                    mode: SbcAddressAddressingMode::ZeroPageX {
+                        mode: AM::ZeroPageX::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Sbc { addressing_mode }
+            }
+            SBC_ABSOLUTE => {
+                let addressing_mode = SbcAddressingMode::SbcAddressAddressingMode {
+                    mode: SbcAddressAddressingMode::Absolute {
+                        mode: AM::Absolute::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Sbc { addressing_mode }
+            }
+            SBC_ABSOLUTE_X => {
+                let addressing_mode = SbcAddressingMode::SbcAddressAddressingMode {
+                    mode: SbcAddressAddressingMode::AbsoluteX {
+                        mode: AM::AbsoluteX::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Sbc { addressing_mode }
+            }
+            SBC_ABSOLUTE_Y => {
+                let addressing_mode = SbcAddressingMode::SbcAddressAddressingMode {
+                    mode: SbcAddressAddressingMode::AbsoluteY {
+                        mode: AM::AbsoluteY::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Sbc { addressing_mode }
+            }
+            SBC_INDIRECT_X => {
+                let addressing_mode = SbcAddressingMode::SbcAddressAddressingMode {
+                    mode: SbcAddressAddressingMode::IndirectX {
+                        mode: AM::IndirectX::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Sbc { addressing_mode }
+            }
+            SBC_INDIRECT_Y => {
+                let addressing_mode = SbcAddressingMode::SbcAddressAddressingMode {
+                    mode: SbcAddressAddressingMode::IndirectY {
+                        mode: AM::IndirectY::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Sbc { addressing_mode }
+            }
+            SEC => Instruction::Set { flag: Flag::Carry },
+            SED => Instruction::Set {
+                flag: Flag::Decimal,
+            },
+            SEI => Instruction::Set {
+                flag: Flag::InterruptDisable,
+            },
+            STA_ZERO_PAGE => {
+                let addressing_mode = StAddressingMode::ZeroPage {
+                    mode: AM::ZeroPage::new(fetch, &mut program_counter),
+                };
+                Instruction::St {
+                    origin: Register::A,
+                    addressing_mode,
+                }
+            }
+            STA_ZERO_PAGE_X => {
+                let addressing_mode = StAddressingMode::ZeroPageX {
+                    mode: AM::ZeroPageX::new(fetch, &mut program_counter),
+                };
+                Instruction::St {
+                    origin: Register::A,
+                    addressing_mode,
+                }
+            }
+            STA_ABSOLUTE => {
+                let addressing_mode = StAddressingMode::Absolute {
+                    mode: AM::Absolute::new(fetch, &mut program_counter),
+                };
+                Instruction::St {
+                    origin: Register::A,
+                    addressing_mode,
+                }
+            }
+            STA_ABSOLUTE_X => {
+                let addressing_mode = StAddressingMode::AbsoluteX {
+                    mode: AM::AbsoluteX::new(fetch, &mut program_counter),
+                };
+                Instruction::St {
+                    origin: Register::A,
+                    addressing_mode,
+                }
+            }
+            STA_ABSOLUTE_Y => {
+                let addressing_mode = StAddressingMode::AbsoluteY {
+                    mode: AM::AbsoluteY::new(fetch, &mut program_counter),
+                };
+                Instruction::St {
+                    origin: Register::A,
+                    addressing_mode,
+                }
+            }
+            STA_INDIRECT_X => {
+                let addressing_mode = StAddressingMode::IndirectX {
+                    mode: AM::IndirectX::new(fetch, &mut program_counter),
+                };
+                Instruction::St {
+                    origin: Register::A,
+                    addressing_mode,
+                }
+            }
+            STA_INDIRECT_Y => {
+                let addressing_mode = StAddressingMode::IndirectY {
+                    mode: AM::IndirectY::new(fetch, &mut program_counter),
+                };
+                Instruction::St {
+                    origin: Register::A,
+                    addressing_mode,
+                }
+            }
+            STX_ZERO_PAGE => {
+                let addressing_mode = StAddressingMode::ZeroPage {
+                    mode: AM::ZeroPage::new(fetch, &mut program_counter),
+                };
+                Instruction::St {
+                    origin: Register::X,
+                    addressing_mode,
+                }
+            }
+            STX_ZERO_PAGE_Y => {
+                let addressing_mode = StAddressingMode::ZeroPageY {
+                    mode: AM::ZeroPageY::new(fetch, &mut program_counter),
+                };
+                Instruction::St {
+                    origin: Register::X,
+                    addressing_mode,
+                }
+            }
+            STX_ABSOLUTE => {
+                let addressing_mode = StAddressingMode::Absolute {
+                    mode: AM::Absolute::new(fetch, &mut program_counter),
+                };
+                Instruction::St {
+                    origin: Register::X,
+                    addressing_mode,
+                }
+            }
+            STY_ZERO_PAGE => {
+                let addressing_mode = StAddressingMode::ZeroPage {
+                    mode: AM::ZeroPage::new(fetch, &mut program_counter),
+                };
+                Instruction::St {
+                    origin: Register::Y,
+                    addressing_mode,
+                }
+            }
+            STY_ZERO_PAGE_X => {
+                let addressing_mode = StAddressingMode::ZeroPageX {
+                    mode: AM::ZeroPageX::new(fetch, &mut program_counter),
+                };
+                Instruction::St {
+                    origin: Register::Y,
+                    addressing_mode,
+                }
+            }
+            STY_ABSOLUTE => {
+                let addressing_mode = StAddressingMode::Absolute {
+                    mode: AM::Absolute::new(fetch, &mut program_counter),
+                };
+                Instruction::St {
+                    origin: Register::Y,
+                    addressing_mode,
+                }
+            }
+            LSR_ACCUMULATOR => Instruction::Lsr {
+                addressing_mode: LsrAddressingMode::Accumulator {
+                    mode: AM::Accumulator {},
+                },
+            },
+            LSR_ZERO_PAGE => {
+                let addressing_mode = LsrAddressingMode::LsrAddressAddressingMode {
+                    mode: LsrAddressAddressingMode::ZeroPage {
+                        mode: AM::ZeroPage::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Lsr { addressing_mode }
+            }
+            LSR_ZERO_PAGE_X => {
+                let addressing_mode = LsrAddressingMode::LsrAddressAddressingMode {
+                    mode: LsrAddressAddressingMode::ZeroPageX {
+                        mode: AM::ZeroPageX::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Lsr { addressing_mode }
+            }
+            LSR_ABSOLUTE => {
+                let addressing_mode = LsrAddressingMode::LsrAddressAddressingMode {
+                    mode: LsrAddressAddressingMode::Absolute {
+                        mode: AM::Absolute::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Lsr { addressing_mode }
+            }
+            LSR_ABSOLUTE_X => {
+                let addressing_mode = LsrAddressingMode::LsrAddressAddressingMode {
+                    mode: LsrAddressAddressingMode::AbsoluteX {
+                        mode: AM::AbsoluteX::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Lsr { addressing_mode }
+            }
+            ORA_IMMEDIATE => {
+                let addressing_mode = OraAddressingMode::Immediate {
+                    mode: AM::Immediate::new(fetch, &mut program_counter),
+                };
+                Instruction::Ora { addressing_mode }
+            }
+            ORA_ZERO_PAGE => {
+                let addressing_mode = OraAddressingMode::OraAddressAddressingMode {
+                    mode: OraAddressAddressingMode::ZeroPage {
+                        mode: AM::ZeroPage::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Ora { addressing_mode }
+            }
+            ORA_ZERO_PAGE_X => {
+                let addressing_mode = OraAddressingMode::OraAddressAddressingMode {
+                    mode: OraAddressAddressingMode::ZeroPageX {
+                        mode: AM::ZeroPageX::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Ora { addressing_mode }
+            }
+            ORA_ABSOLUTE => {
+                let addressing_mode = OraAddressingMode::OraAddressAddressingMode {
+                    mode: OraAddressAddressingMode::Absolute {
+                        mode: AM::Absolute::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Ora { addressing_mode }
+            }
+            ORA_ABSOLUTE_X => {
+                let addressing_mode = OraAddressingMode::OraAddressAddressingMode {
+                    mode: OraAddressAddressingMode::AbsoluteX {
+                        mode: AM::AbsoluteX::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Ora { addressing_mode }
+            }
+            ORA_ABSOLUTE_Y => {
+                let addressing_mode = OraAddressingMode::OraAddressAddressingMode {
+                    mode: OraAddressAddressingMode::AbsoluteY {
+                        mode: AM::AbsoluteY::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Ora { addressing_mode }
+            }
+            ORA_INDIRECT_X => {
+                let addressing_mode = OraAddressingMode::OraAddressAddressingMode {
+                    mode: OraAddressAddressingMode::IndirectX {
+                        mode: AM::IndirectX::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Ora { addressing_mode }
+            }
+            ORA_INDIRECT_Y => {
+                let addressing_mode = OraAddressingMode::OraAddressAddressingMode {
+                    mode: OraAddressAddressingMode::IndirectY {
+                        mode: AM::IndirectY::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Ora { addressing_mode }
+            }
+            PHA => Instruction::Push {
+                origin: Register::A,
+            },
+            PHP => Instruction::PushStatus,
+            PLA => Instruction::Pull {
+                destination: Register::A,
+            },
+            PLP => Instruction::PullStatus,
+            ROL_ACCUMULATOR => Instruction::Rol {
+                addressing_mode: RolAddressingMode::Accumulator {
+                    mode: AM::Accumulator {},
+                },
+            },
+            ROL_ZERO_PAGE => {
+                let addressing_mode = RolAddressingMode::RolAddressAddressingMode {
+                    mode: RolAddressAddressingMode::ZeroPage {
+                        mode: AM::ZeroPage::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Rol { addressing_mode }
+            }
+            ROL_ZERO_PAGE_X => {
+                let addressing_mode = RolAddressingMode::RolAddressAddressingMode {
+                    mode: RolAddressAddressingMode::ZeroPageX {
+                        mode: AM::ZeroPageX::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Rol { addressing_mode }
+            }
+            ROL_ABSOLUTE => {
+                let addressing_mode = RolAddressingMode::RolAddressAddressingMode {
+                    mode: RolAddressAddressingMode::Absolute {
+                        mode: AM::Absolute::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Rol { addressing_mode }
+            }
+            ROL_ABSOLUTE_X => {
+                let addressing_mode = RolAddressingMode::RolAddressAddressingMode {
+                    mode: RolAddressAddressingMode::AbsoluteX {
+                        mode: AM::AbsoluteX::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Rol { addressing_mode }
+            }
+            ROR_ACCUMULATOR => Instruction::Ror {
+                addressing_mode: RorAddressingMode::Accumulator {
+                    mode: AM::Accumulator {},
+                },
+            },
+            ROR_ZERO_PAGE => {
+                let addressing_mode = RorAddressingMode::RorAddressAddressingMode {
+                    mode: RorAddressAddressingMode::ZeroPage {
+                        mode: AM::ZeroPage::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Ror { addressing_mode }
+            }
+            ROR_ZERO_PAGE_X => {
+                let addressing_mode = RorAddressingMode::RorAddressAddressingMode {
+                    mode: RorAddressAddressingMode::ZeroPageX {
+                        mode: AM::ZeroPageX::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Ror { addressing_mode }
+            }
+            ROR_ABSOLUTE => {
+                let addressing_mode = RorAddressingMode::RorAddressAddressingMode {
+                    mode: RorAddressAddressingMode::Absolute {
+                        mode: AM::Absolute::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Ror { addressing_mode }
+            }
+            ROR_ABSOLUTE_X => {
+                let addressing_mode = RorAddressingMode::RorAddressAddressingMode {
+                    mode: RorAddressAddressingMode::AbsoluteX {
+                        mode: AM::AbsoluteX::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Ror { addressing_mode }
+            }
+            RTI => Instruction::Rti,
+            RTS => Instruction::Rts,
+            TAX => Instruction::Trr {
+                origin: Register::A,
+                destination: Register::X,
+            },
+            TAY => Instruction::Trr {
+                origin: Register::A,
+                destination: Register::Y,
+            },
+            TSX => Instruction::Trr {
+                origin: Register::StackPointer,
+                destination: Register::X,
+            },
+            TXA => Instruction::Trr {
+                origin: Register::X,
+                destination: Register::A,
+            },
+            TXS => Instruction::Trr {
+                origin: Register::X,
+                destination: Register::StackPointer,
+            },
+            TYA => Instruction::Trr {
+                origin: Register::Y,
+                destination: Register::A,
+            },
+            INX => Instruction::In {
+                destination: Register::X,
+            },
+            INY => Instruction::In {
+                destination: Register::Y,
+            },
+            DEX => Instruction::De {
+                destination: Register::X,
+            },
+            DEY => Instruction::De {
+                destination: Register::Y,
+            },
+            JAM_02 | JAM_12 | JAM_22 | JAM_32 | JAM_42 | JAM_52 | JAM_62 | JAM_72 | JAM_92
+            | JAM_B2 | JAM_D2 | JAM_F2 => Instruction::Jam,
+            NOP | NOP_IMPLIED_1A | NOP_IMPLIED_3A | NOP_IMPLIED_5A | NOP_IMPLIED_7A
+            | NOP_IMPLIED_DA | NOP_IMPLIED_FA => Instruction::Nop,
+            NOP_IMMEDIATE_80 | NOP_IMMEDIATE_82 | NOP_IMMEDIATE_89 | NOP_IMMEDIATE_C2
+            | NOP_IMMEDIATE_E2 => {
+                let addressing_mode = IgnAddressingMode::Immediate {
+                    mode: AM::Immediate::new(fetch, &mut program_counter),
+                };
+                Instruction::Ign { addressing_mode }
+            }
+            NOP_ZERO_PAGE_04 | NOP_ZERO_PAGE_44 | NOP_ZERO_PAGE_64 => {
+                let addressing_mode = IgnAddressingMode::IgnAddressAddressingMode {
+                    mode: IgnAddressAddressingMode::ZeroPage {
+                        mode: AM::ZeroPage::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Ign { addressing_mode }
+            }
+            NOP_ZERO_PAGE_X_14 | NOP_ZERO_PAGE_X_34 | NOP_ZERO_PAGE_X_54 | NOP_ZERO_PAGE_X_74
+            | NOP_ZERO_PAGE_X_D4 | NOP_ZERO_PAGE_X_F4 => {
+                let addressing_mode = IgnAddressingMode::IgnAddressAddressingMode {
+                    mode: IgnAddressAddressingMode::ZeroPageX {
+                        mode: AM::ZeroPageX::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Ign { addressing_mode }
+            }
+            NOP_ABSOLUTE_0C => {
+                let addressing_mode = IgnAddressingMode::IgnAddressAddressingMode {
+                    mode: IgnAddressAddressingMode::Absolute {
+                        mode: AM::Absolute::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Ign { addressing_mode }
+            }
+            NOP_ABSOLUTE_X_1C | NOP_ABSOLUTE_X_3C | NOP_ABSOLUTE_X_5C | NOP_ABSOLUTE_X_7C
+            | NOP_ABSOLUTE_X_DC | NOP_ABSOLUTE_X_FC => {
+                let addressing_mode = IgnAddressingMode::IgnAddressAddressingMode {
+                    mode: IgnAddressAddressingMode::AbsoluteX {
+                        mode: AM::AbsoluteX::new(fetch, &mut program_counter),
+                    },
+                };
+                Instruction::Ign { addressing_mode }
+            }
+            LAX_ZERO_PAGE => {
+                let addressing_mode = LaxAddressingMode::ZeroPage {
+                    mode: AM::ZeroPage::new(fetch, &mut program_counter),
+                };
+                Instruction::Lax { addressing_mode }
+            }
+            LAX_ZERO_PAGE_Y => {
+                let addressing_mode = LaxAddressingMode::ZeroPageY {
+                    mode: AM::ZeroPageY::new(fetch, &mut program_counter),
+                };
+                Instruction::Lax { addressing_mode }
+            }
+            LAX_ABSOLUTE => {
+                let addressing_mode = LaxAddressingMode::Absolute {
+                    mode: AM::Absolute::new(fetch, &mut program_counter),
+                };
+                Instruction::Lax { addressing_mode }
+            }
+            LAX_ABSOLUTE_Y => {
+                let addressing_mode = LaxAddressingMode::AbsoluteY {
+                    mode: AM::AbsoluteY::new(fetch, &mut program_counter),
+                };
+                Instruction::Lax { addressing_mode }
+            }
+            LAX_INDIRECT_X => {
+                let addressing_mode = LaxAddressingMode::IndirectX {
+                    mode: AM::IndirectX::new(fetch, &mut program_counter),
+                };
+                Instruction::Lax { addressing_mode }
+            }
+            LAX_INDIRECT_Y => {
+                let addressing_mode = LaxAddressingMode::IndirectY {
+                    mode: AM::IndirectY::new(fetch, &mut program_counter),
+                };
+                Instruction::Lax { addressing_mode }
+            }
+            SAX_ZERO_PAGE => {
+                let addressing_mode = SaxAddressingMode::ZeroPage {
+                    mode: AM::ZeroPage::new(fetch, &mut program_counter),
+                };
+                Instruction::Sax { addressing_mode }
+            }
+            SAX_ZERO_PAGE_Y => {
+                let addressing_mode = SaxAddressingMode::ZeroPageY {
+                    mode: AM::ZeroPageY::new(fetch, &mut program_counter),
+                };
+                Instruction::Sax { addressing_mode }
+            }
+            SAX_ABSOLUTE => {
+                let addressing_mode = SaxAddressingMode::Absolute {
+                    mode: AM::Absolute::new(fetch, &mut program_counter),
+                };
+                Instruction::Sax { addressing_mode }
+            }
+            SAX_INDIRECT_X => {
+                let addressing_mode = SaxAddressingMode::IndirectX {
+                    mode: AM::IndirectX::new(fetch, &mut program_counter),
+                };
+                Instruction::Sax { addressing_mode }
+            }
+            DCP_ZERO_PAGE => {
+                let addressing_mode = DcpAddressingMode::ZeroPage {
+                    mode: AM::ZeroPage::new(fetch, &mut program_counter),
+                };
+                Instruction::Dcp { addressing_mode }
+            }
+            DCP_ZERO_PAGE_X => {
+                let addressing_mode = DcpAddressingMode::ZeroPageX {
+                    mode: AM::ZeroPageX::new(fetch, &mut program_counter),
+                };
+                Instruction::Dcp { addressing_mode }
+            }
+            DCP_ABSOLUTE => {
+                let addressing_mode = DcpAddressingMode::Absolute {
+                    mode: AM::Absolute::new(fetch, &mut program_counter),
+                };
+                Instruction::Dcp { addressing_mode }
+            }
+            DCP_ABSOLUTE_X => {
+                let addressing_mode = DcpAddressingMode::AbsoluteX {
+                    mode: AM::AbsoluteX::new(fetch, &mut program_counter),
+                };
+                Instruction::Dcp { addressing_mode }
+            }
+            DCP_ABSOLUTE_Y => {
+                let addressing_mode = DcpAddressingMode::AbsoluteY {
+                    mode: AM::AbsoluteY::new(fetch, &mut program_counter),
+                };
+                Instruction::Dcp { addressing_mode }
+            }
+            DCP_INDIRECT_X => {
+                let addressing_mode = DcpAddressingMode::IndirectX {
+                    mode: AM::IndirectX::new(fetch, &mut program_counter),
+                };
+                Instruction::Dcp { addressing_mode }
+            }
+            DCP_INDIRECT_Y => {
+                let addressing_mode = DcpAddressingMode::IndirectY {
+                    mode: AM::IndirectY::new(fetch, &mut program_counter),
+                };
+                Instruction::Dcp { addressing_mode }
+            }
+            ISB_ZERO_PAGE => {
+                let addressing_mode = IsbAddressingMode::ZeroPage {
+                    mode: AM::ZeroPage::new(fetch, &mut program_counter),
+                };
+                Instruction::Isb { addressing_mode }
+            }
+            ISB_ZERO_PAGE_X => {
+                let addressing_mode = IsbAddressingMode::ZeroPageX {
+                    mode: AM::ZeroPageX::new(fetch, &mut program_counter),
+                };
+                Instruction::Isb { addressing_mode }
+            }
+            ISB_ABSOLUTE => {
+                let addressing_mode = IsbAddressingMode::Absolute {
+                    mode: AM::Absolute::new(fetch, &mut program_counter),
+                };
+                Instruction::Isb { addressing_mode }
+            }
+            ISB_ABSOLUTE_X => {
+                let addressing_mode = IsbAddressingMode::AbsoluteX {
+                    mode: AM::AbsoluteX::new(fetch, &mut program_counter),
+                };
+                Instruction::Isb { addressing_mode }
+            }
+            ISB_ABSOLUTE_Y => {
+                let addressing_mode = IsbAddressingMode::AbsoluteY {
+                    mode: AM::AbsoluteY::new(fetch, &mut program_counter),
+                };
+                Instruction::Isb { addressing_mode }
+            }
+            ISB_INDIRECT_X => {
+                let addressing_mode = IsbAddressingMode::IndirectX {
+                    mode: AM::IndirectX::new(fetch, &mut program_counter),
+                };
+                Instruction::Isb { addressing_mode }
+            }
+            ISB_INDIRECT_Y => {
+                let addressing_mode = IsbAddressingMode::IndirectY {
+                    mode: AM::IndirectY::new(fetch, &mut program_counter),
+                };
+                Instruction::Isb { addressing_mode }
+            }
+            SLO_ZERO_PAGE => {
+                let addressing_mode = SloAddressingMode::ZeroPage {
+                    mode: AM::ZeroPage::new(fetch, &mut program_counter),
+                };
+                Instruction::Slo { addressing_mode }
+            }
+            SLO_ZERO_PAGE_X => {
+                let addressing_mode = SloAddressingMode::ZeroPageX {
+                    mode: AM::ZeroPageX::new(fetch, &mut program_counter),
+                };
+                Instruction::Slo { addressing_mode }
+            }
+            SLO_ABSOLUTE => {
+                let addressing_mode = SloAddressingMode::Absolute {
+                    mode: AM::Absolute::new(fetch, &mut program_counter),
+                };
+                Instruction::Slo { addressing_mode }
+            }
+            SLO_ABSOLUTE_X => {
+                let addressing_mode = SloAddressingMode::AbsoluteX {
+                    mode: AM::AbsoluteX::new(fetch, &mut program_counter),
+                };
+                Instruction::Slo { addressing_mode }
+            }
+            SLO_ABSOLUTE_Y => {
+                let addressing_mode = SloAddressingMode::AbsoluteY {
+                    mode: AM::AbsoluteY::new(fetch, &mut program_counter),
+                };
+                Instruction::Slo { addressing_mode }
+            }
+            SLO_INDIRECT_X => {
+                let addressing_mode = SloAddressingMode::IndirectX {
+                    mode: AM::IndirectX::new(fetch, &mut program_counter),
+                };
+                Instruction::Slo { addressing_mode }
+            }
+            SLO_INDIRECT_Y => {
+                let addressing_mode = SloAddressingMode::IndirectY {
+                    mode: AM::IndirectY::new(fetch, &mut program_counter),
+                };
+                Instruction::Slo { addressing_mode }
+            }
+            RLA_ZERO_PAGE => {
+                let addressing_mode = RlaAddressingMode::ZeroPage {
+                    mode: AM::ZeroPage::new(fetch, &mut program_counter),
+                };
+                Instruction::Rla { addressing_mode }
+            }
+            RLA_ZERO_PAGE_X => {
+                let addressing_mode = RlaAddressingMode::ZeroPageX {
+                    mode: AM::ZeroPageX::new(fetch, &mut program_counter),
+                };
+                Instruction::Rla { addressing_mode }
+            }
+            RLA_ABSOLUTE => {
+                let addressing_mode = RlaAddressingMode::Absolute {
+                    mode: AM::Absolute::new(fetch, &mut program_counter),
+                };
+                Instruction::Rla { addressing_mode }
+            }
+            RLA_ABSOLUTE_X => {
+                let addressing_mode = RlaAddressingMode::AbsoluteX {
+                    mode: AM::AbsoluteX::new(fetch, &mut program_counter),
+                };
+                Instruction::Rla { addressing_mode }
+            }
+            RLA_ABSOLUTE_Y => {
+                let addressing_mode = RlaAddressingMode::AbsoluteY {
+                    mode: AM::AbsoluteY::new(fetch, &mut program_counter),
+                };
+                Instruction::Rla { addressing_mode }
+            }
+            RLA_INDIRECT_X => {
+                let addressing_mode = RlaAddressingMode::IndirectX {
+                    mode: AM::IndirectX::new(fetch, &mut program_counter),
+                };
+                Instruction::Rla { addressing_mode }
+            }
+            RLA_INDIRECT_Y => {
+                let addressing_mode = RlaAddressingMode::IndirectY {
+                    mode: AM::IndirectY::new(fetch, &mut program_counter),
+                };
+                Instruction::Rla { addressing_mode }
+            }
+            SRE_ZERO_PAGE => {
+                let addressing_mode = SreAddressingMode::ZeroPage {
+                    mode: AM::ZeroPage::new(fetch, &mut program_counter),
+                };
+                Instruction::Sre { addressing_mode }
+            }
+            SRE_ZERO_PAGE_X => {
+                let addressing_mode = SreAddressingMode::ZeroPageX {
+                    mode: AM::ZeroPageX::new(fetch, &mut program_counter),
+                };
+                Instruction::Sre { addressing_mode }
+            }
+            SRE_ABSOLUTE => {
+                let addressing_mode = SreAddressingMode::Absolute {
+                    mode: AM::Absolute::new(fetch, &mut program_counter),
+                };
+                Instruction::Sre { addressing_mode }
+            }
+            SRE_ABSOLUTE_X => {
+                let addressing_mode = SreAddressingMode::AbsoluteX {
+                    mode: AM::AbsoluteX::new(fetch, &mut program_counter),
+                };
+                Instruction::Sre { addressing_mode }
+            }
+            SRE_ABSOLUTE_Y => {
+                let addressing_mode = SreAddressingMode::AbsoluteY {
+                    mode: AM::AbsoluteY::new(fetch, &mut program_counter),
+                };
+                Instruction::Sre { addressing_mode }
+            }
+            SRE_INDIRECT_X => {
+                let addressing_mode = SreAddressingMode::IndirectX {
+                    mode: AM::IndirectX::new(fetch, &mut program_counter),
+                };
+                Instruction::Sre { addressing_mode }
+            }
+            SRE_INDIRECT_Y => {
+                let addressing_mode = SreAddressingMode::IndirectY {
+                    mode: AM::IndirectY::new(fetch, &mut program_counter),
+                };
+                Instruction::Sre { addressing_mode }
+            }
+            RRA_ZERO_PAGE => {
+                let addressing_mode = RraAddressingMode::ZeroPage {
+                    mode: AM::ZeroPage::new(fetch, &mut program_counter),
+                };
+                Instruction::Rra { addressing_mode }
+            }
+            RRA_ZERO_PAGE_X => {
+                let addressing_mode = RraAddressingMode::ZeroPageX {
+                    mode: AM::ZeroPageX::new(fetch, &mut program_counter),
+                };
+                Instruction::Rra { addressing_mode }
+            }
+            RRA_ABSOLUTE => {
+                let addressing_mode = RraAddressingMode::Absolute {
+                    mode: AM::Absolute::new(fetch, &mut program_counter),
+                };
+                Instruction::Rra { addressing_mode }
+            }
+            RRA_ABSOLUTE_X => {
+                let addressing_mode = RraAddressingMode::AbsoluteX {
+                    mode: AM::AbsoluteX::new(fetch, &mut program_counter),
+                };
+                Instruction::Rra { addressing_mode }
+            }
+            RRA_ABSOLUTE_Y => {
+                let addressing_mode = RraAddressingMode::AbsoluteY {
+                    mode: AM::AbsoluteY::new(fetch, &mut program_counter),
+                };
+                Instruction::Rra { addressing_mode }
+            }
+            RRA_INDIRECT_X => {
+                let addressing_mode = RraAddressingMode::IndirectX {
+                    mode: AM::IndirectX::new(fetch, &mut program_counter),
+                };
+                Instruction::Rra { addressing_mode }
+            }
+            RRA_INDIRECT_Y => {
+                let addressing_mode = RraAddressingMode::IndirectY {
+                    mode: AM::IndirectY::new(fetch, &mut program_counter),
+                };
+                Instruction::Rra { addressing_mode }
+            }
+            code => {
+                return Err(InstructionError::InvalidInstructionCode { code });
+            }
+        };
+
+        Ok((instruction, program_counter))
+    }
+}
+
+impl Instruction {
+    /// Decodes the opcodes added by the 65C02.
+    fn get_cmos_instruction(
+        instruction: u8,
+        fetch: &mut impl FnMut(u16) -> u8,
+        program_counter: &mut u16,
+    ) -> Option<Instruction> {
+        use opcodes::*;
+
+        let instruction = match instruction {
+            BRA => Instruction::Bra {
+                addressing_mode: AM::Relative::new(fetch, program_counter),
+            },
+            PHX => Instruction::Push {
+                origin: Register::X,
+            },
+            PHY => Instruction::Push {
+                origin: Register::Y,
+            },
+            PLX => Instruction::Pull {
+                destination: Register::X,
+            },
+            PLY => Instruction::Pull {
+                destination: Register::Y,
+            },
+            STZ_ZERO_PAGE => Instruction::Stz {
+                addressing_mode: StzAddressingMode::ZeroPage {
+                    mode: AM::ZeroPage::new(fetch, program_counter),
+                },
+            },
+            STZ_ZERO_PAGE_X => Instruction::Stz {
+                addressing_mode: StzAddressingMode::ZeroPageX {
+                    mode: AM::ZeroPageX::new(fetch, program_counter),
+                },
+            },
+            STZ_ABSOLUTE => Instruction::Stz {
+                addressing_mode: StzAddressingMode::Absolute {
+                    mode: AM::Absolute::new(fetch, program_counter),
+                },
+            },
+            STZ_ABSOLUTE_X => Instruction::Stz {
+                addressing_mode: StzAddressingMode::AbsoluteX {
+                    mode: AM::AbsoluteX::new(fetch, program_counter),
+                },
+            },
+            TRB_ZERO_PAGE => Instruction::Trb {
+                addressing_mode: TrbAddressingMode::ZeroPage {
+                    mode: AM::ZeroPage::new(fetch, program_counter),
+                },
+            },
+            TRB_ABSOLUTE => Instruction::Trb {
+                addressing_mode: TrbAddressingMode::Absolute {
+                    mode: AM::Absolute::new(fetch, program_counter),
+                },
+            },
+            TSB_ZERO_PAGE => Instruction::Tsb {
+                addressing_mode: TsbAddressingMode::ZeroPage {
+                    mode: AM::ZeroPage::new(fetch, program_counter),
+                },
+            },
+            TSB_ABSOLUTE => Instruction::Tsb {
+                addressing_mode: TsbAddressingMode::Absolute {
+                    mode: AM::Absolute::new(fetch, program_counter),
+                },
+            },
+            ADC_ZERO_PAGE_INDIRECT => Instruction::Adc {
+                addressing_mode: AdcAddressingMode::AdcAddressAddressingMode {
+                    mode: AdcAddressAddressingMode::ZeroPageIndirect {
+                        mode: AM::ZeroPageIndirect::new(fetch, program_counter),
+                    },
+                },
+            },
+            AND_ZERO_PAGE_INDIRECT => Instruction::And {
+                addressing_mode: AndAddressingMode::AndAddressAddressingMode {
+                    mode: AndAddressAddressingMode::ZeroPageIndirect {
+                        mode: AM::ZeroPageIndirect::new(fetch, program_counter),
+                    },
+                },
+            },
+            CMP_ZERO_PAGE_INDIRECT => Instruction::Cmp {
+                addressing_mode: CmpAddressingMode::CmpAddressAddressingMode {
+                    mode: CmpAddressAddressingMode::ZeroPageIndirect {
+                        mode: AM::ZeroPageIndirect::new(fetch, program_counter),
+                    },
+                },
+            },
+            EOR_ZERO_PAGE_INDIRECT => Instruction::Eor {
+                addressing_mode: EorAddressingMode::EorAddressAddressingMode {
+                    mode: EorAddressAddressingMode::ZeroPageIndirect {
+                        mode: AM::ZeroPageIndirect::new(fetch, program_counter),
+                    },
+                },
+            },
+            LDA_ZERO_PAGE_INDIRECT => Instruction::Ld {
+                destination: Register::A,
+                addressing_mode: LdAddressingMode::LdAddressAddressingMode {
+                    mode: LdAddressAddressingMode::ZeroPageIndirect {
+                        mode: AM::ZeroPageIndirect::new(fetch, program_counter),
+                    },
+                },
+            },
+            ORA_ZERO_PAGE_INDIRECT => Instruction::Ora {
+                addressing_mode: OraAddressingMode::OraAddressAddressingMode {
+                    mode: OraAddressAddressingMode::ZeroPageIndirect {
+                        mode: AM::ZeroPageIndirect::new(fetch, program_counter),
+                    },
+                },
+            },
+            SBC_ZERO_PAGE_INDIRECT => Instruction::Sbc {
+                addressing_mode: SbcAddressingMode::SbcAddressAddressingMode {
+                    mode: SbcAddressAddressingMode::ZeroPageIndirect {
+                        mode: AM::ZeroPageIndirect::new(fetch, program_counter),
+                    },
+                },
+            },
+            STA_ZERO_PAGE_INDIRECT => Instruction::St {
+                origin: Register::A,
+                addressing_mode: StAddressingMode::ZeroPageIndirect {
+                    mode: AM::ZeroPageIndirect::new(fetch, program_counter),
+                },
+            },
+            INC_ACCUMULATOR => Instruction::In {
+                destination: Register::A,
+            },
+            DEC_ACCUMULATOR => Instruction::De {
+                destination: Register::A,
+            },
+            BIT_IMMEDIATE => Instruction::BitImmediate {
+                addressing_mode: AM::Immediate::new(fetch, program_counter),
+            },
+            BIT_ZERO_PAGE_X => Instruction::Bit {
+                addressing_mode: BitAddressingMode::ZeroPageX {
+                    mode: AM::ZeroPageX::new(fetch, program_counter),
+                },
+            },
+            BIT_ABSOLUTE_X => Instruction::Bit {
+                addressing_mode: BitAddressingMode::AbsoluteX {
+                    mode: AM::AbsoluteX::new(fetch, program_counter),
+                },
+            },
+            JMP_ABSOLUTE_X_INDIRECT => Instruction::Jmp {
+                addressing_mode: JmpAddressingMode::AbsoluteXIndirect {
+                    mode: AM::AbsoluteXIndirect::new(fetch, program_counter),
+                },
+            },
+            // The opcodes the 65C02 leaves undefined are NOPs that read as
+            // many operand bytes as the NMOS instructions there would.
+            JAM_02 | JAM_22 | JAM_42 | JAM_62 | NOP_IMMEDIATE_82 | NOP_IMMEDIATE_C2
+            | NOP_IMMEDIATE_E2 => Instruction::Ign {
+                addressing_mode: IgnAddressingMode::Immediate {
+                    mode: AM::Immediate::new(fetch, program_counter),
+                },
+            },
+            NOP_ZERO_PAGE_44 => Instruction::Ign {
+                addressing_mode: IgnAddressingMode::IgnAddressAddressingMode {
+                    mode: IgnAddressAddressingMode::ZeroPage {
+                        mode: AM::ZeroPage::new(fetch, program_counter),
+                    },
+                },
+            },
+            NOP_ZERO_PAGE_X_54 | NOP_ZERO_PAGE_X_D4 | NOP_ZERO_PAGE_X_F4 => Instruction::Ign {
+                addressing_mode: IgnAddressingMode::IgnAddressAddressingMode {
+                    mode: IgnAddressAddressingMode::ZeroPageX {
+                        mode: AM::ZeroPageX::new(fetch, program_counter),
+                    },
+                },
+            },
+            // Unlike on the NMOS chips, these aren't indexed.
+            NOP_ABSOLUTE_X_DC | NOP_ABSOLUTE_X_FC => Instruction::Ign {
+                addressing_mode: IgnAddressingMode::IgnAddressAddressingMode {
+                    mode: IgnAddressAddressingMode::Absolute {
+                        mode: AM::Absolute::new(fetch, program_counter),
+                    },
+                },
+            },
+            NOP_ABSOLUTE_X_5C => Instruction::LongNop {
+                addressing_mode: AM::Absolute::new(fetch, program_counter),
+            },
+            code if code & 0b11 == 0b11 => Instruction::SingleCycleNop,
+            _ => return None,
+        };
+
+        Some(instruction)
     }
 }
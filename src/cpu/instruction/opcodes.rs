@@ -119,6 +119,45 @@ pub const DEC_ABSOLUTE: u8 = 0xce;
 /// Decrement Memory (Absolute, X)
 pub const DEC_ABSOLUTE_X: u8 = 0xde;
 
+/// Decrement X
+pub const DEX: u8 = 0xca;
+/// Decrement Y
+pub const DEY: u8 = 0x88;
+
+/// Bit-wise Exclusive OR (Immediate)
+pub const EOR_IMMEDIATE: u8 = 0x49;
+/// Bit-wise Exclusive OR (Zero page)
+pub const EOR_ZERO_PAGE: u8 = 0x45;
+/// Bit-wise Exclusive OR (Zero page, X)
+pub const EOR_ZERO_PAGE_X: u8 = 0x55;
+/// Bit-wise Exclusive OR (Absolute)
+pub const EOR_ABSOLUTE: u8 = 0x4d;
+/// Bit-wise Exclusive OR (Absolute, X)
+pub const EOR_ABSOLUTE_X: u8 = 0x5d;
+/// Bit-wise Exclusive OR (Absolute, Y)
+pub const EOR_ABSOLUTE_Y: u8 = 0x59;
+/// Bit-wise Exclusive OR (Indirect, X)
+pub const EOR_INDIRECT_X: u8 = 0x41;
+/// Bit-wise Exclusive OR (Indirect, Y)
+pub const EOR_INDIRECT_Y: u8 = 0x51;
+
+/// Increment Memory (Zero Page)
+pub const INC_ZERO_PAGE: u8 = 0xe6;
+/// Increment Memory (Zero Page, X)
+pub const INC_ZERO_PAGE_X: u8 = 0xf6;
+/// Increment Memory (Absolute)
+pub const INC_ABSOLUTE: u8 = 0xee;
+/// Increment Memory (Absolute, X)
+pub const INC_ABSOLUTE_X: u8 = 0xfe;
+
+/// Jump (Absolute)
+pub const JMP_ABSOLUTE: u8 = 0x4c;
+/// Jump (Indirect)
+pub const JMP_INDIRECT: u8 = 0x6c;
+
+/// Jump to Subroutine
+pub const JSR: u8 = 0x20;
+
 /// Load to Accumulator (Immediate)
 pub const LDA_IMMEDIATE: u8 = 0xa9;
 /// Load to Accumulator (Zero page)
@@ -158,8 +197,572 @@ pub const LDY_ABSOLUTE: u8 = 0xac;
 /// Load to Y (Absolute, X)
 pub const LDY_ABSOLUTE_X: u8 = 0xbc;
 
+/// Logical Shift Right (Accumulator)
+pub const LSR_ACCUMULATOR: u8 = 0x4a;
+/// Logical Shift Right (Zero page)
+pub const LSR_ZERO_PAGE: u8 = 0x46;
+/// Logical Shift Right (Zero page, X)
+pub const LSR_ZERO_PAGE_X: u8 = 0x56;
+/// Logical Shift Right (Absolute)
+pub const LSR_ABSOLUTE: u8 = 0x4e;
+/// Logical Shift Right (Absolute, X)
+pub const LSR_ABSOLUTE_X: u8 = 0x5e;
+
+/// No Operation
+pub const NOP: u8 = 0xea;
+
+/// Bit-wise OR (Immediate)
+pub const ORA_IMMEDIATE: u8 = 0x09;
+/// Bit-wise OR (Zero page)
+pub const ORA_ZERO_PAGE: u8 = 0x05;
+/// Bit-wise OR (Zero page, X)
+pub const ORA_ZERO_PAGE_X: u8 = 0x15;
+/// Bit-wise OR (Absolute)
+pub const ORA_ABSOLUTE: u8 = 0x0d;
+/// Bit-wise OR (Absolute, X)
+pub const ORA_ABSOLUTE_X: u8 = 0x1d;
+/// Bit-wise OR (Absolute, Y)
+pub const ORA_ABSOLUTE_Y: u8 = 0x19;
+/// Bit-wise OR (Indirect, X)
+pub const ORA_INDIRECT_X: u8 = 0x01;
+/// Bit-wise OR (Indirect, Y)
+pub const ORA_INDIRECT_Y: u8 = 0x11;
+
+/// Push Accumulator
+pub const PHA: u8 = 0x48;
+/// Push Processor Status
+pub const PHP: u8 = 0x08;
+/// Pull Accumulator
+pub const PLA: u8 = 0x68;
+/// Pull Processor Status
+pub const PLP: u8 = 0x28;
+
+/// Rotate Left (Accumulator)
+pub const ROL_ACCUMULATOR: u8 = 0x2a;
+/// Rotate Left (Zero page)
+pub const ROL_ZERO_PAGE: u8 = 0x26;
+/// Rotate Left (Zero page, X)
+pub const ROL_ZERO_PAGE_X: u8 = 0x36;
+/// Rotate Left (Absolute)
+pub const ROL_ABSOLUTE: u8 = 0x2e;
+/// Rotate Left (Absolute, X)
+pub const ROL_ABSOLUTE_X: u8 = 0x3e;
+
+/// Rotate Right (Accumulator)
+pub const ROR_ACCUMULATOR: u8 = 0x6a;
+/// Rotate Right (Zero page)
+pub const ROR_ZERO_PAGE: u8 = 0x66;
+/// Rotate Right (Zero page, X)
+pub const ROR_ZERO_PAGE_X: u8 = 0x76;
+/// Rotate Right (Absolute)
+pub const ROR_ABSOLUTE: u8 = 0x6e;
+/// Rotate Right (Absolute, X)
+pub const ROR_ABSOLUTE_X: u8 = 0x7e;
+
+/// Return from Interrupt
+pub const RTI: u8 = 0x40;
+/// Return from Subroutine
+pub const RTS: u8 = 0x60;
+
+/// Subtract With Carry (Immediate)
+pub const SBC_IMMEDIATE: u8 = 0xe9;
+/// Subtract With Carry (Immediate), unofficial duplicate of SBC_IMMEDIATE
+pub const SBC_IMMEDIATE_UNOFFICIAL: u8 = 0xeb;
+/// Subtract With Carry (Zero page)
+pub const SBC_ZERO_PAGE: u8 = 0xe5;
+/// Subtract With Carry (Zero page, X)
+pub const SBC_ZERO_PAGE_X: u8 = 0xf5;
+/// Subtract With Carry (Absolute)
+pub const SBC_ABSOLUTE: u8 = 0xed;
+/// Subtract With Carry (Absolute, X)
+pub const SBC_ABSOLUTE_X: u8 = 0xfd;
+/// Subtract With Carry (Absolute, Y)
+pub const SBC_ABSOLUTE_Y: u8 = 0xf9;
+/// Subtract With Carry (Indirect, X)
+pub const SBC_INDIRECT_X: u8 = 0xe1;
+/// Subtract With Carry (Indirect, Y)
+pub const SBC_INDIRECT_Y: u8 = 0xf1;
+
+/// Set Carry Flag
+pub const SEC: u8 = 0x38;
+/// Set Decimal Flag
+pub const SED: u8 = 0xf8;
+/// Set Interrupt Disable
+pub const SEI: u8 = 0x78;
+
+/// Store Accumulator (Zero page)
+pub const STA_ZERO_PAGE: u8 = 0x85;
+/// Store Accumulator (Zero page, X)
+pub const STA_ZERO_PAGE_X: u8 = 0x95;
+/// Store Accumulator (Absolute)
+pub const STA_ABSOLUTE: u8 = 0x8d;
+/// Store Accumulator (Absolute, X)
+pub const STA_ABSOLUTE_X: u8 = 0x9d;
+/// Store Accumulator (Absolute, Y)
+pub const STA_ABSOLUTE_Y: u8 = 0x99;
+/// Store Accumulator (Indirect, X)
+pub const STA_INDIRECT_X: u8 = 0x81;
+/// Store Accumulator (Indirect, Y)
+pub const STA_INDIRECT_Y: u8 = 0x91;
+
+/// Store X (Zero page)
+pub const STX_ZERO_PAGE: u8 = 0x86;
+/// Store X (Zero page, Y)
+pub const STX_ZERO_PAGE_Y: u8 = 0x96;
+/// Store X (Absolute)
+pub const STX_ABSOLUTE: u8 = 0x8e;
+
+/// Store Y (Zero page)
+pub const STY_ZERO_PAGE: u8 = 0x84;
+/// Store Y (Zero page, X)
+pub const STY_ZERO_PAGE_X: u8 = 0x94;
+/// Store Y (Absolute)
+pub const STY_ABSOLUTE: u8 = 0x8c;
+
 /// Transfer Accumulator to X
 pub const TAX: u8 = 0xaa;
+/// Transfer Accumulator to Y
+pub const TAY: u8 = 0xa8;
+/// Transfer Stack Pointer to X
+pub const TSX: u8 = 0xba;
+/// Transfer X to Accumulator
+pub const TXA: u8 = 0x8a;
+/// Transfer X to Stack Pointer
+pub const TXS: u8 = 0x9a;
+/// Transfer Y to Accumulator
+pub const TYA: u8 = 0x98;
 
 /// Increment X
 pub const INX: u8 = 0xe8;
+/// Increment Y
+pub const INY: u8 = 0xc8;
+
+// Unofficial opcodes
+
+/// No Operation (Implied)
+pub const NOP_IMPLIED_1A: u8 = 0x1a;
+/// No Operation (Implied)
+pub const NOP_IMPLIED_3A: u8 = 0x3a;
+/// No Operation (Implied)
+pub const NOP_IMPLIED_5A: u8 = 0x5a;
+/// No Operation (Implied)
+pub const NOP_IMPLIED_7A: u8 = 0x7a;
+/// No Operation (Implied)
+pub const NOP_IMPLIED_DA: u8 = 0xda;
+/// No Operation (Implied)
+pub const NOP_IMPLIED_FA: u8 = 0xfa;
+
+/// No Operation (Immediate)
+pub const NOP_IMMEDIATE_80: u8 = 0x80;
+/// No Operation (Immediate)
+pub const NOP_IMMEDIATE_82: u8 = 0x82;
+/// No Operation (Immediate)
+pub const NOP_IMMEDIATE_89: u8 = 0x89;
+/// No Operation (Immediate)
+pub const NOP_IMMEDIATE_C2: u8 = 0xc2;
+/// No Operation (Immediate)
+pub const NOP_IMMEDIATE_E2: u8 = 0xe2;
+
+/// No Operation (Zero page)
+pub const NOP_ZERO_PAGE_04: u8 = 0x04;
+/// No Operation (Zero page)
+pub const NOP_ZERO_PAGE_44: u8 = 0x44;
+/// No Operation (Zero page)
+pub const NOP_ZERO_PAGE_64: u8 = 0x64;
+
+/// No Operation (Zero page, X)
+pub const NOP_ZERO_PAGE_X_14: u8 = 0x14;
+/// No Operation (Zero page, X)
+pub const NOP_ZERO_PAGE_X_34: u8 = 0x34;
+/// No Operation (Zero page, X)
+pub const NOP_ZERO_PAGE_X_54: u8 = 0x54;
+/// No Operation (Zero page, X)
+pub const NOP_ZERO_PAGE_X_74: u8 = 0x74;
+/// No Operation (Zero page, X)
+pub const NOP_ZERO_PAGE_X_D4: u8 = 0xd4;
+/// No Operation (Zero page, X)
+pub const NOP_ZERO_PAGE_X_F4: u8 = 0xf4;
+
+/// No Operation (Absolute)
+pub const NOP_ABSOLUTE_0C: u8 = 0x0c;
+
+/// No Operation (Absolute, X)
+pub const NOP_ABSOLUTE_X_1C: u8 = 0x1c;
+/// No Operation (Absolute, X)
+pub const NOP_ABSOLUTE_X_3C: u8 = 0x3c;
+/// No Operation (Absolute, X)
+pub const NOP_ABSOLUTE_X_5C: u8 = 0x5c;
+/// No Operation (Absolute, X)
+pub const NOP_ABSOLUTE_X_7C: u8 = 0x7c;
+/// No Operation (Absolute, X)
+pub const NOP_ABSOLUTE_X_DC: u8 = 0xdc;
+/// No Operation (Absolute, X)
+pub const NOP_ABSOLUTE_X_FC: u8 = 0xfc;
+
+/// Load to Accumulator and X (Zero page)
+pub const LAX_ZERO_PAGE: u8 = 0xa7;
+/// Load to Accumulator and X (Zero page, Y)
+pub const LAX_ZERO_PAGE_Y: u8 = 0xb7;
+/// Load to Accumulator and X (Absolute)
+pub const LAX_ABSOLUTE: u8 = 0xaf;
+/// Load to Accumulator and X (Absolute, Y)
+pub const LAX_ABSOLUTE_Y: u8 = 0xbf;
+/// Load to Accumulator and X (Indirect, X)
+pub const LAX_INDIRECT_X: u8 = 0xa3;
+/// Load to Accumulator and X (Indirect, Y)
+pub const LAX_INDIRECT_Y: u8 = 0xb3;
+
+/// Store Accumulator AND X (Zero page)
+pub const SAX_ZERO_PAGE: u8 = 0x87;
+/// Store Accumulator AND X (Zero page, Y)
+pub const SAX_ZERO_PAGE_Y: u8 = 0x97;
+/// Store Accumulator AND X (Absolute)
+pub const SAX_ABSOLUTE: u8 = 0x8f;
+/// Store Accumulator AND X (Indirect, X)
+pub const SAX_INDIRECT_X: u8 = 0x83;
+
+/// Decrement then Compare (Zero page)
+pub const DCP_ZERO_PAGE: u8 = 0xc7;
+/// Decrement then Compare (Zero page, X)
+pub const DCP_ZERO_PAGE_X: u8 = 0xd7;
+/// Decrement then Compare (Absolute)
+pub const DCP_ABSOLUTE: u8 = 0xcf;
+/// Decrement then Compare (Absolute, X)
+pub const DCP_ABSOLUTE_X: u8 = 0xdf;
+/// Decrement then Compare (Absolute, Y)
+pub const DCP_ABSOLUTE_Y: u8 = 0xdb;
+/// Decrement then Compare (Indirect, X)
+pub const DCP_INDIRECT_X: u8 = 0xc3;
+/// Decrement then Compare (Indirect, Y)
+pub const DCP_INDIRECT_Y: u8 = 0xd3;
+
+/// Increment then Subtract With Carry (Zero page)
+pub const ISB_ZERO_PAGE: u8 = 0xe7;
+/// Increment then Subtract With Carry (Zero page, X)
+pub const ISB_ZERO_PAGE_X: u8 = 0xf7;
+/// Increment then Subtract With Carry (Absolute)
+pub const ISB_ABSOLUTE: u8 = 0xef;
+/// Increment then Subtract With Carry (Absolute, X)
+pub const ISB_ABSOLUTE_X: u8 = 0xff;
+/// Increment then Subtract With Carry (Absolute, Y)
+pub const ISB_ABSOLUTE_Y: u8 = 0xfb;
+/// Increment then Subtract With Carry (Indirect, X)
+pub const ISB_INDIRECT_X: u8 = 0xe3;
+/// Increment then Subtract With Carry (Indirect, Y)
+pub const ISB_INDIRECT_Y: u8 = 0xf3;
+
+/// Arithmetic Shift Left then OR (Zero page)
+pub const SLO_ZERO_PAGE: u8 = 0x07;
+/// Arithmetic Shift Left then OR (Zero page, X)
+pub const SLO_ZERO_PAGE_X: u8 = 0x17;
+/// Arithmetic Shift Left then OR (Absolute)
+pub const SLO_ABSOLUTE: u8 = 0x0f;
+/// Arithmetic Shift Left then OR (Absolute, X)
+pub const SLO_ABSOLUTE_X: u8 = 0x1f;
+/// Arithmetic Shift Left then OR (Absolute, Y)
+pub const SLO_ABSOLUTE_Y: u8 = 0x1b;
+/// Arithmetic Shift Left then OR (Indirect, X)
+pub const SLO_INDIRECT_X: u8 = 0x03;
+/// Arithmetic Shift Left then OR (Indirect, Y)
+pub const SLO_INDIRECT_Y: u8 = 0x13;
+
+/// Rotate Left then AND (Zero page)
+pub const RLA_ZERO_PAGE: u8 = 0x27;
+/// Rotate Left then AND (Zero page, X)
+pub const RLA_ZERO_PAGE_X: u8 = 0x37;
+/// Rotate Left then AND (Absolute)
+pub const RLA_ABSOLUTE: u8 = 0x2f;
+/// Rotate Left then AND (Absolute, X)
+pub const RLA_ABSOLUTE_X: u8 = 0x3f;
+/// Rotate Left then AND (Absolute, Y)
+pub const RLA_ABSOLUTE_Y: u8 = 0x3b;
+/// Rotate Left then AND (Indirect, X)
+pub const RLA_INDIRECT_X: u8 = 0x23;
+/// Rotate Left then AND (Indirect, Y)
+pub const RLA_INDIRECT_Y: u8 = 0x33;
+
+/// Logical Shift Right then Exclusive OR (Zero page)
+pub const SRE_ZERO_PAGE: u8 = 0x47;
+/// Logical Shift Right then Exclusive OR (Zero page, X)
+pub const SRE_ZERO_PAGE_X: u8 = 0x57;
+/// Logical Shift Right then Exclusive OR (Absolute)
+pub const SRE_ABSOLUTE: u8 = 0x4f;
+/// Logical Shift Right then Exclusive OR (Absolute, X)
+pub const SRE_ABSOLUTE_X: u8 = 0x5f;
+/// Logical Shift Right then Exclusive OR (Absolute, Y)
+pub const SRE_ABSOLUTE_Y: u8 = 0x5b;
+/// Logical Shift Right then Exclusive OR (Indirect, X)
+pub const SRE_INDIRECT_X: u8 = 0x43;
+/// Logical Shift Right then Exclusive OR (Indirect, Y)
+pub const SRE_INDIRECT_Y: u8 = 0x53;
+
+/// Rotate Right then Add With Carry (Zero page)
+pub const RRA_ZERO_PAGE: u8 = 0x67;
+/// Rotate Right then Add With Carry (Zero page, X)
+pub const RRA_ZERO_PAGE_X: u8 = 0x77;
+/// Rotate Right then Add With Carry (Absolute)
+pub const RRA_ABSOLUTE: u8 = 0x6f;
+/// Rotate Right then Add With Carry (Absolute, X)
+pub const RRA_ABSOLUTE_X: u8 = 0x7f;
+/// Rotate Right then Add With Carry (Absolute, Y)
+pub const RRA_ABSOLUTE_Y: u8 = 0x7b;
+/// Rotate Right then Add With Carry (Indirect, X)
+pub const RRA_INDIRECT_X: u8 = 0x63;
+/// Rotate Right then Add With Carry (Indirect, Y)
+pub const RRA_INDIRECT_Y: u8 = 0x73;
+
+/// Jam, locks the CPU up
+pub const JAM_02: u8 = 0x02;
+/// Jam, locks the CPU up
+pub const JAM_12: u8 = 0x12;
+/// Jam, locks the CPU up
+pub const JAM_22: u8 = 0x22;
+/// Jam, locks the CPU up
+pub const JAM_32: u8 = 0x32;
+/// Jam, locks the CPU up
+pub const JAM_42: u8 = 0x42;
+/// Jam, locks the CPU up
+pub const JAM_52: u8 = 0x52;
+/// Jam, locks the CPU up
+pub const JAM_62: u8 = 0x62;
+/// Jam, locks the CPU up
+pub const JAM_72: u8 = 0x72;
+/// Jam, locks the CPU up
+pub const JAM_92: u8 = 0x92;
+/// Jam, locks the CPU up
+pub const JAM_B2: u8 = 0xb2;
+/// Jam, locks the CPU up
+pub const JAM_D2: u8 = 0xd2;
+/// Jam, locks the CPU up
+pub const JAM_F2: u8 = 0xf2;
+
+/// Whether `code` is one of the unofficial opcodes above.
+pub fn is_unofficial(code: u8) -> bool {
+    matches!(
+        code,
+        SBC_IMMEDIATE_UNOFFICIAL
+            | JAM_02
+            | JAM_12
+            | JAM_22
+            | JAM_32
+            | JAM_42
+            | JAM_52
+            | JAM_62
+            | JAM_72
+            | JAM_92
+            | JAM_B2
+            | JAM_D2
+            | JAM_F2
+            | NOP_IMPLIED_1A
+            | NOP_IMPLIED_3A
+            | NOP_IMPLIED_5A
+            | NOP_IMPLIED_7A
+            | NOP_IMPLIED_DA
+            | NOP_IMPLIED_FA
+            | NOP_IMMEDIATE_80
+            | NOP_IMMEDIATE_82
+            | NOP_IMMEDIATE_89
+            | NOP_IMMEDIATE_C2
+            | NOP_IMMEDIATE_E2
+            | NOP_ZERO_PAGE_04
+            | NOP_ZERO_PAGE_44
+            | NOP_ZERO_PAGE_64
+            | NOP_ZERO_PAGE_X_14
+            | NOP_ZERO_PAGE_X_34
+            | NOP_ZERO_PAGE_X_54
+            | NOP_ZERO_PAGE_X_74
+            | NOP_ZERO_PAGE_X_D4
+            | NOP_ZERO_PAGE_X_F4
+            | NOP_ABSOLUTE_0C
+            | NOP_ABSOLUTE_X_1C
+            | NOP_ABSOLUTE_X_3C
+            | NOP_ABSOLUTE_X_5C
+            | NOP_ABSOLUTE_X_7C
+            | NOP_ABSOLUTE_X_DC
+            | NOP_ABSOLUTE_X_FC
+            | LAX_ZERO_PAGE
+            | LAX_ZERO_PAGE_Y
+            | LAX_ABSOLUTE
+            | LAX_ABSOLUTE_Y
+            | LAX_INDIRECT_X
+            | LAX_INDIRECT_Y
+            | SAX_ZERO_PAGE
+            | SAX_ZERO_PAGE_Y
+            | SAX_ABSOLUTE
+            | SAX_INDIRECT_X
+            | DCP_ZERO_PAGE
+            | DCP_ZERO_PAGE_X
+            | DCP_ABSOLUTE
+            | DCP_ABSOLUTE_X
+            | DCP_ABSOLUTE_Y
+            | DCP_INDIRECT_X
+            | DCP_INDIRECT_Y
+            | ISB_ZERO_PAGE
+            | ISB_ZERO_PAGE_X
+            | ISB_ABSOLUTE
+            | ISB_ABSOLUTE_X
+            | ISB_ABSOLUTE_Y
+            | ISB_INDIRECT_X
+            | ISB_INDIRECT_Y
+            | SLO_ZERO_PAGE
+            | SLO_ZERO_PAGE_X
+            | SLO_ABSOLUTE
+            | SLO_ABSOLUTE_X
+            | SLO_ABSOLUTE_Y
+            | SLO_INDIRECT_X
+            | SLO_INDIRECT_Y
+            | RLA_ZERO_PAGE
+            | RLA_ZERO_PAGE_X
+            | RLA_ABSOLUTE
+            | RLA_ABSOLUTE_X
+            | RLA_ABSOLUTE_Y
+            | RLA_INDIRECT_X
+            | RLA_INDIRECT_Y
+            | SRE_ZERO_PAGE
+            | SRE_ZERO_PAGE_X
+            | SRE_ABSOLUTE
+            | SRE_ABSOLUTE_X
+            | SRE_ABSOLUTE_Y
+            | SRE_INDIRECT_X
+            | SRE_INDIRECT_Y
+            | RRA_ZERO_PAGE
+            | RRA_ZERO_PAGE_X
+            | RRA_ABSOLUTE
+            | RRA_ABSOLUTE_X
+            | RRA_ABSOLUTE_Y
+            | RRA_INDIRECT_X
+            | RRA_INDIRECT_Y
+    )
+}
+
+// 65C02 opcodes
+
+/// BRA
+pub const BRA: u8 = 0x80;
+
+/// PHX
+pub const PHX: u8 = 0xda;
+/// PHY
+pub const PHY: u8 = 0x5a;
+/// PLX
+pub const PLX: u8 = 0xfa;
+/// PLY
+pub const PLY: u8 = 0x7a;
+
+/// STZ (Zero Page)
+pub const STZ_ZERO_PAGE: u8 = 0x64;
+/// STZ (Zero Page, X)
+pub const STZ_ZERO_PAGE_X: u8 = 0x74;
+/// STZ (Absolute)
+pub const STZ_ABSOLUTE: u8 = 0x9c;
+/// STZ (Absolute, X)
+pub const STZ_ABSOLUTE_X: u8 = 0x9e;
+
+/// TRB (Zero Page)
+pub const TRB_ZERO_PAGE: u8 = 0x14;
+/// TRB (Absolute)
+pub const TRB_ABSOLUTE: u8 = 0x1c;
+
+/// TSB (Zero Page)
+pub const TSB_ZERO_PAGE: u8 = 0x04;
+/// TSB (Absolute)
+pub const TSB_ABSOLUTE: u8 = 0x0c;
+
+/// ADC (Zero Page Indirect)
+pub const ADC_ZERO_PAGE_INDIRECT: u8 = 0x72;
+/// AND (Zero Page Indirect)
+pub const AND_ZERO_PAGE_INDIRECT: u8 = 0x32;
+/// CMP (Zero Page Indirect)
+pub const CMP_ZERO_PAGE_INDIRECT: u8 = 0xd2;
+/// EOR (Zero Page Indirect)
+pub const EOR_ZERO_PAGE_INDIRECT: u8 = 0x52;
+/// LDA (Zero Page Indirect)
+pub const LDA_ZERO_PAGE_INDIRECT: u8 = 0xb2;
+/// ORA (Zero Page Indirect)
+pub const ORA_ZERO_PAGE_INDIRECT: u8 = 0x12;
+/// SBC (Zero Page Indirect)
+pub const SBC_ZERO_PAGE_INDIRECT: u8 = 0xf2;
+/// STA (Zero Page Indirect)
+pub const STA_ZERO_PAGE_INDIRECT: u8 = 0x92;
+
+/// INC (Accumulator)
+pub const INC_ACCUMULATOR: u8 = 0x1a;
+/// DEC (Accumulator)
+pub const DEC_ACCUMULATOR: u8 = 0x3a;
+
+/// BIT (Immediate)
+pub const BIT_IMMEDIATE: u8 = 0x89;
+/// BIT (Zero Page, X)
+pub const BIT_ZERO_PAGE_X: u8 = 0x34;
+/// BIT (Absolute, X)
+pub const BIT_ABSOLUTE_X: u8 = 0x3c;
+
+/// JMP (Absolute, X Indirect)
+pub const JMP_ABSOLUTE_X_INDIRECT: u8 = 0x7c;
+
+/// Base cycle count of every opcode, indexed by opcode. Page-cross and
+/// taken-branch penalties are added on top while executing.
+#[rustfmt::skip]
+pub const CYCLES: [u8; 0x100] = [
+    // 0  1  2  3  4  5  6  7  8  9  a  b  c  d  e  f
+    7, 6, 2, 8, 3, 3, 5, 5, 3, 2, 2, 2, 4, 4, 6, 6, // 0
+    2, 5, 2, 8, 4, 4, 6, 6, 2, 4, 2, 7, 4, 4, 7, 7, // 1
+    6, 6, 2, 8, 3, 3, 5, 5, 4, 2, 2, 2, 4, 4, 6, 6, // 2
+    2, 5, 2, 8, 4, 4, 6, 6, 2, 4, 2, 7, 4, 4, 7, 7, // 3
+    6, 6, 2, 8, 3, 3, 5, 5, 3, 2, 2, 2, 3, 4, 6, 6, // 4
+    2, 5, 2, 8, 4, 4, 6, 6, 2, 4, 2, 7, 4, 4, 7, 7, // 5
+    6, 6, 2, 8, 3, 3, 5, 5, 4, 2, 2, 2, 5, 4, 6, 6, // 6
+    2, 5, 2, 8, 4, 4, 6, 6, 2, 4, 2, 7, 4, 4, 7, 7, // 7
+    2, 6, 2, 6, 3, 3, 3, 3, 2, 2, 2, 2, 4, 4, 4, 4, // 8
+    2, 6, 2, 6, 4, 4, 4, 4, 2, 5, 2, 5, 5, 5, 5, 5, // 9
+    2, 6, 2, 6, 3, 3, 3, 3, 2, 2, 2, 2, 4, 4, 4, 4, // a
+    2, 5, 2, 5, 4, 4, 4, 4, 2, 4, 2, 4, 4, 4, 4, 4, // b
+    2, 6, 2, 8, 3, 3, 5, 5, 2, 2, 2, 2, 4, 4, 6, 6, // c
+    2, 5, 2, 8, 4, 4, 6, 6, 2, 4, 2, 7, 4, 4, 7, 7, // d
+    2, 6, 2, 8, 3, 3, 5, 5, 2, 2, 2, 2, 4, 4, 6, 6, // e
+    2, 5, 2, 8, 4, 4, 6, 6, 2, 4, 2, 7, 4, 4, 7, 7, // f
+];
+
+/// Base cycle count of every opcode on the 65C02. Besides the opcodes it
+/// added, the fixed JMP indirect takes a cycle more, and shifts and rotates
+/// on absolute,X one less unless they cross a page. The opcodes it leaves
+/// undefined are NOPs, most of them of a single cycle.
+pub const CMOS_CYCLES: [u8; 0x100] = {
+    let mut cycles = CYCLES;
+    let mut code = 0;
+    while code < 0x100 {
+        if code & 0b11 == 0b11 {
+            cycles[code] = 1;
+        }
+        code += 1;
+    }
+    cycles[NOP_ABSOLUTE_X_5C as usize] = 8;
+    cycles[JMP_ABSOLUTE_X_INDIRECT as usize] = 6;
+    cycles[BRA as usize] = 3;
+    cycles[PHX as usize] = 3;
+    cycles[PHY as usize] = 3;
+    cycles[PLX as usize] = 4;
+    cycles[PLY as usize] = 4;
+    cycles[STZ_ZERO_PAGE as usize] = 3;
+    cycles[STZ_ZERO_PAGE_X as usize] = 4;
+    cycles[STZ_ABSOLUTE as usize] = 4;
+    cycles[STZ_ABSOLUTE_X as usize] = 5;
+    cycles[TRB_ZERO_PAGE as usize] = 5;
+    cycles[TRB_ABSOLUTE as usize] = 6;
+    cycles[TSB_ZERO_PAGE as usize] = 5;
+    cycles[TSB_ABSOLUTE as usize] = 6;
+    cycles[ADC_ZERO_PAGE_INDIRECT as usize] = 5;
+    cycles[AND_ZERO_PAGE_INDIRECT as usize] = 5;
+    cycles[CMP_ZERO_PAGE_INDIRECT as usize] = 5;
+    cycles[EOR_ZERO_PAGE_INDIRECT as usize] = 5;
+    cycles[LDA_ZERO_PAGE_INDIRECT as usize] = 5;
+    cycles[ORA_ZERO_PAGE_INDIRECT as usize] = 5;
+    cycles[SBC_ZERO_PAGE_INDIRECT as usize] = 5;
+    cycles[STA_ZERO_PAGE_INDIRECT as usize] = 5;
+    cycles[JMP_INDIRECT as usize] = 6;
+    cycles[ASL_ABSOLUTE_X as usize] = 6;
+    cycles[LSR_ABSOLUTE_X as usize] = 6;
+    cycles[ROL_ABSOLUTE_X as usize] = 6;
+    cycles[ROR_ABSOLUTE_X as usize] = 6;
+    cycles
+};
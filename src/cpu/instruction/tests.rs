@@ -1,18 +1,43 @@
 use super::addressing_mode as AM;
 use super::*;
 use crate::cpu::status::Flag;
-use crate::cpu::Cpu;
+use crate::cpu::{memory::Memory, Cpu, CpuConfig};
 
-fn get_instruction(instructions: &[u8]) -> color_eyre::Result<(Instruction, u16)> {
+fn get_instruction(instructions: &[u8]) -> Result<(Instruction, u16), InstructionError> {
     let mut memory = Memory::new();
     memory.load(0x8000, instructions)?;
-    let instruction = Instruction::get_instruction(&memory, &0x8000)?;
+    let instruction = Instruction::get_instruction(&memory, &0x8000, &CpuConfig::default())?;
     Ok(instruction)
 }
 
 #[test]
 fn dec() {
-    todo!("DEC tests")
+    use super::opcodes::{DEC_ABSOLUTE, DEC_ZERO_PAGE, LDA_IMMEDIATE, STA_ZERO_PAGE};
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[
+        LDA_IMMEDIATE,
+        0x02,
+        STA_ZERO_PAGE,
+        0x10,
+        DEC_ZERO_PAGE,
+        0x10,
+        DEC_ABSOLUTE,
+        0x10,
+        0x00,
+        0x00,
+    ])
+    .unwrap();
+    assert_eq!(cpu.bus.read(0x10), 0x00);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[DEC_ZERO_PAGE, 0x10, DEC_ZERO_PAGE, 0x10, 0x00])
+        .unwrap();
+    assert_eq!(cpu.bus.read(0x10), 0xfe);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
 }
 
 #[test]
@@ -28,7 +53,7 @@ fn clc() {
     cpu.load_and_run_test(&[CLC, 0x00]).unwrap();
     assert!(!cpu.status.get(Flag::Carry));
     assert!(!cpu.status.get(Flag::Decimal));
-    assert!(!cpu.status.get(Flag::InterruptDisable));
+    assert!(cpu.status.get(Flag::InterruptDisable));
     assert!(!cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Overflow));
     assert!(!cpu.status.get(Flag::Zero));
@@ -43,7 +68,7 @@ fn clc() {
     cpu.status.set(Flag::Negative, true);
     cpu.status.set(Flag::Overflow, true);
     cpu.status.set(Flag::Zero, true);
-    cpu.run().unwrap();
+    cpu.run_until_brk().unwrap();
     assert!(!cpu.status.get(Flag::Carry));
     assert!(cpu.status.get(Flag::Decimal));
     assert!(cpu.status.get(Flag::InterruptDisable));
@@ -70,7 +95,7 @@ fn cld() {
     cpu.load_and_run_test(&[CLD, 0x00]).unwrap();
     assert!(!cpu.status.get(Flag::Carry));
     assert!(!cpu.status.get(Flag::Decimal));
-    assert!(!cpu.status.get(Flag::InterruptDisable));
+    assert!(cpu.status.get(Flag::InterruptDisable));
     assert!(!cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Overflow));
     assert!(!cpu.status.get(Flag::Zero));
@@ -85,7 +110,7 @@ fn cld() {
     cpu.status.set(Flag::Negative, true);
     cpu.status.set(Flag::Overflow, true);
     cpu.status.set(Flag::Zero, true);
-    cpu.run().unwrap();
+    cpu.run_until_brk().unwrap();
     assert!(cpu.status.get(Flag::Carry));
     assert!(!cpu.status.get(Flag::Decimal));
     assert!(cpu.status.get(Flag::InterruptDisable));
@@ -127,7 +152,7 @@ fn cli() {
     cpu.status.set(Flag::Negative, true);
     cpu.status.set(Flag::Overflow, true);
     cpu.status.set(Flag::Zero, true);
-    cpu.run().unwrap();
+    cpu.run_until_brk().unwrap();
     assert!(cpu.status.get(Flag::Carry));
     assert!(cpu.status.get(Flag::Decimal));
     assert!(!cpu.status.get(Flag::InterruptDisable));
@@ -154,7 +179,7 @@ fn clv() {
     cpu.load_and_run_test(&[CLV, 0x00]).unwrap();
     assert!(!cpu.status.get(Flag::Carry));
     assert!(!cpu.status.get(Flag::Decimal));
-    assert!(!cpu.status.get(Flag::InterruptDisable));
+    assert!(cpu.status.get(Flag::InterruptDisable));
     assert!(!cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Overflow));
     assert!(!cpu.status.get(Flag::Zero));
@@ -169,7 +194,7 @@ fn clv() {
     cpu.status.set(Flag::Negative, true);
     cpu.status.set(Flag::Overflow, true);
     cpu.status.set(Flag::Zero, true);
-    cpu.run().unwrap();
+    cpu.run_until_brk().unwrap();
     assert!(cpu.status.get(Flag::Carry));
     assert!(cpu.status.get(Flag::Decimal));
     assert!(cpu.status.get(Flag::InterruptDisable));
@@ -179,338 +204,382 @@ fn clv() {
 }
 
 #[test]
-fn asl_accumulator() {
-    use super::opcodes::{ASL_ACCUMULATOR, LDA_IMMEDIATE};
+fn sec() {
+    use super::opcodes::SEC;
 
     assert!(matches!(
-        get_instruction(&[ASL_ACCUMULATOR]).unwrap(),
-        (
-            Instruction::Asl {
-                addressing_mode: AslAddressingMode::Accumulator {
-                    mode: AM::Accumulator {}
-                }
-            },
-            0x8001
-        )
+        get_instruction(&[SEC]).unwrap(),
+        (Instruction::Set { flag: Flag::Carry }, 0x8001)
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0b10000000, ASL_ACCUMULATOR, 0x00])
-        .unwrap();
-    assert_eq!(cpu.register_a, 0b00000000);
-    assert!(cpu.status.get(Flag::Zero));
+    cpu.load_and_run_test(&[SEC, 0x00]).unwrap();
     assert!(cpu.status.get(Flag::Carry));
+    assert!(!cpu.status.get(Flag::Decimal));
+    assert!(cpu.status.get(Flag::InterruptDisable));
     assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Overflow));
+    assert!(!cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0b0101_0101, ASL_ACCUMULATOR, 0x00])
-        .unwrap();
-    assert_eq!(cpu.register_a, 0b1010_1010);
-    assert!(!cpu.status.get(Flag::Zero));
+    cpu.load(&[SEC, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Carry, true);
+    cpu.status.set(Flag::Decimal, true);
+    cpu.status.set(Flag::InterruptDisable, true);
+    cpu.status.set(Flag::Negative, true);
+    cpu.status.set(Flag::Overflow, true);
+    cpu.status.set(Flag::Zero, true);
+    cpu.run_until_brk().unwrap();
+    assert!(cpu.status.get(Flag::Carry));
+    assert!(cpu.status.get(Flag::Decimal));
+    assert!(cpu.status.get(Flag::InterruptDisable));
     assert!(cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Carry));
+    assert!(cpu.status.get(Flag::Overflow));
+    assert!(cpu.status.get(Flag::Zero));
 }
 
 #[test]
-fn adc_immediate() {
-    use super::opcodes::{ADC_IMMEDIATE, LDA_IMMEDIATE};
+fn sed() {
+    use super::opcodes::SED;
+
     assert!(matches!(
-        get_instruction(&[ADC_IMMEDIATE, 0xc0]).unwrap(),
+        get_instruction(&[SED]).unwrap(),
         (
-            Instruction::Adc {
-                addressing_mode: AdcAddressingMode::Immediate {
-                    mode: AM::Immediate { immediate: 0xc0 }
-                }
+            Instruction::Set {
+                flag: Flag::Decimal
             },
-            0x8002
+            0x8001
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[ADC_IMMEDIATE, 0xf0, 0x00]).unwrap();
-    assert_eq!(cpu.register_a, 0xf0);
-    assert!(cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
-    assert!(!cpu.status.get(Flag::Overflow));
+    cpu.load_and_run_test(&[SED, 0x00]).unwrap();
     assert!(!cpu.status.get(Flag::Carry));
+    assert!(cpu.status.get(Flag::Decimal));
+    assert!(cpu.status.get(Flag::InterruptDisable));
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Overflow));
+    assert!(!cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0x71, ADC_IMMEDIATE, 0x72, 0x00])
-        .unwrap();
-    assert_eq!(cpu.register_a, u8::wrapping_add(0x71, 0x72));
+    cpu.load(&[SED, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Carry, true);
+    cpu.status.set(Flag::Decimal, true);
+    cpu.status.set(Flag::InterruptDisable, true);
+    cpu.status.set(Flag::Negative, true);
+    cpu.status.set(Flag::Overflow, true);
+    cpu.status.set(Flag::Zero, true);
+    cpu.run_until_brk().unwrap();
+    assert!(cpu.status.get(Flag::Carry));
+    assert!(cpu.status.get(Flag::Decimal));
+    assert!(cpu.status.get(Flag::InterruptDisable));
     assert!(cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
     assert!(cpu.status.get(Flag::Overflow));
-    assert!(!cpu.status.get(Flag::Carry));
+    assert!(cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn sei() {
+    use super::opcodes::SEI;
+
+    assert!(matches!(
+        get_instruction(&[SEI]).unwrap(),
+        (
+            Instruction::Set {
+                flag: Flag::InterruptDisable
+            },
+            0x8001
+        )
+    ));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0x80, ADC_IMMEDIATE, 0x80, 0x00])
-        .unwrap();
-    assert_eq!(cpu.register_a, 0x00);
+    cpu.load_and_run_test(&[SEI, 0x00]).unwrap();
+    assert!(!cpu.status.get(Flag::Carry));
+    assert!(!cpu.status.get(Flag::Decimal));
+    assert!(cpu.status.get(Flag::InterruptDisable));
     assert!(!cpu.status.get(Flag::Negative));
-    assert!(cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Overflow));
+    assert!(!cpu.status.get(Flag::Overflow));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[SEI, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Carry, true);
+    cpu.status.set(Flag::Decimal, true);
+    cpu.status.set(Flag::InterruptDisable, true);
+    cpu.status.set(Flag::Negative, true);
+    cpu.status.set(Flag::Overflow, true);
+    cpu.status.set(Flag::Zero, true);
+    cpu.run_until_brk().unwrap();
     assert!(cpu.status.get(Flag::Carry));
+    assert!(cpu.status.get(Flag::Decimal));
+    assert!(cpu.status.get(Flag::InterruptDisable));
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Overflow));
+    assert!(cpu.status.get(Flag::Zero));
 }
 
 #[test]
-fn and_immediate() {
-    use super::opcodes::{AND_IMMEDIATE, LDA_IMMEDIATE};
+fn asl_accumulator() {
+    use super::opcodes::{ASL_ACCUMULATOR, LDA_IMMEDIATE};
 
     assert!(matches!(
-        get_instruction(&[AND_IMMEDIATE, 0xc0]).unwrap(),
+        get_instruction(&[ASL_ACCUMULATOR]).unwrap(),
         (
-            Instruction::And {
-                addressing_mode: AndAddressingMode::Immediate {
-                    mode: AM::Immediate { immediate: 0xc0 }
+            Instruction::Asl {
+                addressing_mode: AslAddressingMode::Accumulator {
+                    mode: AM::Accumulator {}
                 }
             },
-            0x8002
+            0x8001
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[AND_IMMEDIATE, 0b11010011, 0x00])
+    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0b10000000, ASL_ACCUMULATOR, 0x00])
         .unwrap();
     assert_eq!(cpu.register_a, 0b00000000);
-    assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Carry));
+    assert!(!cpu.status.get(Flag::Negative));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0b11010011, AND_IMMEDIATE, 0b10110001, 0x00])
-        .unwrap();
-    assert_eq!(cpu.register_a, 0b10010001);
-    assert!(cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
-
-    let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0b00110111, AND_IMMEDIATE, 0b00110111, 0x00])
+    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0b0101_0101, ASL_ACCUMULATOR, 0x00])
         .unwrap();
-    assert_eq!(cpu.register_a, 0b00110111);
-    assert!(!cpu.status.get(Flag::Negative));
+    assert_eq!(cpu.register_a, 0b1010_1010);
     assert!(!cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Carry));
 }
 
 #[test]
-fn cmp_immediate() {
-    use super::opcodes::CMP_IMMEDIATE;
+fn lsr_accumulator() {
+    use super::opcodes::{LDA_IMMEDIATE, LSR_ACCUMULATOR};
 
     assert!(matches!(
-        get_instruction(&[CMP_IMMEDIATE, 0xc0]).unwrap(),
+        get_instruction(&[LSR_ACCUMULATOR]).unwrap(),
         (
-            Instruction::Cmp {
-                addressing_mode: CmpAddressingMode::Immediate {
-                    mode: AM::Immediate { immediate: 0xc0 }
+            Instruction::Lsr {
+                addressing_mode: LsrAddressingMode::Accumulator {
+                    mode: AM::Accumulator {}
                 }
             },
-            0x8002
+            0x8001
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[CMP_IMMEDIATE, 123]).unwrap();
+    cpu.load(&[LDA_IMMEDIATE, 0b1000_0001, LSR_ACCUMULATOR, 0x00])
+        .unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_a = 127;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 127);
-    assert!(!cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0100_0000);
     assert!(cpu.status.get(Flag::Carry));
-
-    let mut cpu = Cpu::new();
-    cpu.reset().unwrap();
-    cpu.load(&[CMP_IMMEDIATE, 200]).unwrap();
-    cpu.program_counter = 0x8000;
-    cpu.register_a = 127;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 127);
-    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
-    assert!(!cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0b0000_0001, LSR_ACCUMULATOR, 0x00])
+        .unwrap();
     cpu.reset().unwrap();
-    cpu.load(&[CMP_IMMEDIATE, 200]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_a = 200;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 200);
+    cpu.status.set(Flag::Carry, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0000_0000);
+    assert!(cpu.status.get(Flag::Carry));
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Carry));
 }
 
 #[test]
-fn cpx_immediate() {
-    use super::opcodes::CPX_IMMEDIATE;
+fn rol_accumulator() {
+    use super::opcodes::{LDA_IMMEDIATE, ROL_ACCUMULATOR};
 
     assert!(matches!(
-        get_instruction(&[CPX_IMMEDIATE, 0xc0]).unwrap(),
+        get_instruction(&[ROL_ACCUMULATOR]).unwrap(),
         (
-            Instruction::Cpx {
-                addressing_mode: CpxAddressingMode::Immediate {
-                    mode: AM::Immediate { immediate: 0xc0 }
+            Instruction::Rol {
+                addressing_mode: RolAddressingMode::Accumulator {
+                    mode: AM::Accumulator {}
                 }
             },
-            0x8002
+            0x8001
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[CPX_IMMEDIATE, 123]).unwrap();
+    cpu.load(&[LDA_IMMEDIATE, 0b1000_0000, ROL_ACCUMULATOR, 0x00])
+        .unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 127;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_x, 127);
+    cpu.status.set(Flag::Carry, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0000_0001);
+    assert!(cpu.status.get(Flag::Carry));
     assert!(!cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0b0100_0000, ROL_ACCUMULATOR, 0x00])
+        .unwrap();
     cpu.reset().unwrap();
-    cpu.load(&[CPX_IMMEDIATE, 200]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 127;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_x, 127);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b1000_0000);
+    assert!(!cpu.status.get(Flag::Carry));
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
-    assert!(!cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0b1000_0000, ROL_ACCUMULATOR, 0x00])
+        .unwrap();
     cpu.reset().unwrap();
-    cpu.load(&[CPX_IMMEDIATE, 200]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 200;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_x, 200);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0000_0000);
+    assert!(cpu.status.get(Flag::Carry));
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Carry));
 }
 
 #[test]
-fn cpy_immediate() {
-    use super::opcodes::CPY_IMMEDIATE;
+fn ror_accumulator() {
+    use super::opcodes::{LDA_IMMEDIATE, ROR_ACCUMULATOR};
 
     assert!(matches!(
-        get_instruction(&[CPY_IMMEDIATE, 0xc0]).unwrap(),
+        get_instruction(&[ROR_ACCUMULATOR]).unwrap(),
         (
-            Instruction::Cpy {
-                addressing_mode: CpyAddressingMode::Immediate {
-                    mode: AM::Immediate { immediate: 0xc0 }
+            Instruction::Ror {
+                addressing_mode: RorAddressingMode::Accumulator {
+                    mode: AM::Accumulator {}
                 }
             },
-            0x8002
+            0x8001
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[CPY_IMMEDIATE, 123]).unwrap();
+    cpu.load(&[LDA_IMMEDIATE, 0b0000_0001, ROR_ACCUMULATOR, 0x00])
+        .unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_y = 127;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_y, 127);
-    assert!(!cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
+    cpu.status.set(Flag::Carry, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b1000_0000);
     assert!(cpu.status.get(Flag::Carry));
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0b0000_0010, ROR_ACCUMULATOR, 0x00])
+        .unwrap();
     cpu.reset().unwrap();
-    cpu.load(&[CPY_IMMEDIATE, 200]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_y = 127;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_y, 127);
-    assert!(cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0000_0001);
     assert!(!cpu.status.get(Flag::Carry));
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0b0000_0001, ROR_ACCUMULATOR, 0x00])
+        .unwrap();
     cpu.reset().unwrap();
-    cpu.load(&[CPY_IMMEDIATE, 200]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_y = 200;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_y, 200);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0000_0000);
+    assert!(cpu.status.get(Flag::Carry));
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Carry));
 }
 
 #[test]
-fn lda_immediate() {
-    use super::opcodes::LDA_IMMEDIATE;
-
+fn adc_immediate() {
+    use super::opcodes::{ADC_IMMEDIATE, LDA_IMMEDIATE};
     assert!(matches!(
-        get_instruction(&[LDA_IMMEDIATE, 0xc0]).unwrap(),
+        get_instruction(&[ADC_IMMEDIATE, 0xc0]).unwrap(),
         (
-            Instruction::Ld {
-                destination: Register::A,
-                addressing_mode: LdAddressingMode::Immediate {
+            Instruction::Adc {
+                addressing_mode: AdcAddressingMode::Immediate {
                     mode: AM::Immediate { immediate: 0xc0 }
                 }
             },
-            0x8002,
+            0x8002
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0xf0, 0x00]).unwrap();
+    cpu.load_and_run_test(&[ADC_IMMEDIATE, 0xf0, 0x00]).unwrap();
     assert_eq!(cpu.register_a, 0xf0);
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Overflow));
+    assert!(!cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0x00, 0x00]).unwrap();
+    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0x71, ADC_IMMEDIATE, 0x72, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_a, u8::wrapping_add(0x71, 0x72));
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Overflow));
+    assert!(!cpu.status.get(Flag::Carry));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0x80, ADC_IMMEDIATE, 0x80, 0x00])
+        .unwrap();
     assert_eq!(cpu.register_a, 0x00);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Overflow));
+    assert!(cpu.status.get(Flag::Carry));
 }
 
 #[test]
-fn ldx_immediate() {
-    use super::opcodes::LDX_IMMEDIATE;
-
-    assert!(matches!(
-        get_instruction(&[LDX_IMMEDIATE, 0xc0]).unwrap(),
-        (
-            Instruction::Ld {
-                destination: Register::X,
-                addressing_mode: LdAddressingMode::Immediate {
-                    mode: AM::Immediate { immediate: 0xc0 }
-                }
-            },
-            0x8002
-        )
-    ));
+fn adc_immediate_carry_in() {
+    use super::opcodes::{ADC_IMMEDIATE, LDA_IMMEDIATE};
 
+    // -128 + -1 + 1 = -128 doesn't overflow even if the intermediate sum does.
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDX_IMMEDIATE, 0xf0, 0x00]).unwrap();
-    assert_eq!(cpu.register_x, 0xf0);
+    cpu.load(&[LDA_IMMEDIATE, 0x80, ADC_IMMEDIATE, 0xff, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Carry, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0x80);
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Overflow));
+    assert!(cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDX_IMMEDIATE, 0x00, 0x00]).unwrap();
-    assert_eq!(cpu.register_x, 0x00);
-    assert!(!cpu.status.get(Flag::Negative));
-    assert!(cpu.status.get(Flag::Zero));
+    cpu.load(&[LDA_IMMEDIATE, 0x7f, ADC_IMMEDIATE, 0x00, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Carry, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0x80);
+    assert!(cpu.status.get(Flag::Overflow));
+    assert!(!cpu.status.get(Flag::Carry));
 }
 
 #[test]
-fn ldy_immediate() {
-    use super::opcodes::LDY_IMMEDIATE;
+fn sbc_immediate() {
+    use super::opcodes::{LDA_IMMEDIATE, SBC_IMMEDIATE};
 
     assert!(matches!(
-        get_instruction(&[LDY_IMMEDIATE, 0xc0]).unwrap(),
+        get_instruction(&[SBC_IMMEDIATE, 0xc0]).unwrap(),
         (
-            Instruction::Ld {
-                destination: Register::Y,
-                addressing_mode: LdAddressingMode::Immediate {
+            Instruction::Sbc {
+                addressing_mode: SbcAddressingMode::Immediate {
                     mode: AM::Immediate { immediate: 0xc0 }
                 }
             },
@@ -518,89 +587,79 @@ fn ldy_immediate() {
         )
     ));
 
+    // Carry clear means borrow.
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDY_IMMEDIATE, 0xf0, 0x00]).unwrap();
-    assert_eq!(cpu.register_y, 0xf0);
-    assert!(cpu.status.get(Flag::Negative));
+    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0x05, SBC_IMMEDIATE, 0x03, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_a, 0x01);
+    assert!(!cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Overflow));
+    assert!(cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDY_IMMEDIATE, 0x00, 0x00]).unwrap();
-    assert_eq!(cpu.register_y, 0x00);
+    cpu.load(&[LDA_IMMEDIATE, 0x05, SBC_IMMEDIATE, 0x05, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Carry, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0x00);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
-}
-
-#[test]
-fn adc_zero_page() {
-    use super::opcodes::ADC_ZERO_PAGE;
-    use super::opcodes::LDA_IMMEDIATE;
-
-    assert!(matches!(
-        get_instruction(&[ADC_ZERO_PAGE, 0xc0]).unwrap(),
-        (
-            Instruction::Adc {
-                addressing_mode: AdcAddressingMode::AdcAddressAddressingMode {
-                    mode: AdcAddressAddressingMode::ZeroPage {
-                        mode: AM::ZeroPage { address: 0xc0 }
-                    }
-                }
-            },
-            0x8002
-        )
-    ));
+    assert!(!cpu.status.get(Flag::Overflow));
+    assert!(cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[ADC_ZERO_PAGE, 0x02, 0x00]).unwrap();
+    cpu.load(&[LDA_IMMEDIATE, 0x05, SBC_IMMEDIATE, 0x06, 0x00])
+        .unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0xf0, 0x04]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 0xf0);
+    cpu.status.set(Flag::Carry, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0xff);
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
     assert!(!cpu.status.get(Flag::Overflow));
     assert!(!cpu.status.get(Flag::Carry));
 
+    // 127 - -1 = 128 overflows.
     let mut cpu = Cpu::new();
-    cpu.load(&[LDA_IMMEDIATE, 0x71, ADC_ZERO_PAGE, 0x02, 0x00])
+    cpu.load(&[LDA_IMMEDIATE, 0x7f, SBC_IMMEDIATE, 0xff, 0x00])
         .unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x72, 0x04]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, u8::wrapping_add(0x71, 0x72));
+    cpu.status.set(Flag::Carry, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0x80);
     assert!(cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
     assert!(cpu.status.get(Flag::Overflow));
     assert!(!cpu.status.get(Flag::Carry));
 
+    // -128 - 1 = -129 overflows.
     let mut cpu = Cpu::new();
-    cpu.load(&[LDA_IMMEDIATE, 0x80, ADC_ZERO_PAGE, 0x03, 0x00])
+    cpu.load(&[LDA_IMMEDIATE, 0x80, SBC_IMMEDIATE, 0x01, 0x00])
         .unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x03, 0x80]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 0x00);
+    cpu.status.set(Flag::Carry, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0x7f);
     assert!(!cpu.status.get(Flag::Negative));
-    assert!(cpu.status.get(Flag::Zero));
     assert!(cpu.status.get(Flag::Overflow));
     assert!(cpu.status.get(Flag::Carry));
 }
 
 #[test]
-fn and_zero_page() {
-    use super::opcodes::{AND_ZERO_PAGE, LDA_IMMEDIATE};
+fn sbc_immediate_unofficial() {
+    use super::opcodes::{LDA_IMMEDIATE, SBC_IMMEDIATE_UNOFFICIAL};
 
     assert!(matches!(
-        get_instruction(&[AND_ZERO_PAGE, 0xc0]).unwrap(),
+        get_instruction(&[SBC_IMMEDIATE_UNOFFICIAL, 0xc0]).unwrap(),
         (
-            Instruction::And {
-                addressing_mode: AndAddressingMode::AndAddressAddressingMode {
-                    mode: AndAddressAddressingMode::ZeroPage {
-                        mode: AM::ZeroPage { address: 0xc0 }
-                    }
+            Instruction::Sbc {
+                addressing_mode: SbcAddressingMode::Immediate {
+                    mode: AM::Immediate { immediate: 0xc0 }
                 }
             },
             0x8002
@@ -608,54 +667,64 @@ fn and_zero_page() {
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[AND_ZERO_PAGE, 0x02, 0x00]).unwrap();
+    cpu.load(&[LDA_IMMEDIATE, 0x05, SBC_IMMEDIATE_UNOFFICIAL, 0x03, 0x00])
+        .unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0xf0, 0x04]).unwrap();
-    cpu.run().unwrap();
+    cpu.status.set(Flag::Carry, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0x02);
+    assert!(cpu.status.get(Flag::Carry));
+}
+
+#[test]
+fn and_immediate() {
+    use super::opcodes::{AND_IMMEDIATE, LDA_IMMEDIATE};
+
+    assert!(matches!(
+        get_instruction(&[AND_IMMEDIATE, 0xc0]).unwrap(),
+        (
+            Instruction::And {
+                addressing_mode: AndAddressingMode::Immediate {
+                    mode: AM::Immediate { immediate: 0xc0 }
+                }
+            },
+            0x8002
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[AND_IMMEDIATE, 0b11010011, 0x00])
+        .unwrap();
     assert_eq!(cpu.register_a, 0b00000000);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDA_IMMEDIATE, 0b11010011, AND_ZERO_PAGE, 0x02, 0x00])
-        .unwrap();
-    cpu.reset().unwrap();
-    cpu.program_counter = 0x8000;
-    cpu.memory
-        .load(0x00, &[0x01, 0x02, 0b10110001, 0x04])
+    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0b11010011, AND_IMMEDIATE, 0b10110001, 0x00])
         .unwrap();
-    cpu.run().unwrap();
     assert_eq!(cpu.register_a, 0b10010001);
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0b00110111, AND_ZERO_PAGE, 0x02, 0x00])
-        .unwrap();
-    cpu.reset().unwrap();
-    cpu.program_counter = 0x8000;
-    cpu.memory
-        .load(0x00, &[0x01, 0x02, 0b00110111, 0x04])
+    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0b00110111, AND_IMMEDIATE, 0b00110111, 0x00])
         .unwrap();
-    cpu.run().unwrap();
     assert_eq!(cpu.register_a, 0b00110111);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
 }
 
 #[test]
-fn asl_zero_page() {
-    use super::opcodes::ASL_ZERO_PAGE;
+fn eor_immediate() {
+    use super::opcodes::{EOR_IMMEDIATE, LDA_IMMEDIATE};
 
     assert!(matches!(
-        get_instruction(&[ASL_ZERO_PAGE, 0xab]).unwrap(),
+        get_instruction(&[EOR_IMMEDIATE, 0xc0]).unwrap(),
         (
-            Instruction::Asl {
-                addressing_mode: AslAddressingMode::AslAddressAddressingMode {
-                    mode: AslAddressAddressingMode::ZeroPage {
-                        mode: AM::ZeroPage { address: 0xab }
-                    }
+            Instruction::Eor {
+                addressing_mode: EorAddressingMode::Immediate {
+                    mode: AM::Immediate { immediate: 0xc0 }
                 }
             },
             0x8002
@@ -663,42 +732,36 @@ fn asl_zero_page() {
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[ASL_ZERO_PAGE, 0x02, 0x00]).unwrap();
+    cpu.load(&[LDA_IMMEDIATE, 0b1101_0011, EOR_IMMEDIATE, 0b1011_0001, 0x00])
+        .unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory
-        .load(0x00, &[0x01, 0x02, 0b10000000, 0x04])
-        .unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.memory.read(0x02), 0b00000000);
-    assert!(cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Carry));
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0110_0010);
     assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[ASL_ZERO_PAGE, 0x03, 0x00]).unwrap();
+    cpu.load(&[LDA_IMMEDIATE, 0b0101_1010, EOR_IMMEDIATE, 0b0101_1010, 0x00])
+        .unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory
-        .load(0x00, &[0x01, 0x02, 0x03, 0b0101_0101])
-        .unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.memory.read(0x03), 0b1010_1010);
-    assert!(!cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Carry));
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0000_0000);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
 }
 
 #[test]
-fn bit_zero_page() {
-    use super::opcodes::BIT_ZERO_PAGE;
+fn ora_immediate() {
+    use super::opcodes::{LDA_IMMEDIATE, ORA_IMMEDIATE};
 
     assert!(matches!(
-        get_instruction(&[BIT_ZERO_PAGE, 0xab]).unwrap(),
+        get_instruction(&[ORA_IMMEDIATE, 0xc0]).unwrap(),
         (
-            Instruction::Bit {
-                addressing_mode: BitAddressingMode::ZeroPage {
-                    mode: AM::ZeroPage { address: 0xab }
+            Instruction::Ora {
+                addressing_mode: OraAddressingMode::Immediate {
+                    mode: AM::Immediate { immediate: 0xc0 }
                 }
             },
             0x8002
@@ -706,57 +769,36 @@ fn bit_zero_page() {
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[BIT_ZERO_PAGE, 0x02, 0x00]).unwrap();
-    cpu.reset().unwrap();
-    cpu.program_counter = 0x8000;
-    cpu.memory
-        .load(0x00, &[0x01, 0x02, 0b11000000, 0x04])
+    cpu.load(&[LDA_IMMEDIATE, 0b1101_0011, ORA_IMMEDIATE, 0b1011_0001, 0x00])
         .unwrap();
-    cpu.register_a = 0b0011_1111;
-    cpu.run().unwrap();
-    assert!(cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Overflow));
-    assert!(cpu.status.get(Flag::Negative));
-
-    let mut cpu = Cpu::new();
-    cpu.load(&[BIT_ZERO_PAGE, 0x03, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory
-        .load(0x00, &[0x01, 0x02, 0x03, 0b0101_0101])
-        .unwrap();
-    cpu.register_a = 0b0001_0101;
-    cpu.run().unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b1111_0011);
+    assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Overflow));
-    assert!(!cpu.status.get(Flag::Negative));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[BIT_ZERO_PAGE, 0x03, 0x00]).unwrap();
+    cpu.load(&[LDA_IMMEDIATE, 0b0000_0000, ORA_IMMEDIATE, 0b0000_0000, 0x00])
+        .unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory
-        .load(0x00, &[0x01, 0x02, 0x03, 0b1010_1010])
-        .unwrap();
-    cpu.register_a = 0b0101_0101;
-    cpu.run().unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0000_0000);
+    assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
-    assert!(!cpu.status.get(Flag::Overflow));
-    assert!(cpu.status.get(Flag::Negative));
 }
 
 #[test]
-fn cmp_zero_page() {
-    use super::opcodes::CMP_ZERO_PAGE;
+fn cmp_immediate() {
+    use super::opcodes::CMP_IMMEDIATE;
 
     assert!(matches!(
-        get_instruction(&[CMP_ZERO_PAGE, 0xc0]).unwrap(),
+        get_instruction(&[CMP_IMMEDIATE, 0xc0]).unwrap(),
         (
             Instruction::Cmp {
-                addressing_mode: CmpAddressingMode::CmpAddressAddressingMode {
-                    mode: CmpAddressAddressingMode::ZeroPage {
-                        mode: AM::ZeroPage { address: 0xc0 }
-                    }
+                addressing_mode: CmpAddressingMode::Immediate {
+                    mode: AM::Immediate { immediate: 0xc0 }
                 }
             },
             0x8002
@@ -764,12 +806,11 @@ fn cmp_zero_page() {
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[CMP_ZERO_PAGE, 0x02]).unwrap();
+    cpu.load(&[CMP_IMMEDIATE, 123]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory.load(0x00, &[0x01, 0x02, 123, 0x04]).unwrap();
     cpu.register_a = 127;
-    cpu.run().unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_a, 127);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
@@ -777,11 +818,10 @@ fn cmp_zero_page() {
 
     let mut cpu = Cpu::new();
     cpu.reset().unwrap();
-    cpu.load(&[CMP_ZERO_PAGE, 0x01]).unwrap();
+    cpu.load(&[CMP_IMMEDIATE, 200]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory.load(0x00, &[0x01, 200, 0x03, 0x04]).unwrap();
     cpu.register_a = 127;
-    cpu.run().unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_a, 127);
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
@@ -789,11 +829,10 @@ fn cmp_zero_page() {
 
     let mut cpu = Cpu::new();
     cpu.reset().unwrap();
-    cpu.load(&[CMP_ZERO_PAGE, 0x00]).unwrap();
+    cpu.load(&[CMP_IMMEDIATE, 200]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory.load(0x00, &[200, 0x02, 0x03, 0x04]).unwrap();
     cpu.register_a = 200;
-    cpu.run().unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_a, 200);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
@@ -801,17 +840,15 @@ fn cmp_zero_page() {
 }
 
 #[test]
-fn cpx_zero_page() {
-    use super::opcodes::CPX_ZERO_PAGE;
+fn cpx_immediate() {
+    use super::opcodes::CPX_IMMEDIATE;
 
     assert!(matches!(
-        get_instruction(&[CPX_ZERO_PAGE, 0xc0]).unwrap(),
+        get_instruction(&[CPX_IMMEDIATE, 0xc0]).unwrap(),
         (
             Instruction::Cpx {
-                addressing_mode: CpxAddressingMode::CpxAddressAddressingMode {
-                    mode: CpxAddressAddressingMode::ZeroPage {
-                        mode: AM::ZeroPage { address: 0xc0 }
-                    }
+                addressing_mode: CpxAddressingMode::Immediate {
+                    mode: AM::Immediate { immediate: 0xc0 }
                 }
             },
             0x8002
@@ -819,12 +856,11 @@ fn cpx_zero_page() {
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[CPX_ZERO_PAGE, 0x02]).unwrap();
+    cpu.load(&[CPX_IMMEDIATE, 123]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory.load(0x00, &[0x01, 0x02, 123, 0x04]).unwrap();
     cpu.register_x = 127;
-    cpu.run().unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_x, 127);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
@@ -832,11 +868,10 @@ fn cpx_zero_page() {
 
     let mut cpu = Cpu::new();
     cpu.reset().unwrap();
-    cpu.load(&[CPX_ZERO_PAGE, 0x01]).unwrap();
+    cpu.load(&[CPX_IMMEDIATE, 200]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory.load(0x00, &[0x01, 200, 0x03, 0x04]).unwrap();
     cpu.register_x = 127;
-    cpu.run().unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_x, 127);
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
@@ -844,11 +879,10 @@ fn cpx_zero_page() {
 
     let mut cpu = Cpu::new();
     cpu.reset().unwrap();
-    cpu.load(&[CPX_ZERO_PAGE, 0x00]).unwrap();
+    cpu.load(&[CPX_IMMEDIATE, 200]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory.load(0x00, &[200, 0x02, 0x03, 0x04]).unwrap();
     cpu.register_x = 200;
-    cpu.run().unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_x, 200);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
@@ -856,17 +890,15 @@ fn cpx_zero_page() {
 }
 
 #[test]
-fn cpy_zero_page() {
-    use super::opcodes::CPY_ZERO_PAGE;
+fn cpy_immediate() {
+    use super::opcodes::CPY_IMMEDIATE;
 
     assert!(matches!(
-        get_instruction(&[CPY_ZERO_PAGE, 0xc0]).unwrap(),
+        get_instruction(&[CPY_IMMEDIATE, 0xc0]).unwrap(),
         (
             Instruction::Cpy {
-                addressing_mode: CpyAddressingMode::CpyAddressAddressingMode {
-                    mode: CpyAddressAddressingMode::ZeroPage {
-                        mode: AM::ZeroPage { address: 0xc0 }
-                    }
+                addressing_mode: CpyAddressingMode::Immediate {
+                    mode: AM::Immediate { immediate: 0xc0 }
                 }
             },
             0x8002
@@ -874,12 +906,11 @@ fn cpy_zero_page() {
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[CPY_ZERO_PAGE, 0x02]).unwrap();
+    cpu.load(&[CPY_IMMEDIATE, 123]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory.load(0x00, &[0x01, 0x02, 123, 0x04]).unwrap();
     cpu.register_y = 127;
-    cpu.run().unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_y, 127);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
@@ -887,11 +918,10 @@ fn cpy_zero_page() {
 
     let mut cpu = Cpu::new();
     cpu.reset().unwrap();
-    cpu.load(&[CPY_ZERO_PAGE, 0x01]).unwrap();
+    cpu.load(&[CPY_IMMEDIATE, 200]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory.load(0x00, &[0x01, 200, 0x03, 0x04]).unwrap();
     cpu.register_y = 127;
-    cpu.run().unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_y, 127);
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
@@ -899,11 +929,10 @@ fn cpy_zero_page() {
 
     let mut cpu = Cpu::new();
     cpu.reset().unwrap();
-    cpu.load(&[CPY_ZERO_PAGE, 0x00]).unwrap();
+    cpu.load(&[CPY_IMMEDIATE, 200]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory.load(0x00, &[200, 0x02, 0x03, 0x04]).unwrap();
     cpu.register_y = 200;
-    cpu.run().unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_y, 200);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
@@ -911,55 +940,16 @@ fn cpy_zero_page() {
 }
 
 #[test]
-fn dec_zero_page() {
-    use super::opcodes::DEC_ZERO_PAGE;
-
-    assert!(matches!(
-        get_instruction(&[DEC_ZERO_PAGE, 0xc0]).unwrap(),
-        (
-            Instruction::Dec {
-                addressing_mode: DecAddressingMode::ZeroPage {
-                    mode: AM::ZeroPage { address: 0xc0 }
-                }
-            },
-            0x8002
-        )
-    ));
-
-    let mut cpu = Cpu::new();
-    cpu.load(&[DEC_ZERO_PAGE, 0x02]).unwrap();
-    cpu.reset().unwrap();
-    cpu.program_counter = 0x8000;
-    cpu.memory.load(0x00, &[0x01, 0x02, 1, 0x04]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.memory.read(0x02), 0);
-    assert!(!cpu.status.get(Flag::Negative));
-    assert!(cpu.status.get(Flag::Zero));
-
-    let mut cpu = Cpu::new();
-    cpu.load(&[DEC_ZERO_PAGE, 0x03]).unwrap();
-    cpu.reset().unwrap();
-    cpu.program_counter = 0x8000;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x03, 0]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.memory.read(0x03), 0xff);
-    assert!(cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
-}
-
-#[test]
-fn lda_zero_page() {
-    use super::opcodes::LDA_ZERO_PAGE;
+fn lda_immediate() {
+    use super::opcodes::LDA_IMMEDIATE;
 
     assert!(matches!(
-        get_instruction(&[LDA_ZERO_PAGE, 0xc0]).unwrap(),
+        get_instruction(&[LDA_IMMEDIATE, 0xc0]).unwrap(),
         (
             Instruction::Ld {
                 destination: Register::A,
-                addressing_mode: LdAddressingMode::LdAddressAddressingMode {
-                    mode: LdAddressAddressingMode::ZeroPage {
-                        mode: AM::ZeroPage { address: 0xc0 }
-                    }
+                addressing_mode: LdAddressingMode::Immediate {
+                    mode: AM::Immediate { immediate: 0xc0 }
                 }
             },
             0x8002,
@@ -967,105 +957,90 @@ fn lda_zero_page() {
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDA_ZERO_PAGE, 0x02, 0x00]).unwrap();
-    cpu.reset().unwrap();
-    cpu.program_counter = 0x8000;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0xf1, 0x04]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 0xf1);
+    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0xf0, 0x00]).unwrap();
+    assert_eq!(cpu.register_a, 0xf0);
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDA_ZERO_PAGE, 0x02, 0x00]).unwrap();
+    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0x00, 0x00]).unwrap();
     assert_eq!(cpu.register_a, 0x00);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
 }
 
 #[test]
-fn ldx_zero_page() {
-    use super::opcodes::LDX_ZERO_PAGE;
+fn ldx_immediate() {
+    use super::opcodes::LDX_IMMEDIATE;
 
     assert!(matches!(
-        get_instruction(&[LDX_ZERO_PAGE, 0xc0]).unwrap(),
+        get_instruction(&[LDX_IMMEDIATE, 0xc0]).unwrap(),
         (
             Instruction::Ld {
                 destination: Register::X,
-                addressing_mode: LdAddressingMode::LdAddressAddressingMode {
-                    mode: LdAddressAddressingMode::ZeroPage {
-                        mode: AM::ZeroPage { address: 0xc0 }
-                    }
+                addressing_mode: LdAddressingMode::Immediate {
+                    mode: AM::Immediate { immediate: 0xc0 }
                 }
             },
-            0x8002,
+            0x8002
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDX_ZERO_PAGE, 0x02, 0x00]).unwrap();
-    cpu.reset().unwrap();
-    cpu.program_counter = 0x8000;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0xf1, 0x04]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_x, 0xf1);
+    cpu.load_and_run_test(&[LDX_IMMEDIATE, 0xf0, 0x00]).unwrap();
+    assert_eq!(cpu.register_x, 0xf0);
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDX_ZERO_PAGE, 0x02, 0x00]).unwrap();
-    assert_eq!(cpu.register_a, 0x00);
+    cpu.load_and_run_test(&[LDX_IMMEDIATE, 0x00, 0x00]).unwrap();
+    assert_eq!(cpu.register_x, 0x00);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
 }
 
 #[test]
-fn ldy_zero_page() {
-    use super::opcodes::LDY_ZERO_PAGE;
+fn ldy_immediate() {
+    use super::opcodes::LDY_IMMEDIATE;
 
     assert!(matches!(
-        get_instruction(&[LDY_ZERO_PAGE, 0xc0]).unwrap(),
+        get_instruction(&[LDY_IMMEDIATE, 0xc0]).unwrap(),
         (
             Instruction::Ld {
                 destination: Register::Y,
-                addressing_mode: LdAddressingMode::LdAddressAddressingMode {
-                    mode: LdAddressAddressingMode::ZeroPage {
-                        mode: AM::ZeroPage { address: 0xc0 }
-                    }
+                addressing_mode: LdAddressingMode::Immediate {
+                    mode: AM::Immediate { immediate: 0xc0 }
                 }
             },
-            0x8002,
+            0x8002
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDY_ZERO_PAGE, 0x02, 0x00]).unwrap();
-    cpu.reset().unwrap();
-    cpu.program_counter = 0x8000;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0xf1, 0x04]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_y, 0xf1);
+    cpu.load_and_run_test(&[LDY_IMMEDIATE, 0xf0, 0x00]).unwrap();
+    assert_eq!(cpu.register_y, 0xf0);
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDY_ZERO_PAGE, 0x02, 0x00]).unwrap();
+    cpu.load_and_run_test(&[LDY_IMMEDIATE, 0x00, 0x00]).unwrap();
     assert_eq!(cpu.register_y, 0x00);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
 }
 
 #[test]
-fn adc_zero_page_x() {
-    use super::opcodes::{ADC_ZERO_PAGE_X, LDA_IMMEDIATE};
+fn adc_zero_page() {
+    use super::opcodes::ADC_ZERO_PAGE;
+    use super::opcodes::LDA_IMMEDIATE;
 
     assert!(matches!(
-        get_instruction(&[ADC_ZERO_PAGE_X, 0xc0]).unwrap(),
+        get_instruction(&[ADC_ZERO_PAGE, 0xc0]).unwrap(),
         (
             Instruction::Adc {
                 addressing_mode: AdcAddressingMode::AdcAddressAddressingMode {
-                    mode: AdcAddressAddressingMode::ZeroPageX {
-                        mode: AM::ZeroPageX { address: 0xc0 }
+                    mode: AdcAddressAddressingMode::ZeroPage {
+                        mode: AM::ZeroPage { address: 0xc0 }
                     }
                 }
             },
@@ -1074,12 +1049,11 @@ fn adc_zero_page_x() {
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[ADC_ZERO_PAGE_X, 0x01, 0x00]).unwrap();
+    cpu.load(&[ADC_ZERO_PAGE, 0x02, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0x02;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x03, 0xf0]).unwrap();
-    cpu.run().unwrap();
+    cpu.bus.load(0x00, &[0x01, 0x02, 0xf0, 0x04]).unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_a, 0xf0);
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
@@ -1087,13 +1061,12 @@ fn adc_zero_page_x() {
     assert!(!cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDA_IMMEDIATE, 0x71, ADC_ZERO_PAGE_X, 0x02, 0x00])
+    cpu.load(&[LDA_IMMEDIATE, 0x71, ADC_ZERO_PAGE, 0x02, 0x00])
         .unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0x01;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x03, 0x72]).unwrap();
-    cpu.run().unwrap();
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x72, 0x04]).unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_a, u8::wrapping_add(0x71, 0x72));
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
@@ -1101,13 +1074,12 @@ fn adc_zero_page_x() {
     assert!(!cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDA_IMMEDIATE, 0x80, ADC_ZERO_PAGE_X, 0x03, 0x00])
+    cpu.load(&[LDA_IMMEDIATE, 0x80, ADC_ZERO_PAGE, 0x03, 0x00])
         .unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0x00;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x03, 0x80]).unwrap();
-    cpu.run().unwrap();
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0x80]).unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_a, 0x00);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
@@ -1116,16 +1088,49 @@ fn adc_zero_page_x() {
 }
 
 #[test]
-fn and_zero_page_x() {
-    use super::opcodes::{AND_ZERO_PAGE_X, LDA_IMMEDIATE};
+fn sbc_zero_page() {
+    use super::opcodes::{LDA_IMMEDIATE, SBC_ZERO_PAGE};
 
     assert!(matches!(
-        get_instruction(&[AND_ZERO_PAGE_X, 0xc0]).unwrap(),
+        get_instruction(&[SBC_ZERO_PAGE, 0xc0]).unwrap(),
+        (
+            Instruction::Sbc {
+                addressing_mode: SbcAddressingMode::SbcAddressAddressingMode {
+                    mode: SbcAddressAddressingMode::ZeroPage {
+                        mode: AM::ZeroPage { address: 0xc0 }
+                    }
+                }
+            },
+            0x8002
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0x50, SBC_ZERO_PAGE, 0x02, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Carry, true);
+    cpu.bus.load(0x00, &[0x01, 0x02, 0xb0, 0x04]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0xa0);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Overflow));
+    assert!(!cpu.status.get(Flag::Carry));
+}
+
+#[test]
+fn and_zero_page() {
+    use super::opcodes::{AND_ZERO_PAGE, LDA_IMMEDIATE};
+
+    assert!(matches!(
+        get_instruction(&[AND_ZERO_PAGE, 0xc0]).unwrap(),
         (
             Instruction::And {
                 addressing_mode: AndAddressingMode::AndAddressAddressingMode {
-                    mode: AndAddressAddressingMode::ZeroPageX {
-                        mode: AM::ZeroPageX { address: 0xc0 }
+                    mode: AndAddressAddressingMode::ZeroPage {
+                        mode: AM::ZeroPage { address: 0xc0 }
                     }
                 }
             },
@@ -1134,56 +1139,49 @@ fn and_zero_page_x() {
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[AND_ZERO_PAGE_X, 0x01, 0x00]).unwrap();
+    cpu.load(&[AND_ZERO_PAGE, 0x02, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0x01;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0xf0, 0x04]).unwrap();
-    cpu.run().unwrap();
+    cpu.bus.load(0x00, &[0x01, 0x02, 0xf0, 0x04]).unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_a, 0b00000000);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDA_IMMEDIATE, 0b11010011, AND_ZERO_PAGE_X, 0x02, 0x00])
+    cpu.load(&[LDA_IMMEDIATE, 0b11010011, AND_ZERO_PAGE, 0x02, 0x00])
         .unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0x01;
-    cpu.memory
-        .load(0x00, &[0x01, 0x02, 0x03, 0b10110001])
-        .unwrap();
-    cpu.run().unwrap();
+    cpu.bus.load(0x00, &[0x01, 0x02, 0b10110001, 0x04]).unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_a, 0b10010001);
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0b00110111, AND_ZERO_PAGE_X, 0x02, 0x00])
+    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0b00110111, AND_ZERO_PAGE, 0x02, 0x00])
         .unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0x00;
-    cpu.memory
-        .load(0x00, &[0x01, 0x02, 0b00110111, 0x04])
-        .unwrap();
-    cpu.run().unwrap();
+    cpu.bus.load(0x00, &[0x01, 0x02, 0b00110111, 0x04]).unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_a, 0b00110111);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
 }
 
 #[test]
-fn asl_zero_page_x() {
-    use super::opcodes::ASL_ZERO_PAGE_X;
+fn eor_zero_page() {
+    use super::opcodes::{EOR_ZERO_PAGE, LDA_IMMEDIATE};
 
     assert!(matches!(
-        get_instruction(&[ASL_ZERO_PAGE_X, 0xab]).unwrap(),
+        get_instruction(&[EOR_ZERO_PAGE, 0xab]).unwrap(),
         (
-            Instruction::Asl {
-                addressing_mode: AslAddressingMode::AslAddressAddressingMode {
-                    mode: AslAddressAddressingMode::ZeroPageX {
-                        mode: AM::ZeroPageX { address: 0xab }
+            Instruction::Eor {
+                addressing_mode: EorAddressingMode::EorAddressAddressingMode {
+                    mode: EorAddressAddressingMode::ZeroPage {
+                        mode: AM::ZeroPage { address: 0xab }
                     }
                 }
             },
@@ -1192,45 +1190,39 @@ fn asl_zero_page_x() {
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[ASL_ZERO_PAGE_X, 0x01, 0x00]).unwrap();
+    cpu.load(&[LDA_IMMEDIATE, 0b1101_0011, EOR_ZERO_PAGE, 0x10, 0x00])
+        .unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0x01;
-    cpu.memory
-        .load(0x00, &[0x01, 0x02, 0b10000000, 0x04])
-        .unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.memory.read(0x02), 0b00000000);
-    assert!(cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Carry));
+    cpu.bus.write(0x10, 0b1011_0001);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0110_0010);
     assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[ASL_ZERO_PAGE_X, 0x03, 0x00]).unwrap();
+    cpu.load(&[LDA_IMMEDIATE, 0b0101_1010, EOR_ZERO_PAGE, 0x10, 0x00])
+        .unwrap();
     cpu.reset().unwrap();
-    cpu.register_x = 0x00;
     cpu.program_counter = 0x8000;
-    cpu.memory
-        .load(0x00, &[0x01, 0x02, 0x03, 0b0101_0101])
-        .unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.memory.read(0x03), 0b1010_1010);
-    assert!(!cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Carry));
+    cpu.bus.write(0x10, 0b0101_1010);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0000_0000);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
 }
 
 #[test]
-fn cmp_zero_page_x() {
-    use super::opcodes::CMP_ZERO_PAGE_X;
+fn ora_zero_page() {
+    use super::opcodes::{LDA_IMMEDIATE, ORA_ZERO_PAGE};
 
     assert!(matches!(
-        get_instruction(&[CMP_ZERO_PAGE_X, 0xc0]).unwrap(),
+        get_instruction(&[ORA_ZERO_PAGE, 0xab]).unwrap(),
         (
-            Instruction::Cmp {
-                addressing_mode: CmpAddressingMode::CmpAddressAddressingMode {
-                    mode: CmpAddressAddressingMode::ZeroPageX {
-                        mode: AM::ZeroPageX { address: 0xc0 }
+            Instruction::Ora {
+                addressing_mode: OraAddressingMode::OraAddressAddressingMode {
+                    mode: OraAddressAddressingMode::ZeroPage {
+                        mode: AM::ZeroPage { address: 0xab }
                     }
                 }
             },
@@ -1239,599 +1231,735 @@ fn cmp_zero_page_x() {
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[CMP_ZERO_PAGE_X, 0x02]).unwrap();
-    cpu.reset().unwrap();
-    cpu.program_counter = 0x8000;
-    cpu.register_x = 1;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x03, 123]).unwrap();
-    cpu.register_a = 127;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 127);
-    assert!(!cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Carry));
-
-    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0b1101_0011, ORA_ZERO_PAGE, 0x10, 0x00])
+        .unwrap();
     cpu.reset().unwrap();
-    cpu.load(&[CMP_ZERO_PAGE_X, 0x01]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0x00;
-    cpu.memory.load(0x00, &[0x01, 200, 0x03, 0x04]).unwrap();
-    cpu.register_a = 127;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 127);
+    cpu.bus.write(0x10, 0b1011_0001);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b1111_0011);
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
-    assert!(!cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0b0000_0000, ORA_ZERO_PAGE, 0x10, 0x00])
+        .unwrap();
     cpu.reset().unwrap();
-    cpu.load(&[CMP_ZERO_PAGE_X, 0x00]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0x02;
-    cpu.memory.load(0x00, &[0x01, 0x02, 200, 0x04]).unwrap();
-    cpu.register_a = 200;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 200);
+    cpu.bus.write(0x10, 0b0000_0000);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0000_0000);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Carry));
 }
 
 #[test]
-fn lda_zero_page_x() {
-    use super::opcodes::LDA_ZERO_PAGE_X;
+fn asl_zero_page() {
+    use super::opcodes::ASL_ZERO_PAGE;
 
     assert!(matches!(
-        get_instruction(&[LDA_ZERO_PAGE_X, 0xc0]).unwrap(),
+        get_instruction(&[ASL_ZERO_PAGE, 0xab]).unwrap(),
         (
-            Instruction::Ld {
-                destination: Register::A,
-                addressing_mode: LdAddressingMode::LdAddressAddressingMode {
-                    mode: LdAddressAddressingMode::ZeroPageX {
-                        mode: AM::ZeroPageX { address: 0xc0 }
+            Instruction::Asl {
+                addressing_mode: AslAddressingMode::AslAddressAddressingMode {
+                    mode: AslAddressAddressingMode::ZeroPage {
+                        mode: AM::ZeroPage { address: 0xab }
                     }
                 }
             },
-            0x8002,
+            0x8002
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDA_ZERO_PAGE_X, 0x02, 0x00]).unwrap();
+    cpu.load(&[ASL_ZERO_PAGE, 0x02, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0x01;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x03, 0xf4]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 0xf4);
-    assert!(cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
+    cpu.bus.load(0x00, &[0x01, 0x02, 0b10000000, 0x04]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x02), 0b00000000);
+    assert!(cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Carry));
+    assert!(!cpu.status.get(Flag::Negative));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDA_ZERO_PAGE_X, 0x02, 0x00])
-        .unwrap();
-    assert_eq!(cpu.register_a, 0x00);
-    assert!(!cpu.status.get(Flag::Negative));
-    assert!(cpu.status.get(Flag::Zero));
+    cpu.load(&[ASL_ZERO_PAGE, 0x03, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.bus
+        .load(0x00, &[0x01, 0x02, 0x03, 0b0101_0101])
+        .unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x03), 0b1010_1010);
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Carry));
 }
 
 #[test]
-fn ldy_zero_page_x() {
-    use super::opcodes::LDY_ZERO_PAGE_X;
+fn lsr_zero_page() {
+    use super::opcodes::LSR_ZERO_PAGE;
 
     assert!(matches!(
-        get_instruction(&[LDY_ZERO_PAGE_X, 0xc0]).unwrap(),
+        get_instruction(&[LSR_ZERO_PAGE, 0xc0]).unwrap(),
         (
-            Instruction::Ld {
-                destination: Register::Y,
-                addressing_mode: LdAddressingMode::LdAddressAddressingMode {
-                    mode: LdAddressAddressingMode::ZeroPageX {
-                        mode: AM::ZeroPageX { address: 0xc0 }
+            Instruction::Lsr {
+                addressing_mode: LsrAddressingMode::LsrAddressAddressingMode {
+                    mode: LsrAddressAddressingMode::ZeroPage {
+                        mode: AM::ZeroPage { address: 0xc0 }
                     }
                 }
             },
-            0x8002,
+            0x8002
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDY_ZERO_PAGE_X, 0x04, 0x00]).unwrap();
+    cpu.load(&[LSR_ZERO_PAGE, 0x10, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0xff;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x03, 0xf4]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_y, 0xf4);
-    assert!(cpu.status.get(Flag::Negative));
+    cpu.bus.write(0x10, 0b1000_0001);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x10), 0b0100_0000);
+    assert!(cpu.status.get(Flag::Carry));
+    assert!(!cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDY_ZERO_PAGE_X, 0x02, 0x00])
-        .unwrap();
-    assert_eq!(cpu.register_y, 0x00);
+    cpu.load(&[LSR_ZERO_PAGE, 0x10, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.bus.write(0x10, 0b0000_0001);
+    cpu.status.set(Flag::Carry, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x10), 0b0000_0000);
+    assert!(cpu.status.get(Flag::Carry));
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
 }
 
 #[test]
-fn ldx_zero_page_y() {
-    use super::opcodes::LDX_ZERO_PAGE_Y;
+fn rol_zero_page() {
+    use super::opcodes::ROL_ZERO_PAGE;
 
     assert!(matches!(
-        get_instruction(&[LDX_ZERO_PAGE_Y, 0xc0]).unwrap(),
+        get_instruction(&[ROL_ZERO_PAGE, 0xc0]).unwrap(),
         (
-            Instruction::Ld {
-                destination: Register::X,
-                addressing_mode: LdAddressingMode::LdAddressAddressingMode {
-                    mode: LdAddressAddressingMode::ZeroPageY {
-                        mode: AM::ZeroPageY { address: 0xc0 }
+            Instruction::Rol {
+                addressing_mode: RolAddressingMode::RolAddressAddressingMode {
+                    mode: RolAddressAddressingMode::ZeroPage {
+                        mode: AM::ZeroPage { address: 0xc0 }
                     }
                 }
             },
-            0x8002,
+            0x8002
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDX_ZERO_PAGE_Y, 0x02, 0x00]).unwrap();
+    cpu.load(&[ROL_ZERO_PAGE, 0x10, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_y = 0x01;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x03, 0xf4]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_x, 0xf4);
+    cpu.bus.write(0x10, 0b1000_0000);
+    cpu.status.set(Flag::Carry, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x10), 0b0000_0001);
+    assert!(cpu.status.get(Flag::Carry));
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[ROL_ZERO_PAGE, 0x10, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.bus.write(0x10, 0b0100_0000);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x10), 0b1000_0000);
+    assert!(!cpu.status.get(Flag::Carry));
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDX_ZERO_PAGE_Y, 0x02, 0x00])
-        .unwrap();
-    assert_eq!(cpu.register_x, 0x00);
+    cpu.load(&[ROL_ZERO_PAGE, 0x10, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.bus.write(0x10, 0b1000_0000);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x10), 0b0000_0000);
+    assert!(cpu.status.get(Flag::Carry));
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
 }
 
 #[test]
-fn bcc() {
-    use super::opcodes::BCC;
+fn ror_zero_page() {
+    use super::opcodes::ROR_ZERO_PAGE;
 
     assert!(matches!(
-        get_instruction(&[BCC, 0x0b]).unwrap(),
+        get_instruction(&[ROR_ZERO_PAGE, 0xc0]).unwrap(),
         (
-            Instruction::Branch {
-                addressing_mode: AM::Relative { offset: 0x0b },
-                flag: Flag::Carry,
-                branch_if: false,
+            Instruction::Ror {
+                addressing_mode: RorAddressingMode::RorAddressAddressingMode {
+                    mode: RorAddressAddressingMode::ZeroPage {
+                        mode: AM::ZeroPage { address: 0xc0 }
+                    }
+                }
             },
             0x8002
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BCC, 0x00, 0x00]).unwrap();
-    cpu.reset().unwrap();
-    cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Carry, false);
-    cpu.run().unwrap();
-    assert_eq!(cpu.program_counter, 0x8003);
-
-    let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BCC, 0x08, 0x00]).unwrap();
+    cpu.load(&[ROR_ZERO_PAGE, 0x10, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Carry, false);
-    cpu.run().unwrap();
-    assert_eq!(cpu.program_counter, 0x800b);
+    cpu.bus.write(0x10, 0b0000_0001);
+    cpu.status.set(Flag::Carry, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x10), 0b1000_0000);
+    assert!(cpu.status.get(Flag::Carry));
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BCC, 0x08, 0x00]).unwrap();
+    cpu.load(&[ROR_ZERO_PAGE, 0x10, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Carry, true);
-    cpu.run().unwrap();
-    assert_eq!(cpu.program_counter, 0x8003);
+    cpu.bus.write(0x10, 0b0000_0010);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x10), 0b0000_0001);
+    assert!(!cpu.status.get(Flag::Carry));
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BCC, 0xf8, 0x00]).unwrap();
+    cpu.load(&[ROR_ZERO_PAGE, 0x10, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Carry, false);
-    cpu.run().unwrap();
-    assert_eq!(
-        cpu.program_counter,
-        0x8003u16.wrapping_add(0xf8u8 as i8 as u16)
-    );
+    cpu.bus.write(0x10, 0b0000_0001);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x10), 0b0000_0000);
+    assert!(cpu.status.get(Flag::Carry));
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
 }
 
 #[test]
-fn bcs() {
-    use super::opcodes::BCS;
+fn bit_zero_page() {
+    use super::opcodes::BIT_ZERO_PAGE;
 
     assert!(matches!(
-        get_instruction(&[BCS, 0x0b]).unwrap(),
+        get_instruction(&[BIT_ZERO_PAGE, 0xab]).unwrap(),
         (
-            Instruction::Branch {
-                addressing_mode: AM::Relative { offset: 0x0b },
-                flag: Flag::Carry,
-                branch_if: true,
+            Instruction::Bit {
+                addressing_mode: BitAddressingMode::ZeroPage {
+                    mode: AM::ZeroPage { address: 0xab }
+                }
             },
             0x8002
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BCS, 0x00, 0x00]).unwrap();
-    cpu.reset().unwrap();
-    cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Carry, true);
-    cpu.run().unwrap();
-    assert_eq!(cpu.program_counter, 0x8003);
-
-    let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BCS, 0x08, 0x00]).unwrap();
+    cpu.load(&[BIT_ZERO_PAGE, 0x02, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Carry, true);
-    cpu.run().unwrap();
-    assert_eq!(cpu.program_counter, 0x800b);
+    cpu.bus.load(0x00, &[0x01, 0x02, 0b11000000, 0x04]).unwrap();
+    cpu.register_a = 0b0011_1111;
+    cpu.run_until_brk().unwrap();
+    assert!(cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Overflow));
+    assert!(cpu.status.get(Flag::Negative));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BCS, 0x08, 0x00]).unwrap();
+    cpu.load(&[BIT_ZERO_PAGE, 0x03, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Carry, false);
-    cpu.run().unwrap();
-    assert_eq!(cpu.program_counter, 0x8003);
+    cpu.bus
+        .load(0x00, &[0x01, 0x02, 0x03, 0b0101_0101])
+        .unwrap();
+    cpu.register_a = 0b0001_0101;
+    cpu.run_until_brk().unwrap();
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Overflow));
+    assert!(!cpu.status.get(Flag::Negative));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BCS, 0xf8, 0x00]).unwrap();
+    cpu.load(&[BIT_ZERO_PAGE, 0x03, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Carry, true);
-    cpu.run().unwrap();
-    assert_eq!(
-        cpu.program_counter,
-        0x8003u16.wrapping_add(0xf8u8 as i8 as u16)
-    );
+    cpu.bus
+        .load(0x00, &[0x01, 0x02, 0x03, 0b1010_1010])
+        .unwrap();
+    cpu.register_a = 0b0101_0101;
+    cpu.run_until_brk().unwrap();
+    assert!(cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Overflow));
+    assert!(cpu.status.get(Flag::Negative));
 }
 
 #[test]
-fn beq() {
-    use super::opcodes::BEQ;
+fn cmp_zero_page() {
+    use super::opcodes::CMP_ZERO_PAGE;
 
     assert!(matches!(
-        get_instruction(&[BEQ, 0x0b]).unwrap(),
+        get_instruction(&[CMP_ZERO_PAGE, 0xc0]).unwrap(),
         (
-            Instruction::Branch {
-                addressing_mode: AM::Relative { offset: 0x0b },
-                flag: Flag::Zero,
-                branch_if: true,
+            Instruction::Cmp {
+                addressing_mode: CmpAddressingMode::CmpAddressAddressingMode {
+                    mode: CmpAddressAddressingMode::ZeroPage {
+                        mode: AM::ZeroPage { address: 0xc0 }
+                    }
+                }
             },
             0x8002
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BEQ, 0x00, 0x00]).unwrap();
-    cpu.reset().unwrap();
-    cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Zero, true);
-    cpu.run().unwrap();
-    assert_eq!(cpu.program_counter, 0x8003);
-
-    let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BEQ, 0x08, 0x00]).unwrap();
+    cpu.load(&[CMP_ZERO_PAGE, 0x02]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Zero, true);
-    cpu.run().unwrap();
-    assert_eq!(cpu.program_counter, 0x800b);
+    cpu.bus.load(0x00, &[0x01, 0x02, 123, 0x04]).unwrap();
+    cpu.register_a = 127;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 127);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BEQ, 0x08, 0x00]).unwrap();
     cpu.reset().unwrap();
+    cpu.load(&[CMP_ZERO_PAGE, 0x01]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Zero, false);
-    cpu.run().unwrap();
-    assert_eq!(cpu.program_counter, 0x8003);
+    cpu.bus.load(0x00, &[0x01, 200, 0x03, 0x04]).unwrap();
+    cpu.register_a = 127;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 127);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BEQ, 0xf8, 0x00]).unwrap();
     cpu.reset().unwrap();
+    cpu.load(&[CMP_ZERO_PAGE, 0x00]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Zero, true);
-    cpu.run().unwrap();
-    assert_eq!(
-        cpu.program_counter,
-        0x8003u16.wrapping_add(0xf8u8 as i8 as u16)
-    );
+    cpu.bus.load(0x00, &[200, 0x02, 0x03, 0x04]).unwrap();
+    cpu.register_a = 200;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 200);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Carry));
 }
 
 #[test]
-fn bmi() {
-    use super::opcodes::BMI;
+fn cpx_zero_page() {
+    use super::opcodes::CPX_ZERO_PAGE;
 
     assert!(matches!(
-        get_instruction(&[BMI, 0x0b]).unwrap(),
+        get_instruction(&[CPX_ZERO_PAGE, 0xc0]).unwrap(),
         (
-            Instruction::Branch {
-                addressing_mode: AM::Relative { offset: 0x0b },
-                flag: Flag::Negative,
-                branch_if: true,
+            Instruction::Cpx {
+                addressing_mode: CpxAddressingMode::CpxAddressAddressingMode {
+                    mode: CpxAddressAddressingMode::ZeroPage {
+                        mode: AM::ZeroPage { address: 0xc0 }
+                    }
+                }
             },
             0x8002
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BMI, 0x00, 0x00]).unwrap();
-    cpu.reset().unwrap();
-    cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Negative, true);
-    cpu.run().unwrap();
-    assert_eq!(cpu.program_counter, 0x8003);
-
-    let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BMI, 0x08, 0x00]).unwrap();
+    cpu.load(&[CPX_ZERO_PAGE, 0x02]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Negative, true);
-    cpu.run().unwrap();
-    assert_eq!(cpu.program_counter, 0x800b);
+    cpu.bus.load(0x00, &[0x01, 0x02, 123, 0x04]).unwrap();
+    cpu.register_x = 127;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_x, 127);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BMI, 0x08, 0x00]).unwrap();
     cpu.reset().unwrap();
+    cpu.load(&[CPX_ZERO_PAGE, 0x01]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Negative, false);
-    cpu.run().unwrap();
-    assert_eq!(cpu.program_counter, 0x8003);
+    cpu.bus.load(0x00, &[0x01, 200, 0x03, 0x04]).unwrap();
+    cpu.register_x = 127;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_x, 127);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BMI, 0xf8, 0x00]).unwrap();
     cpu.reset().unwrap();
+    cpu.load(&[CPX_ZERO_PAGE, 0x00]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Negative, true);
-    cpu.run().unwrap();
-    assert_eq!(
-        cpu.program_counter,
-        0x8003u16.wrapping_add(0xf8u8 as i8 as u16)
-    );
+    cpu.bus.load(0x00, &[200, 0x02, 0x03, 0x04]).unwrap();
+    cpu.register_x = 200;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_x, 200);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Carry));
 }
 
 #[test]
-fn bne() {
-    use super::opcodes::BNE;
+fn cpy_zero_page() {
+    use super::opcodes::CPY_ZERO_PAGE;
 
     assert!(matches!(
-        get_instruction(&[BNE, 0x0b]).unwrap(),
+        get_instruction(&[CPY_ZERO_PAGE, 0xc0]).unwrap(),
         (
-            Instruction::Branch {
-                addressing_mode: AM::Relative { offset: 0x0b },
-                flag: Flag::Zero,
-                branch_if: false,
+            Instruction::Cpy {
+                addressing_mode: CpyAddressingMode::CpyAddressAddressingMode {
+                    mode: CpyAddressAddressingMode::ZeroPage {
+                        mode: AM::ZeroPage { address: 0xc0 }
+                    }
+                }
             },
             0x8002
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BNE, 0x00, 0x00]).unwrap();
+    cpu.load(&[CPY_ZERO_PAGE, 0x02]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Zero, false);
-    cpu.run().unwrap();
-    assert_eq!(cpu.program_counter, 0x8003);
-
-    let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BNE, 0x08, 0x00]).unwrap();
-    cpu.reset().unwrap();
-    cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Zero, false);
-    cpu.run().unwrap();
-    assert_eq!(cpu.program_counter, 0x800b);
+    cpu.bus.load(0x00, &[0x01, 0x02, 123, 0x04]).unwrap();
+    cpu.register_y = 127;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_y, 127);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BNE, 0x08, 0x00]).unwrap();
     cpu.reset().unwrap();
+    cpu.load(&[CPY_ZERO_PAGE, 0x01]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Zero, true);
-    cpu.run().unwrap();
-    assert_eq!(cpu.program_counter, 0x8003);
+    cpu.bus.load(0x00, &[0x01, 200, 0x03, 0x04]).unwrap();
+    cpu.register_y = 127;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_y, 127);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BNE, 0xf8, 0x00]).unwrap();
     cpu.reset().unwrap();
+    cpu.load(&[CPY_ZERO_PAGE, 0x00]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Zero, false);
-    cpu.run().unwrap();
-    assert_eq!(
-        cpu.program_counter,
-        0x8003u16.wrapping_add(0xf8u8 as i8 as u16)
-    );
+    cpu.bus.load(0x00, &[200, 0x02, 0x03, 0x04]).unwrap();
+    cpu.register_y = 200;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_y, 200);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Carry));
 }
 
 #[test]
-fn bpl() {
-    use super::opcodes::BPL;
+fn dec_zero_page() {
+    use super::opcodes::DEC_ZERO_PAGE;
 
     assert!(matches!(
-        get_instruction(&[BPL, 0x0b]).unwrap(),
+        get_instruction(&[DEC_ZERO_PAGE, 0xc0]).unwrap(),
         (
-            Instruction::Branch {
-                addressing_mode: AM::Relative { offset: 0x0b },
-                flag: Flag::Negative,
-                branch_if: false,
+            Instruction::Dec {
+                addressing_mode: DecAddressingMode::ZeroPage {
+                    mode: AM::ZeroPage { address: 0xc0 }
+                }
             },
             0x8002
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BPL, 0x00, 0x00]).unwrap();
+    cpu.load(&[DEC_ZERO_PAGE, 0x02]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Negative, false);
-    cpu.run().unwrap();
-    assert_eq!(cpu.program_counter, 0x8003);
+    cpu.bus.load(0x00, &[0x01, 0x02, 1, 0x04]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x02), 0);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BPL, 0x08, 0x00]).unwrap();
+    cpu.load(&[DEC_ZERO_PAGE, 0x03]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Negative, false);
-    cpu.run().unwrap();
-    assert_eq!(cpu.program_counter, 0x800b);
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x03), 0xff);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn inc_zero_page() {
+    use super::opcodes::INC_ZERO_PAGE;
+
+    assert!(matches!(
+        get_instruction(&[INC_ZERO_PAGE, 0xc0]).unwrap(),
+        (
+            Instruction::Inc {
+                addressing_mode: IncAddressingMode::ZeroPage {
+                    mode: AM::ZeroPage { address: 0xc0 }
+                }
+            },
+            0x8002
+        )
+    ));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BPL, 0x08, 0x00]).unwrap();
+    cpu.load(&[INC_ZERO_PAGE, 0x02]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Negative, true);
-    cpu.run().unwrap();
-    assert_eq!(cpu.program_counter, 0x8003);
+    cpu.bus.load(0x00, &[0x01, 0x02, 0xff, 0x04]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x02), 0);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BPL, 0xf8, 0x00]).unwrap();
+    cpu.load(&[INC_ZERO_PAGE, 0x03]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Negative, false);
-    cpu.run().unwrap();
-    assert_eq!(
-        cpu.program_counter,
-        0x8003u16.wrapping_add(0xf8u8 as i8 as u16)
-    );
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0x7f]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x03), 0x80);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Overflow));
 }
 
 #[test]
-fn bvc() {
-    use super::opcodes::BVC;
+fn lda_zero_page() {
+    use super::opcodes::LDA_ZERO_PAGE;
 
     assert!(matches!(
-        get_instruction(&[BVC, 0x0b]).unwrap(),
+        get_instruction(&[LDA_ZERO_PAGE, 0xc0]).unwrap(),
         (
-            Instruction::Branch {
-                addressing_mode: AM::Relative { offset: 0x0b },
-                flag: Flag::Overflow,
-                branch_if: false,
+            Instruction::Ld {
+                destination: Register::A,
+                addressing_mode: LdAddressingMode::LdAddressAddressingMode {
+                    mode: LdAddressAddressingMode::ZeroPage {
+                        mode: AM::ZeroPage { address: 0xc0 }
+                    }
+                }
             },
-            0x8002
+            0x8002,
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BVC, 0x00, 0x00]).unwrap();
+    cpu.load(&[LDA_ZERO_PAGE, 0x02, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Overflow, false);
-    cpu.run().unwrap();
-    assert_eq!(cpu.program_counter, 0x8003);
+    cpu.bus.load(0x00, &[0x01, 0x02, 0xf1, 0x04]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0xf1);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BVC, 0x08, 0x00]).unwrap();
-    cpu.reset().unwrap();
-    cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Overflow, false);
-    cpu.run().unwrap();
-    assert_eq!(cpu.program_counter, 0x800b);
+    cpu.load_and_run_test(&[LDA_ZERO_PAGE, 0x02, 0x00]).unwrap();
+    assert_eq!(cpu.register_a, 0x00);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn ldx_zero_page() {
+    use super::opcodes::LDX_ZERO_PAGE;
+
+    assert!(matches!(
+        get_instruction(&[LDX_ZERO_PAGE, 0xc0]).unwrap(),
+        (
+            Instruction::Ld {
+                destination: Register::X,
+                addressing_mode: LdAddressingMode::LdAddressAddressingMode {
+                    mode: LdAddressAddressingMode::ZeroPage {
+                        mode: AM::ZeroPage { address: 0xc0 }
+                    }
+                }
+            },
+            0x8002,
+        )
+    ));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BVC, 0x08, 0x00]).unwrap();
+    cpu.load(&[LDX_ZERO_PAGE, 0x02, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Overflow, true);
-    cpu.run().unwrap();
-    assert_eq!(cpu.program_counter, 0x8003);
+    cpu.bus.load(0x00, &[0x01, 0x02, 0xf1, 0x04]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_x, 0xf1);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BVC, 0xf8, 0x00]).unwrap();
-    cpu.reset().unwrap();
-    cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Overflow, false);
-    cpu.run().unwrap();
-    assert_eq!(
-        cpu.program_counter,
-        0x8003u16.wrapping_add(0xf8u8 as i8 as u16)
-    );
+    cpu.load_and_run_test(&[LDX_ZERO_PAGE, 0x02, 0x00]).unwrap();
+    assert_eq!(cpu.register_a, 0x00);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
 }
 
 #[test]
-fn bvs() {
-    use super::opcodes::BVS;
+fn ldy_zero_page() {
+    use super::opcodes::LDY_ZERO_PAGE;
 
     assert!(matches!(
-        get_instruction(&[BVS, 0x0b]).unwrap(),
+        get_instruction(&[LDY_ZERO_PAGE, 0xc0]).unwrap(),
         (
-            Instruction::Branch {
-                addressing_mode: AM::Relative { offset: 0x0b },
-                flag: Flag::Overflow,
-                branch_if: true,
+            Instruction::Ld {
+                destination: Register::Y,
+                addressing_mode: LdAddressingMode::LdAddressAddressingMode {
+                    mode: LdAddressAddressingMode::ZeroPage {
+                        mode: AM::ZeroPage { address: 0xc0 }
+                    }
+                }
             },
-            0x8002
+            0x8002,
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BVS, 0x00, 0x00]).unwrap();
+    cpu.load(&[LDY_ZERO_PAGE, 0x02, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Overflow, true);
-    cpu.run().unwrap();
-    assert_eq!(cpu.program_counter, 0x8003);
+    cpu.bus.load(0x00, &[0x01, 0x02, 0xf1, 0x04]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_y, 0xf1);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDY_ZERO_PAGE, 0x02, 0x00]).unwrap();
+    assert_eq!(cpu.register_y, 0x00);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn sta_zero_page() {
+    use super::opcodes::{LDA_IMMEDIATE, STA_ZERO_PAGE};
+
+    assert!(matches!(
+        get_instruction(&[STA_ZERO_PAGE, 0xc0]).unwrap(),
+        (
+            Instruction::St {
+                origin: Register::A,
+                addressing_mode: StAddressingMode::ZeroPage {
+                    mode: AM::ZeroPage { address: 0xc0 }
+                }
+            },
+            0x8002,
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0xf1, STA_ZERO_PAGE, 0x02, 0x00])
+        .unwrap();
+    assert_eq!(cpu.bus.read(0x02), 0xf1);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BVS, 0x08, 0x00]).unwrap();
+    cpu.load(&[STA_ZERO_PAGE, 0x02, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Overflow, true);
-    cpu.run().unwrap();
-    assert_eq!(cpu.program_counter, 0x800b);
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x02), 0x00);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn stx_zero_page() {
+    use super::opcodes::STX_ZERO_PAGE;
+
+    assert!(matches!(
+        get_instruction(&[STX_ZERO_PAGE, 0xc0]).unwrap(),
+        (
+            Instruction::St {
+                origin: Register::X,
+                addressing_mode: StAddressingMode::ZeroPage {
+                    mode: AM::ZeroPage { address: 0xc0 }
+                }
+            },
+            0x8002,
+        )
+    ));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BVS, 0x08, 0x00]).unwrap();
+    cpu.load(&[STX_ZERO_PAGE, 0x03, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Overflow, false);
-    cpu.run().unwrap();
-    assert_eq!(cpu.program_counter, 0x8003);
+    cpu.register_x = 0xab;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x03), 0xab);
+    assert_eq!(cpu.bus.read(0x02), 0x00);
+}
+
+#[test]
+fn sty_zero_page() {
+    use super::opcodes::STY_ZERO_PAGE;
+
+    assert!(matches!(
+        get_instruction(&[STY_ZERO_PAGE, 0xc0]).unwrap(),
+        (
+            Instruction::St {
+                origin: Register::Y,
+                addressing_mode: StAddressingMode::ZeroPage {
+                    mode: AM::ZeroPage { address: 0xc0 }
+                }
+            },
+            0x8002,
+        )
+    ));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run(&[BVS, 0xf8, 0x00]).unwrap();
+    cpu.load(&[STY_ZERO_PAGE, 0x03, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.status.set(Flag::Overflow, true);
-    cpu.run().unwrap();
-    assert_eq!(
-        cpu.program_counter,
-        0x8003u16.wrapping_add(0xf8u8 as i8 as u16)
-    );
+    cpu.register_y = 0xab;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x03), 0xab);
+    assert_eq!(cpu.bus.read(0x02), 0x00);
 }
 
 #[test]
-fn adc_absolute() {
-    use super::opcodes::{ADC_ABSOLUTE, LDA_IMMEDIATE};
+fn adc_zero_page_x() {
+    use super::opcodes::{ADC_ZERO_PAGE_X, LDA_IMMEDIATE};
 
     assert!(matches!(
-        get_instruction(&[ADC_ABSOLUTE, 0xab, 0xcd]).unwrap(),
+        get_instruction(&[ADC_ZERO_PAGE_X, 0xc0]).unwrap(),
         (
             Instruction::Adc {
                 addressing_mode: AdcAddressingMode::AdcAddressAddressingMode {
-                    mode: AdcAddressAddressingMode::Absolute {
-                        mode: AM::Absolute { address: 0xcdab },
-                    },
-                },
+                    mode: AdcAddressAddressingMode::ZeroPageX {
+                        mode: AM::ZeroPageX { address: 0xc0 }
+                    }
+                }
             },
-            0x8003,
+            0x8002
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[ADC_ABSOLUTE, 0x03, 0x01, 0x00]).unwrap();
+    cpu.load(&[ADC_ZERO_PAGE_X, 0x01, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory.load(0x0100, &[0x01, 0x02, 0x03, 0xf0]).unwrap();
-    cpu.run().unwrap();
+    cpu.register_x = 0x02;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0xf0]).unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_a, 0xf0);
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
@@ -1839,12 +1967,13 @@ fn adc_absolute() {
     assert!(!cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDA_IMMEDIATE, 0x71, ADC_ABSOLUTE, 0x03, 0x01, 0x00])
+    cpu.load(&[LDA_IMMEDIATE, 0x71, ADC_ZERO_PAGE_X, 0x02, 0x00])
         .unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory.load(0x0100, &[0x01, 0x02, 0x03, 0x72]).unwrap();
-    cpu.run().unwrap();
+    cpu.register_x = 0x01;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0x72]).unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_a, u8::wrapping_add(0x71, 0x72));
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
@@ -1852,12 +1981,13 @@ fn adc_absolute() {
     assert!(!cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDA_IMMEDIATE, 0x80, ADC_ABSOLUTE, 0x02, 0x01, 0x00])
+    cpu.load(&[LDA_IMMEDIATE, 0x80, ADC_ZERO_PAGE_X, 0x03, 0x00])
         .unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory.load(0x0100, &[0x01, 0x02, 0x80, 0x04]).unwrap();
-    cpu.run().unwrap();
+    cpu.register_x = 0x00;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0x80]).unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_a, 0x00);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
@@ -1866,186 +1996,216 @@ fn adc_absolute() {
 }
 
 #[test]
-fn and_absolute() {
-    use super::opcodes::{AND_ABSOLUTE, LDA_IMMEDIATE};
+fn and_zero_page_x() {
+    use super::opcodes::{AND_ZERO_PAGE_X, LDA_IMMEDIATE};
 
     assert!(matches!(
-        get_instruction(&[AND_ABSOLUTE, 0xab, 0xcd]).unwrap(),
+        get_instruction(&[AND_ZERO_PAGE_X, 0xc0]).unwrap(),
         (
             Instruction::And {
                 addressing_mode: AndAddressingMode::AndAddressAddressingMode {
-                    mode: AndAddressAddressingMode::Absolute {
-                        mode: AM::Absolute { address: 0xcdab },
-                    },
-                },
+                    mode: AndAddressAddressingMode::ZeroPageX {
+                        mode: AM::ZeroPageX { address: 0xc0 }
+                    }
+                }
             },
-            0x8003
+            0x8002
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[AND_ABSOLUTE, 0x02, 0x01, 0x00]).unwrap();
+    cpu.load(&[AND_ZERO_PAGE_X, 0x01, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory.load(0x1000, &[0x01, 0x02, 0xf0, 0x04]).unwrap();
-    cpu.run().unwrap();
+    cpu.register_x = 0x01;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0xf0, 0x04]).unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_a, 0b00000000);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDA_IMMEDIATE, 0b11010011, AND_ABSOLUTE, 0x03, 0x01, 0x00])
+    cpu.load(&[LDA_IMMEDIATE, 0b11010011, AND_ZERO_PAGE_X, 0x02, 0x00])
         .unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory
-        .load(0x0100, &[0x01, 0x02, 0x03, 0b10110001])
-        .unwrap();
-    cpu.run().unwrap();
+    cpu.register_x = 0x01;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0b10110001]).unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_a, 0b10010001);
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0b00110111, AND_ABSOLUTE, 0x02, 0x01, 0x00])
+    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0b00110111, AND_ZERO_PAGE_X, 0x02, 0x00])
         .unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory
-        .load(0x0100, &[0x01, 0x02, 0b00110111, 0x04])
-        .unwrap();
-    cpu.run().unwrap();
+    cpu.register_x = 0x00;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0b00110111, 0x04]).unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_a, 0b00110111);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
 }
 
 #[test]
-fn asl_absolute() {
-    use super::opcodes::ASL_ABSOLUTE;
+fn eor_zero_page_x() {
+    use super::opcodes::{EOR_ZERO_PAGE_X, LDA_IMMEDIATE};
 
     assert!(matches!(
-        get_instruction(&[ASL_ABSOLUTE, 0xab, 0xcd]).unwrap(),
+        get_instruction(&[EOR_ZERO_PAGE_X, 0xab]).unwrap(),
         (
-            Instruction::Asl {
-                addressing_mode: AslAddressingMode::AslAddressAddressingMode {
-                    mode: AslAddressAddressingMode::Absolute {
-                        mode: AM::Absolute { address: 0xcdab },
-                    },
-                },
+            Instruction::Eor {
+                addressing_mode: EorAddressingMode::EorAddressAddressingMode {
+                    mode: EorAddressAddressingMode::ZeroPageX {
+                        mode: AM::ZeroPageX { address: 0xab }
+                    }
+                }
             },
-            0x8003
+            0x8002
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[ASL_ABSOLUTE, 0x02, 0x01, 0x00]).unwrap();
+    cpu.load(&[LDA_IMMEDIATE, 0b1101_0011, EOR_ZERO_PAGE_X, 0x0f, 0x00])
+        .unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory
-        .load(0x0100, &[0x01, 0x02, 0b10000000, 0x04])
-        .unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.memory.read(0x0102), 0b00000000);
-    assert!(cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Carry));
+    cpu.register_x = 0x01;
+    cpu.bus.write(0x10, 0b1011_0001);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0110_0010);
     assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[ASL_ABSOLUTE, 0x03, 0x01, 0x00]).unwrap();
+    cpu.load(&[LDA_IMMEDIATE, 0b0101_1010, EOR_ZERO_PAGE_X, 0x0f, 0x00])
+        .unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory
-        .load(0x0100, &[0x01, 0x02, 0x03, 0b0101_0101])
-        .unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.memory.read(0x0103), 0b1010_1010);
-    assert!(!cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Carry));
+    cpu.register_x = 0x01;
+    cpu.bus.write(0x10, 0b0101_1010);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0000_0000);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
 }
 
 #[test]
-fn bit_absolute() {
-    use super::opcodes::BIT_ABSOLUTE;
+fn ora_zero_page_x() {
+    use super::opcodes::{LDA_IMMEDIATE, ORA_ZERO_PAGE_X};
 
     assert!(matches!(
-        get_instruction(&[BIT_ABSOLUTE, 0xab, 0xcd]).unwrap(),
+        get_instruction(&[ORA_ZERO_PAGE_X, 0xab]).unwrap(),
         (
-            Instruction::Bit {
-                addressing_mode: BitAddressingMode::Absolute {
-                    mode: AM::Absolute { address: 0xcdab }
+            Instruction::Ora {
+                addressing_mode: OraAddressingMode::OraAddressAddressingMode {
+                    mode: OraAddressAddressingMode::ZeroPageX {
+                        mode: AM::ZeroPageX { address: 0xab }
+                    }
                 }
             },
-            0x8003
+            0x8002
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[BIT_ABSOLUTE, 0x02, 0x10, 0x00]).unwrap();
+    cpu.load(&[LDA_IMMEDIATE, 0b1101_0011, ORA_ZERO_PAGE_X, 0x0f, 0x00])
+        .unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory
-        .load(0x1000, &[0x01, 0x02, 0b11000000, 0x04])
+    cpu.register_x = 0x01;
+    cpu.bus.write(0x10, 0b1011_0001);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b1111_0011);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0b0000_0000, ORA_ZERO_PAGE_X, 0x0f, 0x00])
         .unwrap();
-    cpu.register_a = 0b0011_1111;
-    cpu.run().unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x01;
+    cpu.bus.write(0x10, 0b0000_0000);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0000_0000);
+    assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Overflow));
-    assert!(cpu.status.get(Flag::Negative));
+}
+
+#[test]
+fn asl_zero_page_x() {
+    use super::opcodes::ASL_ZERO_PAGE_X;
+
+    assert!(matches!(
+        get_instruction(&[ASL_ZERO_PAGE_X, 0xab]).unwrap(),
+        (
+            Instruction::Asl {
+                addressing_mode: AslAddressingMode::AslAddressAddressingMode {
+                    mode: AslAddressAddressingMode::ZeroPageX {
+                        mode: AM::ZeroPageX { address: 0xab }
+                    }
+                }
+            },
+            0x8002
+        )
+    ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[BIT_ABSOLUTE, 0x03, 0x10, 0x00]).unwrap();
+    cpu.load(&[ASL_ZERO_PAGE_X, 0x01, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory
-        .load(0x1000, &[0x01, 0x02, 0x03, 0b0101_0101])
-        .unwrap();
-    cpu.register_a = 0b0001_0101;
-    cpu.run().unwrap();
-    assert!(!cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Overflow));
+    cpu.register_x = 0x01;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0b10000000, 0x04]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x02), 0b00000000);
+    assert!(cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Carry));
     assert!(!cpu.status.get(Flag::Negative));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[BIT_ABSOLUTE, 0x03, 0x10, 0x00]).unwrap();
+    cpu.load(&[ASL_ZERO_PAGE_X, 0x03, 0x00]).unwrap();
     cpu.reset().unwrap();
+    cpu.register_x = 0x00;
     cpu.program_counter = 0x8000;
-    cpu.memory
-        .load(0x1000, &[0x01, 0x02, 0x03, 0b1010_1010])
+    cpu.bus
+        .load(0x00, &[0x01, 0x02, 0x03, 0b0101_0101])
         .unwrap();
-    cpu.register_a = 0b0101_0101;
-    cpu.run().unwrap();
-    assert!(cpu.status.get(Flag::Zero));
-    assert!(!cpu.status.get(Flag::Overflow));
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x03), 0b1010_1010);
+    assert!(!cpu.status.get(Flag::Zero));
     assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Carry));
 }
 
 #[test]
-fn cmp_absolute() {
-    use super::opcodes::CMP_ABSOLUTE;
+fn cmp_zero_page_x() {
+    use super::opcodes::CMP_ZERO_PAGE_X;
 
     assert!(matches!(
-        get_instruction(&[CMP_ABSOLUTE, 0xab, 0xcd]).unwrap(),
+        get_instruction(&[CMP_ZERO_PAGE_X, 0xc0]).unwrap(),
         (
             Instruction::Cmp {
                 addressing_mode: CmpAddressingMode::CmpAddressAddressingMode {
-                    mode: CmpAddressAddressingMode::Absolute {
-                        mode: AM::Absolute { address: 0xcdab }
+                    mode: CmpAddressAddressingMode::ZeroPageX {
+                        mode: AM::ZeroPageX { address: 0xc0 }
                     }
                 }
             },
-            0x8003
+            0x8002
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[CMP_ABSOLUTE, 0x02, 0x10]).unwrap();
+    cpu.load(&[CMP_ZERO_PAGE_X, 0x02]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory.load(0x1000, &[0x01, 0x02, 123, 0x04]).unwrap();
+    cpu.register_x = 1;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 123]).unwrap();
     cpu.register_a = 127;
-    cpu.run().unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_a, 127);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
@@ -2053,11 +2213,12 @@ fn cmp_absolute() {
 
     let mut cpu = Cpu::new();
     cpu.reset().unwrap();
-    cpu.load(&[CMP_ABSOLUTE, 0x01, 0x10]).unwrap();
+    cpu.load(&[CMP_ZERO_PAGE_X, 0x01]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory.load(0x1000, &[0x01, 200, 0x03, 0x04]).unwrap();
+    cpu.register_x = 0x00;
+    cpu.bus.load(0x00, &[0x01, 200, 0x03, 0x04]).unwrap();
     cpu.register_a = 127;
-    cpu.run().unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_a, 127);
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
@@ -2065,11 +2226,12 @@ fn cmp_absolute() {
 
     let mut cpu = Cpu::new();
     cpu.reset().unwrap();
-    cpu.load(&[CMP_ABSOLUTE, 0x00, 0x10]).unwrap();
+    cpu.load(&[CMP_ZERO_PAGE_X, 0x00]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory.load(0x1000, &[200, 0x02, 0x03, 0x04]).unwrap();
+    cpu.register_x = 0x02;
+    cpu.bus.load(0x00, &[0x01, 0x02, 200, 0x04]).unwrap();
     cpu.register_a = 200;
-    cpu.run().unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_a, 200);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
@@ -2077,550 +2239,650 @@ fn cmp_absolute() {
 }
 
 #[test]
-fn cpx_absolute() {
-    use super::opcodes::CPX_ABSOLUTE;
+fn lda_zero_page_x() {
+    use super::opcodes::LDA_ZERO_PAGE_X;
 
     assert!(matches!(
-        get_instruction(&[CPX_ABSOLUTE, 0xab, 0xcd]).unwrap(),
+        get_instruction(&[LDA_ZERO_PAGE_X, 0xc0]).unwrap(),
         (
-            Instruction::Cpx {
-                addressing_mode: CpxAddressingMode::CpxAddressAddressingMode {
-                    mode: CpxAddressAddressingMode::Absolute {
-                        mode: AM::Absolute { address: 0xcdab }
+            Instruction::Ld {
+                destination: Register::A,
+                addressing_mode: LdAddressingMode::LdAddressAddressingMode {
+                    mode: LdAddressAddressingMode::ZeroPageX {
+                        mode: AM::ZeroPageX { address: 0xc0 }
                     }
                 }
             },
-            0x8003
+            0x8002,
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[CPX_ABSOLUTE, 0x02, 0x10]).unwrap();
-    cpu.reset().unwrap();
-    cpu.program_counter = 0x8000;
-    cpu.memory.load(0x1000, &[0x01, 0x02, 123, 0x04]).unwrap();
-    cpu.register_x = 127;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_x, 127);
-    assert!(!cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Carry));
-
-    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_ZERO_PAGE_X, 0x02, 0x00]).unwrap();
     cpu.reset().unwrap();
-    cpu.load(&[CPX_ABSOLUTE, 0x01, 0x10]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory.load(0x1000, &[0x01, 200, 0x03, 0x04]).unwrap();
-    cpu.register_x = 127;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_x, 127);
+    cpu.register_x = 0x01;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0xf4]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0xf4);
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
-    assert!(!cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
-    cpu.reset().unwrap();
-    cpu.load(&[CPX_ABSOLUTE, 0x00, 0x10]).unwrap();
-    cpu.program_counter = 0x8000;
-    cpu.memory.load(0x1000, &[200, 0x02, 0x03, 0x04]).unwrap();
-    cpu.register_x = 200;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_x, 200);
+    cpu.load_and_run_test(&[LDA_ZERO_PAGE_X, 0x02, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_a, 0x00);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Carry));
 }
 
 #[test]
-fn cpy_absolute() {
-    use super::opcodes::CPY_ABSOLUTE;
+fn ldy_zero_page_x() {
+    use super::opcodes::LDY_ZERO_PAGE_X;
 
     assert!(matches!(
-        get_instruction(&[CPY_ABSOLUTE, 0xab, 0xcd]).unwrap(),
+        get_instruction(&[LDY_ZERO_PAGE_X, 0xc0]).unwrap(),
         (
-            Instruction::Cpy {
-                addressing_mode: CpyAddressingMode::CpyAddressAddressingMode {
-                    mode: CpyAddressAddressingMode::Absolute {
-                        mode: AM::Absolute { address: 0xcdab }
+            Instruction::Ld {
+                destination: Register::Y,
+                addressing_mode: LdAddressingMode::LdAddressAddressingMode {
+                    mode: LdAddressAddressingMode::ZeroPageX {
+                        mode: AM::ZeroPageX { address: 0xc0 }
                     }
                 }
             },
-            0x8003
+            0x8002,
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[CPY_ABSOLUTE, 0x02, 0x10]).unwrap();
-    cpu.reset().unwrap();
-    cpu.program_counter = 0x8000;
-    cpu.memory.load(0x1000, &[0x01, 0x02, 123, 0x04]).unwrap();
-    cpu.register_y = 127;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_y, 127);
-    assert!(!cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Carry));
-
-    let mut cpu = Cpu::new();
+    cpu.load(&[LDY_ZERO_PAGE_X, 0x04, 0x00]).unwrap();
     cpu.reset().unwrap();
-    cpu.load(&[CPY_ABSOLUTE, 0x01, 0x10]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory.load(0x1000, &[0x01, 200, 0x03, 0x04]).unwrap();
-    cpu.register_y = 127;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_y, 127);
+    cpu.register_x = 0xff;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0xf4]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_y, 0xf4);
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
-    assert!(!cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
-    cpu.reset().unwrap();
-    cpu.load(&[CPY_ABSOLUTE, 0x00, 0x10]).unwrap();
-    cpu.program_counter = 0x8000;
-    cpu.memory.load(0x1000, &[200, 0x02, 0x03, 0x04]).unwrap();
-    cpu.register_y = 200;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_y, 200);
+    cpu.load_and_run_test(&[LDY_ZERO_PAGE_X, 0x02, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_y, 0x00);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Carry));
 }
 
 #[test]
-fn lda_absolute() {
-    use super::opcodes::LDA_ABSOLUTE;
+fn sta_zero_page_x() {
+    use super::opcodes::STA_ZERO_PAGE_X;
 
     assert!(matches!(
-        get_instruction(&[LDA_ABSOLUTE, 0xab, 0xcd]).unwrap(),
+        get_instruction(&[STA_ZERO_PAGE_X, 0xc0]).unwrap(),
         (
-            Instruction::Ld {
-                destination: Register::A,
-                addressing_mode: LdAddressingMode::LdAddressAddressingMode {
-                    mode: LdAddressAddressingMode::Absolute {
-                        mode: AM::Absolute { address: 0xcdab },
-                    },
-                },
+            Instruction::St {
+                origin: Register::A,
+                addressing_mode: StAddressingMode::ZeroPageX {
+                    mode: AM::ZeroPageX { address: 0xc0 }
+                }
             },
-            0x8003,
+            0x8002,
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDA_ABSOLUTE, 0x02, 0x01, 0x00]).unwrap();
+    cpu.load(&[STA_ZERO_PAGE_X, 0x01, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory.load(0x0100, &[0x01, 0x02, 0xf3, 0x04]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 0xf3);
-    assert!(cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
+    cpu.register_a = 0x42;
+    cpu.register_x = 0x02;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x03), 0x42);
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDA_ABSOLUTE, 0x00, 0x02, 0x00])
-        .unwrap();
-    assert_eq!(cpu.register_a, 0x00);
-    assert!(!cpu.status.get(Flag::Negative));
-    assert!(cpu.status.get(Flag::Zero));
+    cpu.load(&[STA_ZERO_PAGE_X, 0xff, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_a = 0x42;
+    cpu.register_x = 0x02;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x01), 0x42);
+    assert_eq!(cpu.bus.read(0x0101), 0x00);
 }
 
 #[test]
-fn ldx_absolute() {
-    use super::opcodes::LDX_ABSOLUTE;
+fn sty_zero_page_x() {
+    use super::opcodes::STY_ZERO_PAGE_X;
 
     assert!(matches!(
-        get_instruction(&[LDX_ABSOLUTE, 0xab, 0xcd]).unwrap(),
+        get_instruction(&[STY_ZERO_PAGE_X, 0xc0]).unwrap(),
         (
-            Instruction::Ld {
-                destination: Register::X,
-                addressing_mode: LdAddressingMode::LdAddressAddressingMode {
-                    mode: LdAddressAddressingMode::Absolute {
-                        mode: AM::Absolute { address: 0xcdab },
-                    },
-                },
+            Instruction::St {
+                origin: Register::Y,
+                addressing_mode: StAddressingMode::ZeroPageX {
+                    mode: AM::ZeroPageX { address: 0xc0 }
+                }
             },
-            0x8003,
+            0x8002,
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDX_ABSOLUTE, 0x02, 0x01, 0x00]).unwrap();
+    cpu.load(&[STY_ZERO_PAGE_X, 0x01, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory.load(0x0100, &[0x01, 0x02, 0xf3, 0x04]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_x, 0xf3);
-    assert!(cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
+    cpu.register_y = 0x42;
+    cpu.register_x = 0x02;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x03), 0x42);
+}
+
+#[test]
+fn stx_zero_page_y() {
+    use super::opcodes::STX_ZERO_PAGE_Y;
+
+    assert!(matches!(
+        get_instruction(&[STX_ZERO_PAGE_Y, 0xc0]).unwrap(),
+        (
+            Instruction::St {
+                origin: Register::X,
+                addressing_mode: StAddressingMode::ZeroPageY {
+                    mode: AM::ZeroPageY { address: 0xc0 }
+                }
+            },
+            0x8002,
+        )
+    ));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDX_ABSOLUTE, 0x00, 0x02, 0x00])
-        .unwrap();
-    assert_eq!(cpu.register_x, 0x00);
-    assert!(!cpu.status.get(Flag::Negative));
-    assert!(cpu.status.get(Flag::Zero));
+    cpu.load(&[STX_ZERO_PAGE_Y, 0x01, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x42;
+    cpu.register_y = 0x02;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x03), 0x42);
 }
 
 #[test]
-fn ldy_absolute() {
-    use super::opcodes::LDY_ABSOLUTE;
+fn ldx_zero_page_y() {
+    use super::opcodes::LDX_ZERO_PAGE_Y;
 
     assert!(matches!(
-        get_instruction(&[LDY_ABSOLUTE, 0xab, 0xcd]).unwrap(),
+        get_instruction(&[LDX_ZERO_PAGE_Y, 0xc0]).unwrap(),
         (
             Instruction::Ld {
-                destination: Register::Y,
+                destination: Register::X,
                 addressing_mode: LdAddressingMode::LdAddressAddressingMode {
-                    mode: LdAddressAddressingMode::Absolute {
-                        mode: AM::Absolute { address: 0xcdab },
-                    },
-                },
+                    mode: LdAddressAddressingMode::ZeroPageY {
+                        mode: AM::ZeroPageY { address: 0xc0 }
+                    }
+                }
             },
-            0x8003,
+            0x8002,
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDY_ABSOLUTE, 0x02, 0x01, 0x00]).unwrap();
+    cpu.load(&[LDX_ZERO_PAGE_Y, 0x02, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.memory.load(0x0100, &[0x01, 0x02, 0xf3, 0x04]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_y, 0xf3);
+    cpu.register_y = 0x01;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0xf4]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_x, 0xf4);
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDY_ABSOLUTE, 0x00, 0x02, 0x00])
+    cpu.load_and_run_test(&[LDX_ZERO_PAGE_Y, 0x02, 0x00])
         .unwrap();
-    assert_eq!(cpu.register_y, 0x00);
+    assert_eq!(cpu.register_x, 0x00);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
 }
 
 #[test]
-fn adc_absolute_x() {
-    use super::opcodes::{ADC_ABSOLUTE_X, LDA_IMMEDIATE};
+fn bcc() {
+    use super::opcodes::BCC;
 
     assert!(matches!(
-        get_instruction(&[ADC_ABSOLUTE_X, 0xab, 0xcd]).unwrap(),
+        get_instruction(&[BCC, 0x0b]).unwrap(),
         (
-            Instruction::Adc {
-                addressing_mode: AdcAddressingMode::AdcAddressAddressingMode {
-                    mode: AdcAddressAddressingMode::AbsoluteX {
-                        mode: AM::AbsoluteX { address: 0xcdab },
-                    },
-                },
+            Instruction::Branch {
+                addressing_mode: AM::Relative { offset: 0x0b },
+                flag: Flag::Carry,
+                branch_if: false,
             },
-            0x8003
+            0x8002
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[ADC_ABSOLUTE_X, 0x02, 0x01, 0x00]).unwrap();
+    cpu.load(&[BCC, 0x00, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0x01;
-    cpu.memory.load(0x0100, &[0x01, 0x02, 0x03, 0xf0]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 0xf0);
-    assert!(cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
-    assert!(!cpu.status.get(Flag::Overflow));
-    assert!(!cpu.status.get(Flag::Carry));
+    cpu.status.set(Flag::Carry, false);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x8002);
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDA_IMMEDIATE, 0x71, ADC_ABSOLUTE_X, 0x01, 0x01, 0x00])
-        .unwrap();
+    cpu.load(&[BCC, 0x08, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0x02;
-    cpu.memory.load(0x0100, &[0x01, 0x02, 0x03, 0x72]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, u8::wrapping_add(0x71, 0x72));
-    assert!(cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Overflow));
-    assert!(!cpu.status.get(Flag::Carry));
+    cpu.status.set(Flag::Carry, false);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x800a);
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDA_IMMEDIATE, 0x80, ADC_ABSOLUTE_X, 0x02, 0x01, 0x00])
-        .unwrap();
+    cpu.load(&[BCC, 0x08, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0x00;
-    cpu.memory.load(0x0100, &[0x01, 0x02, 0x80, 0x04]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 0x00);
-    assert!(!cpu.status.get(Flag::Negative));
-    assert!(cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Overflow));
-    assert!(cpu.status.get(Flag::Carry));
+    cpu.status.set(Flag::Carry, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x8002);
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[BCC, 0xf8, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Carry, false);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(
+        cpu.program_counter,
+        0x8002u16.wrapping_add(0xf8u8 as i8 as u16)
+    );
 }
 
 #[test]
-fn and_absolute_x() {
-    use super::opcodes::{AND_ABSOLUTE_X, LDA_IMMEDIATE};
+fn bcs() {
+    use super::opcodes::BCS;
 
     assert!(matches!(
-        get_instruction(&[AND_ABSOLUTE_X, 0xab, 0xcd]).unwrap(),
+        get_instruction(&[BCS, 0x0b]).unwrap(),
         (
-            Instruction::And {
-                addressing_mode: AndAddressingMode::AndAddressAddressingMode {
-                    mode: AndAddressAddressingMode::AbsoluteX {
-                        mode: AM::AbsoluteX { address: 0xcdab },
-                    },
-                },
+            Instruction::Branch {
+                addressing_mode: AM::Relative { offset: 0x0b },
+                flag: Flag::Carry,
+                branch_if: true,
             },
-            0x8003
+            0x8002
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[AND_ABSOLUTE_X, 0x01, 0x01, 0x00]).unwrap();
+    cpu.load(&[BCS, 0x00, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0x01;
-    cpu.memory.load(0x1000, &[0x01, 0x02, 0xf0, 0x04]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 0b00000000);
-    assert!(!cpu.status.get(Flag::Negative));
-    assert!(cpu.status.get(Flag::Zero));
+    cpu.status.set(Flag::Carry, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x8002);
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDA_IMMEDIATE, 0b11010011, AND_ABSOLUTE_X, 0x02, 0x01, 0x00])
-        .unwrap();
+    cpu.load(&[BCS, 0x08, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0x01;
-    cpu.memory
-        .load(0x0100, &[0x01, 0x02, 0x03, 0b10110001])
-        .unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 0b10010001);
-    assert!(cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
+    cpu.status.set(Flag::Carry, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x800a);
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0b00110111, AND_ABSOLUTE_X, 0x02, 0x01, 0x00])
-        .unwrap();
+    cpu.load(&[BCS, 0x08, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0x00;
-    cpu.memory
-        .load(0x0100, &[0x01, 0x02, 0b00110111, 0x04])
-        .unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 0b00110111);
-    assert!(!cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
+    cpu.status.set(Flag::Carry, false);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x8002);
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[BCS, 0xf8, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Carry, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(
+        cpu.program_counter,
+        0x8002u16.wrapping_add(0xf8u8 as i8 as u16)
+    );
 }
 
 #[test]
-fn asl_absolute_x() {
-    use super::opcodes::ASL_ABSOLUTE_X;
+fn beq() {
+    use super::opcodes::BEQ;
 
     assert!(matches!(
-        get_instruction(&[ASL_ABSOLUTE_X, 0xab, 0xcd]).unwrap(),
+        get_instruction(&[BEQ, 0x0b]).unwrap(),
         (
-            Instruction::Asl {
-                addressing_mode: AslAddressingMode::AslAddressAddressingMode {
-                    mode: AslAddressAddressingMode::AbsoluteX {
-                        mode: AM::AbsoluteX { address: 0xcdab },
-                    },
-                },
+            Instruction::Branch {
+                addressing_mode: AM::Relative { offset: 0x0b },
+                flag: Flag::Zero,
+                branch_if: true,
             },
-            0x8003
+            0x8002
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[ASL_ABSOLUTE_X, 0x01, 0x01, 0x00]).unwrap();
+    cpu.load(&[BEQ, 0x00, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0x01;
-    cpu.memory
-        .load(0x0100, &[0x01, 0x02, 0b10000000, 0x04])
-        .unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.memory.read(0x0102), 0b00000000);
-    assert!(cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Carry));
-    assert!(!cpu.status.get(Flag::Negative));
+    cpu.status.set(Flag::Zero, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x8002);
 
     let mut cpu = Cpu::new();
-    cpu.load(&[ASL_ABSOLUTE_X, 0x03, 0x01, 0x00]).unwrap();
+    cpu.load(&[BEQ, 0x08, 0x00]).unwrap();
     cpu.reset().unwrap();
-    cpu.register_x = 0x00;
     cpu.program_counter = 0x8000;
-    cpu.memory
-        .load(0x0100, &[0x01, 0x02, 0x03, 0b0101_0101])
-        .unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.memory.read(0x0103), 0b1010_1010);
-    assert!(!cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Carry));
+    cpu.status.set(Flag::Zero, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x800a);
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[BEQ, 0x08, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Zero, false);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x8002);
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[BEQ, 0xf8, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Zero, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(
+        cpu.program_counter,
+        0x8002u16.wrapping_add(0xf8u8 as i8 as u16)
+    );
 }
 
 #[test]
-fn cmp_absolute_x() {
-    use super::opcodes::CMP_ABSOLUTE_X;
+fn bmi() {
+    use super::opcodes::BMI;
 
     assert!(matches!(
-        get_instruction(&[CMP_ABSOLUTE_X, 0xab, 0xcd]).unwrap(),
+        get_instruction(&[BMI, 0x0b]).unwrap(),
         (
-            Instruction::Cmp {
-                addressing_mode: CmpAddressingMode::CmpAddressAddressingMode {
-                    mode: CmpAddressAddressingMode::AbsoluteX {
-                        mode: AM::AbsoluteX { address: 0xcdab }
-                    }
-                }
+            Instruction::Branch {
+                addressing_mode: AM::Relative { offset: 0x0b },
+                flag: Flag::Negative,
+                branch_if: true,
             },
-            0x8003
+            0x8002
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[CMP_ABSOLUTE_X, 0x02, 0x10]).unwrap();
+    cpu.load(&[BMI, 0x00, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 1;
-    cpu.memory.load(0x1000, &[0x01, 0x02, 0x03, 123]).unwrap();
-    cpu.register_a = 127;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 127);
-    assert!(!cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Carry));
+    cpu.status.set(Flag::Negative, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x8002);
 
     let mut cpu = Cpu::new();
+    cpu.load(&[BMI, 0x08, 0x00]).unwrap();
     cpu.reset().unwrap();
-    cpu.load(&[CMP_ABSOLUTE_X, 0x01, 0x10]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0x00;
-    cpu.memory.load(0x1000, &[0x01, 200, 0x03, 0x04]).unwrap();
-    cpu.register_a = 127;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 127);
-    assert!(cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
-    assert!(!cpu.status.get(Flag::Carry));
+    cpu.status.set(Flag::Negative, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x800a);
 
     let mut cpu = Cpu::new();
+    cpu.load(&[BMI, 0x08, 0x00]).unwrap();
     cpu.reset().unwrap();
-    cpu.load(&[CMP_ABSOLUTE_X, 0x00, 0x10]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0x02;
-    cpu.memory.load(0x1000, &[0x01, 0x02, 200, 0x04]).unwrap();
-    cpu.register_a = 200;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 200);
-    assert!(!cpu.status.get(Flag::Negative));
-    assert!(cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Carry));
-}
-
-#[test]
-fn lda_absolute_x() {
-    use super::opcodes::LDA_ABSOLUTE_X;
+    cpu.status.set(Flag::Negative, false);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x8002);
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[BMI, 0xf8, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Negative, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(
+        cpu.program_counter,
+        0x8002u16.wrapping_add(0xf8u8 as i8 as u16)
+    );
+}
+
+#[test]
+fn bne() {
+    use super::opcodes::BNE;
 
     assert!(matches!(
-        get_instruction(&[LDA_ABSOLUTE_X, 0xab, 0xcd]).unwrap(),
+        get_instruction(&[BNE, 0x0b]).unwrap(),
         (
-            Instruction::Ld {
-                destination: Register::A,
-                addressing_mode: LdAddressingMode::LdAddressAddressingMode {
-                    mode: LdAddressAddressingMode::AbsoluteX {
-                        mode: AM::AbsoluteX { address: 0xcdab },
-                    },
-                },
+            Instruction::Branch {
+                addressing_mode: AM::Relative { offset: 0x0b },
+                flag: Flag::Zero,
+                branch_if: false,
             },
-            0x8003,
+            0x8002
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDA_ABSOLUTE_X, 0x02, 0x01, 0x00]).unwrap();
+    cpu.load(&[BNE, 0x00, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0x01;
-    cpu.memory.load(0x0100, &[0x01, 0x02, 0x03, 0xf4]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 0xf4);
-    assert!(cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
+    cpu.status.set(Flag::Zero, false);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x8002);
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDA_ABSOLUTE_X, 0x00, 0x02, 0x00])
-        .unwrap();
-    assert_eq!(cpu.register_a, 0x00);
-    assert!(!cpu.status.get(Flag::Negative));
-    assert!(cpu.status.get(Flag::Zero));
+    cpu.load(&[BNE, 0x08, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Zero, false);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x800a);
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[BNE, 0x08, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Zero, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x8002);
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[BNE, 0xf8, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Zero, false);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(
+        cpu.program_counter,
+        0x8002u16.wrapping_add(0xf8u8 as i8 as u16)
+    );
 }
 
 #[test]
-fn ldy_absolute_x() {
-    use super::opcodes::LDY_ABSOLUTE_X;
+fn bpl() {
+    use super::opcodes::BPL;
 
     assert!(matches!(
-        get_instruction(&[LDY_ABSOLUTE_X, 0xab, 0xcd]).unwrap(),
+        get_instruction(&[BPL, 0x0b]).unwrap(),
         (
-            Instruction::Ld {
-                destination: Register::Y,
-                addressing_mode: LdAddressingMode::LdAddressAddressingMode {
-                    mode: LdAddressAddressingMode::AbsoluteX {
-                        mode: AM::AbsoluteX { address: 0xcdab },
-                    },
-                },
+            Instruction::Branch {
+                addressing_mode: AM::Relative { offset: 0x0b },
+                flag: Flag::Negative,
+                branch_if: false,
             },
-            0x8003,
+            0x8002
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDY_ABSOLUTE_X, 0x02, 0x01, 0x00]).unwrap();
+    cpu.load(&[BPL, 0x00, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0x01;
-    cpu.memory.load(0x0100, &[0x01, 0x02, 0x03, 0xf4]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_y, 0xf4);
-    assert!(cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
+    cpu.status.set(Flag::Negative, false);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x8002);
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDY_ABSOLUTE_X, 0x00, 0x02, 0x00])
-        .unwrap();
-    assert_eq!(cpu.register_y, 0x00);
-    assert!(!cpu.status.get(Flag::Negative));
-    assert!(cpu.status.get(Flag::Zero));
+    cpu.load(&[BPL, 0x08, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Negative, false);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x800a);
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[BPL, 0x08, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Negative, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x8002);
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[BPL, 0xf8, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Negative, false);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(
+        cpu.program_counter,
+        0x8002u16.wrapping_add(0xf8u8 as i8 as u16)
+    );
 }
 
 #[test]
-fn adc_absolute_y() {
-    use super::opcodes::{ADC_ABSOLUTE_Y, LDA_IMMEDIATE};
+fn bvc() {
+    use super::opcodes::BVC;
 
     assert!(matches!(
-        get_instruction(&[ADC_ABSOLUTE_Y, 0xab, 0xcd]).unwrap(),
+        get_instruction(&[BVC, 0x0b]).unwrap(),
+        (
+            Instruction::Branch {
+                addressing_mode: AM::Relative { offset: 0x0b },
+                flag: Flag::Overflow,
+                branch_if: false,
+            },
+            0x8002
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[BVC, 0x00, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Overflow, false);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x8002);
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[BVC, 0x08, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Overflow, false);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x800a);
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[BVC, 0x08, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Overflow, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x8002);
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[BVC, 0xf8, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Overflow, false);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(
+        cpu.program_counter,
+        0x8002u16.wrapping_add(0xf8u8 as i8 as u16)
+    );
+}
+
+#[test]
+fn bvs() {
+    use super::opcodes::BVS;
+
+    assert!(matches!(
+        get_instruction(&[BVS, 0x0b]).unwrap(),
+        (
+            Instruction::Branch {
+                addressing_mode: AM::Relative { offset: 0x0b },
+                flag: Flag::Overflow,
+                branch_if: true,
+            },
+            0x8002
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[BVS, 0x00, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Overflow, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x8002);
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[BVS, 0x08, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Overflow, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x800a);
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[BVS, 0x08, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Overflow, false);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x8002);
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[BVS, 0xf8, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Overflow, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(
+        cpu.program_counter,
+        0x8002u16.wrapping_add(0xf8u8 as i8 as u16)
+    );
+}
+
+#[test]
+fn adc_absolute() {
+    use super::opcodes::{ADC_ABSOLUTE, LDA_IMMEDIATE};
+
+    assert!(matches!(
+        get_instruction(&[ADC_ABSOLUTE, 0xab, 0xcd]).unwrap(),
         (
             Instruction::Adc {
                 addressing_mode: AdcAddressingMode::AdcAddressAddressingMode {
-                    mode: AdcAddressAddressingMode::AbsoluteY {
-                        mode: AM::AbsoluteY { address: 0xcdab },
+                    mode: AdcAddressAddressingMode::Absolute {
+                        mode: AM::Absolute { address: 0xcdab },
                     },
                 },
             },
-            0x8003
+            0x8003,
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[ADC_ABSOLUTE_Y, 0x02, 0x01, 0x00]).unwrap();
+    cpu.load(&[ADC_ABSOLUTE, 0x03, 0x01, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_y = 0x01;
-    cpu.memory.load(0x0100, &[0x01, 0x02, 0x03, 0xf0]).unwrap();
-    cpu.run().unwrap();
+    cpu.bus.load(0x0100, &[0x01, 0x02, 0x03, 0xf0]).unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_a, 0xf0);
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
@@ -2628,13 +2890,12 @@ fn adc_absolute_y() {
     assert!(!cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDA_IMMEDIATE, 0x71, ADC_ABSOLUTE_Y, 0x01, 0x01, 0x00])
+    cpu.load(&[LDA_IMMEDIATE, 0x71, ADC_ABSOLUTE, 0x03, 0x01, 0x00])
         .unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_y = 0x02;
-    cpu.memory.load(0x0100, &[0x01, 0x02, 0x03, 0x72]).unwrap();
-    cpu.run().unwrap();
+    cpu.bus.load(0x0100, &[0x01, 0x02, 0x03, 0x72]).unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_a, u8::wrapping_add(0x71, 0x72));
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
@@ -2642,13 +2903,12 @@ fn adc_absolute_y() {
     assert!(!cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDA_IMMEDIATE, 0x80, ADC_ABSOLUTE_Y, 0x02, 0x01, 0x00])
+    cpu.load(&[LDA_IMMEDIATE, 0x80, ADC_ABSOLUTE, 0x02, 0x01, 0x00])
         .unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_y = 0x00;
-    cpu.memory.load(0x0100, &[0x01, 0x02, 0x80, 0x04]).unwrap();
-    cpu.run().unwrap();
+    cpu.bus.load(0x0100, &[0x01, 0x02, 0x80, 0x04]).unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_a, 0x00);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
@@ -2657,16 +2917,16 @@ fn adc_absolute_y() {
 }
 
 #[test]
-fn and_absolute_y() {
-    use super::opcodes::{AND_ABSOLUTE_Y, LDA_IMMEDIATE};
+fn and_absolute() {
+    use super::opcodes::{AND_ABSOLUTE, LDA_IMMEDIATE};
 
     assert!(matches!(
-        get_instruction(&[AND_ABSOLUTE_Y, 0xab, 0xcd]).unwrap(),
+        get_instruction(&[AND_ABSOLUTE, 0xab, 0xcd]).unwrap(),
         (
             Instruction::And {
                 addressing_mode: AndAddressingMode::AndAddressAddressingMode {
-                    mode: AndAddressAddressingMode::AbsoluteY {
-                        mode: AM::AbsoluteY { address: 0xcdab },
+                    mode: AndAddressAddressingMode::Absolute {
+                        mode: AM::Absolute { address: 0xcdab },
                     },
                 },
             },
@@ -2675,56 +2935,53 @@ fn and_absolute_y() {
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[AND_ABSOLUTE_Y, 0x01, 0x01, 0x00]).unwrap();
+    cpu.load(&[AND_ABSOLUTE, 0x02, 0x01, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_y = 0x01;
-    cpu.memory.load(0x1000, &[0x01, 0x02, 0xf0, 0x04]).unwrap();
-    cpu.run().unwrap();
+    cpu.bus.load(0x1000, &[0x01, 0x02, 0xf0, 0x04]).unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_a, 0b00000000);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDA_IMMEDIATE, 0b11010011, AND_ABSOLUTE_Y, 0x02, 0x01, 0x00])
+    cpu.load(&[LDA_IMMEDIATE, 0b11010011, AND_ABSOLUTE, 0x03, 0x01, 0x00])
         .unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_y = 0x01;
-    cpu.memory
+    cpu.bus
         .load(0x0100, &[0x01, 0x02, 0x03, 0b10110001])
         .unwrap();
-    cpu.run().unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_a, 0b10010001);
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0b00110111, AND_ABSOLUTE_Y, 0x02, 0x01, 0x00])
+    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0b00110111, AND_ABSOLUTE, 0x02, 0x01, 0x00])
         .unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_y = 0x00;
-    cpu.memory
+    cpu.bus
         .load(0x0100, &[0x01, 0x02, 0b00110111, 0x04])
         .unwrap();
-    cpu.run().unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_a, 0b00110111);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
 }
 
 #[test]
-fn cmp_absolute_y() {
-    use super::opcodes::CMP_ABSOLUTE_Y;
+fn eor_absolute() {
+    use super::opcodes::{EOR_ABSOLUTE, LDA_IMMEDIATE};
 
     assert!(matches!(
-        get_instruction(&[CMP_ABSOLUTE_Y, 0xab, 0xcd]).unwrap(),
+        get_instruction(&[EOR_ABSOLUTE, 0xab, 0xcd]).unwrap(),
         (
-            Instruction::Cmp {
-                addressing_mode: CmpAddressingMode::CmpAddressAddressingMode {
-                    mode: CmpAddressAddressingMode::AbsoluteY {
-                        mode: AM::AbsoluteY { address: 0xcdab }
+            Instruction::Eor {
+                addressing_mode: EorAddressingMode::EorAddressAddressingMode {
+                    mode: EorAddressAddressingMode::Absolute {
+                        mode: AM::Absolute { address: 0xcdab }
                     }
                 }
             },
@@ -2733,335 +2990,366 @@ fn cmp_absolute_y() {
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[CMP_ABSOLUTE_Y, 0x02, 0x10]).unwrap();
+    cpu.load(&[LDA_IMMEDIATE, 0b1101_0011, EOR_ABSOLUTE, 0x10, 0x02, 0x00])
+        .unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_y = 1;
-    cpu.memory.load(0x1000, &[0x01, 0x02, 0x03, 123]).unwrap();
-    cpu.register_a = 127;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 127);
+    cpu.bus.write(0x0210, 0b1011_0001);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0110_0010);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Carry));
-
-    let mut cpu = Cpu::new();
-    cpu.reset().unwrap();
-    cpu.load(&[CMP_ABSOLUTE_Y, 0x01, 0x10]).unwrap();
-    cpu.program_counter = 0x8000;
-    cpu.register_y = 0x00;
-    cpu.memory.load(0x1000, &[0x01, 200, 0x03, 0x04]).unwrap();
-    cpu.register_a = 127;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 127);
-    assert!(cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
-    assert!(!cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0b0101_1010, EOR_ABSOLUTE, 0x10, 0x02, 0x00])
+        .unwrap();
     cpu.reset().unwrap();
-    cpu.load(&[CMP_ABSOLUTE_Y, 0x00, 0x10]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_y = 0x02;
-    cpu.memory.load(0x1000, &[0x01, 0x02, 200, 0x04]).unwrap();
-    cpu.register_a = 200;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 200);
+    cpu.bus.write(0x0210, 0b0101_1010);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0000_0000);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Carry));
 }
 
 #[test]
-fn lda_absolute_y() {
-    use super::opcodes::LDA_ABSOLUTE_Y;
+fn ora_absolute() {
+    use super::opcodes::{LDA_IMMEDIATE, ORA_ABSOLUTE};
 
     assert!(matches!(
-        get_instruction(&[LDA_ABSOLUTE_Y, 0xab, 0xcd]).unwrap(),
+        get_instruction(&[ORA_ABSOLUTE, 0xab, 0xcd]).unwrap(),
         (
-            Instruction::Ld {
-                destination: Register::A,
-                addressing_mode: LdAddressingMode::LdAddressAddressingMode {
-                    mode: LdAddressAddressingMode::AbsoluteY {
-                        mode: AM::AbsoluteY { address: 0xcdab },
-                    },
-                },
+            Instruction::Ora {
+                addressing_mode: OraAddressingMode::OraAddressAddressingMode {
+                    mode: OraAddressAddressingMode::Absolute {
+                        mode: AM::Absolute { address: 0xcdab }
+                    }
+                }
             },
-            0x8003,
+            0x8003
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDA_ABSOLUTE_Y, 0x02, 0x01, 0x00]).unwrap();
+    cpu.load(&[LDA_IMMEDIATE, 0b1101_0011, ORA_ABSOLUTE, 0x10, 0x02, 0x00])
+        .unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_y = 0x01;
-    cpu.memory.load(0x0100, &[0x01, 0x02, 0x03, 0xf4]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 0xf4);
+    cpu.bus.write(0x0210, 0b1011_0001);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b1111_0011);
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDA_ABSOLUTE_Y, 0x00, 0x02, 0x00])
+    cpu.load(&[LDA_IMMEDIATE, 0b0000_0000, ORA_ABSOLUTE, 0x10, 0x02, 0x00])
         .unwrap();
-    assert_eq!(cpu.register_a, 0x00);
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.bus.write(0x0210, 0b0000_0000);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0000_0000);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
 }
 
 #[test]
-fn ldx_absolute_y() {
-    use super::opcodes::LDX_ABSOLUTE_Y;
+fn asl_absolute() {
+    use super::opcodes::ASL_ABSOLUTE;
 
     assert!(matches!(
-        get_instruction(&[LDX_ABSOLUTE_Y, 0xab, 0xcd]).unwrap(),
+        get_instruction(&[ASL_ABSOLUTE, 0xab, 0xcd]).unwrap(),
         (
-            Instruction::Ld {
-                destination: Register::X,
-                addressing_mode: LdAddressingMode::LdAddressAddressingMode {
-                    mode: LdAddressAddressingMode::AbsoluteY {
-                        mode: AM::AbsoluteY { address: 0xcdab },
+            Instruction::Asl {
+                addressing_mode: AslAddressingMode::AslAddressAddressingMode {
+                    mode: AslAddressAddressingMode::Absolute {
+                        mode: AM::Absolute { address: 0xcdab },
                     },
                 },
             },
-            0x8003,
+            0x8003
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDX_ABSOLUTE_Y, 0x02, 0x01, 0x00]).unwrap();
+    cpu.load(&[ASL_ABSOLUTE, 0x02, 0x01, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_y = 0x01;
-    cpu.memory.load(0x0100, &[0x01, 0x02, 0x03, 0xf4]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_x, 0xf4);
-    assert!(cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
+    cpu.bus
+        .load(0x0100, &[0x01, 0x02, 0b10000000, 0x04])
+        .unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x0102), 0b00000000);
+    assert!(cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Carry));
+    assert!(!cpu.status.get(Flag::Negative));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDX_ABSOLUTE_Y, 0x00, 0x02, 0x00])
+    cpu.load(&[ASL_ABSOLUTE, 0x03, 0x01, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.bus
+        .load(0x0100, &[0x01, 0x02, 0x03, 0b0101_0101])
         .unwrap();
-    assert_eq!(cpu.register_x, 0x00);
-    assert!(!cpu.status.get(Flag::Negative));
-    assert!(cpu.status.get(Flag::Zero))
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x0103), 0b1010_1010);
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Carry));
 }
 
 #[test]
-fn adc_indirect_x() {
-    use super::opcodes::{ADC_INDIRECT_X, LDA_IMMEDIATE};
+fn bit_absolute() {
+    use super::opcodes::BIT_ABSOLUTE;
 
     assert!(matches!(
-        get_instruction(&[ADC_INDIRECT_X, 0xab]).unwrap(),
+        get_instruction(&[BIT_ABSOLUTE, 0xab, 0xcd]).unwrap(),
         (
-            Instruction::Adc {
-                addressing_mode: AdcAddressingMode::AdcAddressAddressingMode {
-                    mode: AdcAddressAddressingMode::IndirectX {
-                        mode: AM::IndirectX { address: 0xab },
-                    },
-                },
+            Instruction::Bit {
+                addressing_mode: BitAddressingMode::Absolute {
+                    mode: AM::Absolute { address: 0xcdab }
+                }
             },
-            0x8002
+            0x8003
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[ADC_INDIRECT_X, 0x01, 0x00]).unwrap();
+    cpu.load(&[BIT_ABSOLUTE, 0x02, 0x10, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0x01;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
-    cpu.memory.load(0x0403, &[0xf0]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 0xf0);
+    cpu.bus
+        .load(0x1000, &[0x01, 0x02, 0b11000000, 0x04])
+        .unwrap();
+    cpu.register_a = 0b0011_1111;
+    cpu.run_until_brk().unwrap();
+    assert!(cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Overflow));
     assert!(cpu.status.get(Flag::Negative));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[BIT_ABSOLUTE, 0x03, 0x10, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.bus
+        .load(0x1000, &[0x01, 0x02, 0x03, 0b0101_0101])
+        .unwrap();
+    cpu.register_a = 0b0001_0101;
+    cpu.run_until_brk().unwrap();
     assert!(!cpu.status.get(Flag::Zero));
-    assert!(!cpu.status.get(Flag::Overflow));
-    assert!(!cpu.status.get(Flag::Carry));
+    assert!(cpu.status.get(Flag::Overflow));
+    assert!(!cpu.status.get(Flag::Negative));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDA_IMMEDIATE, 0x71, ADC_INDIRECT_X, 0x00, 0x00])
+    cpu.load(&[BIT_ABSOLUTE, 0x03, 0x10, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.bus
+        .load(0x1000, &[0x01, 0x02, 0x03, 0b1010_1010])
         .unwrap();
+    cpu.register_a = 0b0101_0101;
+    cpu.run_until_brk().unwrap();
+    assert!(cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Overflow));
+    assert!(cpu.status.get(Flag::Negative));
+}
+
+#[test]
+fn cmp_absolute() {
+    use super::opcodes::CMP_ABSOLUTE;
+
+    assert!(matches!(
+        get_instruction(&[CMP_ABSOLUTE, 0xab, 0xcd]).unwrap(),
+        (
+            Instruction::Cmp {
+                addressing_mode: CmpAddressingMode::CmpAddressAddressingMode {
+                    mode: CmpAddressAddressingMode::Absolute {
+                        mode: AM::Absolute { address: 0xcdab }
+                    }
+                }
+            },
+            0x8003
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[CMP_ABSOLUTE, 0x02, 0x10]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0x01;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
-    cpu.memory.load(0x0302, &[0x72]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, u8::wrapping_add(0x71, 0x72));
+    cpu.bus.load(0x1000, &[0x01, 0x02, 123, 0x04]).unwrap();
+    cpu.register_a = 127;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 127);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Carry));
+
+    let mut cpu = Cpu::new();
+    cpu.reset().unwrap();
+    cpu.load(&[CMP_ABSOLUTE, 0x01, 0x10]).unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.bus.load(0x1000, &[0x01, 200, 0x03, 0x04]).unwrap();
+    cpu.register_a = 127;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 127);
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Overflow));
     assert!(!cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDA_IMMEDIATE, 0x80, ADC_INDIRECT_X, 0x02, 0x00])
-        .unwrap();
     cpu.reset().unwrap();
+    cpu.load(&[CMP_ABSOLUTE, 0x00, 0x10]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0x00;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
-    cpu.memory.load(0x0403, &[0x80]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 0x00);
+    cpu.bus.load(0x1000, &[200, 0x02, 0x03, 0x04]).unwrap();
+    cpu.register_a = 200;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 200);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Overflow));
     assert!(cpu.status.get(Flag::Carry));
 }
 
 #[test]
-fn and_indirect_x() {
-    use super::opcodes::{AND_INDIRECT_X, LDA_IMMEDIATE};
+fn cpx_absolute() {
+    use super::opcodes::CPX_ABSOLUTE;
 
     assert!(matches!(
-        get_instruction(&[AND_INDIRECT_X, 0xab]).unwrap(),
+        get_instruction(&[CPX_ABSOLUTE, 0xab, 0xcd]).unwrap(),
         (
-            Instruction::And {
-                addressing_mode: AndAddressingMode::AndAddressAddressingMode {
-                    mode: AndAddressAddressingMode::IndirectX {
-                        mode: AM::IndirectX { address: 0xab },
-                    },
-                },
+            Instruction::Cpx {
+                addressing_mode: CpxAddressingMode::CpxAddressAddressingMode {
+                    mode: CpxAddressAddressingMode::Absolute {
+                        mode: AM::Absolute { address: 0xcdab }
+                    }
+                }
             },
-            0x8002
+            0x8003
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[AND_INDIRECT_X, 0x01, 0x00]).unwrap();
+    cpu.load(&[CPX_ABSOLUTE, 0x02, 0x10]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0xff;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
-    cpu.memory.load(0x0201, &[0xf0]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 0b00000000);
+    cpu.bus.load(0x1000, &[0x01, 0x02, 123, 0x04]).unwrap();
+    cpu.register_x = 127;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_x, 127);
     assert!(!cpu.status.get(Flag::Negative));
-    assert!(cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDA_IMMEDIATE, 0b11010011, AND_INDIRECT_X, 0x01, 0x00])
-        .unwrap();
     cpu.reset().unwrap();
+    cpu.load(&[CPX_ABSOLUTE, 0x01, 0x10]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0x01;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
-    cpu.memory.load(0x0403, &[0b10110001]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 0b10010001);
+    cpu.bus.load(0x1000, &[0x01, 200, 0x03, 0x04]).unwrap();
+    cpu.register_x = 127;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_x, 127);
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0b00110111, AND_INDIRECT_X, 0x02, 0x00])
-        .unwrap();
     cpu.reset().unwrap();
+    cpu.load(&[CPX_ABSOLUTE, 0x00, 0x10]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0x00;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
-    cpu.memory.load(0x0403, &[0b00110111]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 0b00110111);
+    cpu.bus.load(0x1000, &[200, 0x02, 0x03, 0x04]).unwrap();
+    cpu.register_x = 200;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_x, 200);
     assert!(!cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Carry));
 }
 
 #[test]
-fn cmp_indirect_x() {
-    use super::opcodes::CMP_INDIRECT_X;
+fn cpy_absolute() {
+    use super::opcodes::CPY_ABSOLUTE;
 
     assert!(matches!(
-        get_instruction(&[CMP_INDIRECT_X, 0xc1]).unwrap(),
+        get_instruction(&[CPY_ABSOLUTE, 0xab, 0xcd]).unwrap(),
         (
-            Instruction::Cmp {
-                addressing_mode: CmpAddressingMode::CmpAddressAddressingMode {
-                    mode: CmpAddressAddressingMode::IndirectX {
-                        mode: AM::IndirectX { address: 0xc1 }
+            Instruction::Cpy {
+                addressing_mode: CpyAddressingMode::CpyAddressAddressingMode {
+                    mode: CpyAddressAddressingMode::Absolute {
+                        mode: AM::Absolute { address: 0xcdab }
                     }
                 }
             },
-            0x8002
+            0x8003
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[CMP_INDIRECT_X, 0x01]).unwrap();
+    cpu.load(&[CPY_ABSOLUTE, 0x02, 0x10]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0xff;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
-    cpu.memory.write(0x0201, 123);
-    cpu.register_a = 127;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 127);
+    cpu.bus.load(0x1000, &[0x01, 0x02, 123, 0x04]).unwrap();
+    cpu.register_y = 127;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_y, 127);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
     assert!(cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
     cpu.reset().unwrap();
-    cpu.load(&[CMP_INDIRECT_X, 0x01]).unwrap();
+    cpu.load(&[CPY_ABSOLUTE, 0x01, 0x10]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0x01;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
-    cpu.memory.load(0x0403, &[200]).unwrap();
-    cpu.register_a = 127;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 127);
+    cpu.bus.load(0x1000, &[0x01, 200, 0x03, 0x04]).unwrap();
+    cpu.register_y = 127;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_y, 127);
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
     assert!(!cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
     cpu.reset().unwrap();
-    cpu.load(&[CMP_INDIRECT_X, 0x01]).unwrap();
+    cpu.load(&[CPY_ABSOLUTE, 0x00, 0x10]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0x00;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
-    cpu.memory.load(0x0302, &[200]).unwrap();
-    cpu.register_a = 200;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 200);
+    cpu.bus.load(0x1000, &[200, 0x02, 0x03, 0x04]).unwrap();
+    cpu.register_y = 200;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_y, 200);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
     assert!(cpu.status.get(Flag::Carry));
 }
 
 #[test]
-fn lda_indirect_x() {
-    use super::opcodes::LDA_INDIRECT_X;
+fn lda_absolute() {
+    use super::opcodes::LDA_ABSOLUTE;
 
     assert!(matches!(
-        get_instruction(&[LDA_INDIRECT_X, 0xab]).unwrap(),
+        get_instruction(&[LDA_ABSOLUTE, 0xab, 0xcd]).unwrap(),
         (
             Instruction::Ld {
                 destination: Register::A,
                 addressing_mode: LdAddressingMode::LdAddressAddressingMode {
-                    mode: LdAddressAddressingMode::IndirectX {
-                        mode: AM::IndirectX { address: 0xab },
+                    mode: LdAddressAddressingMode::Absolute {
+                        mode: AM::Absolute { address: 0xcdab },
                     },
                 },
             },
-            0x8002,
+            0x8003,
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDA_INDIRECT_X, 0x01, 0x00]).unwrap();
+    cpu.load(&[LDA_ABSOLUTE, 0x02, 0x01, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_x = 0x01;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
-    cpu.memory.load(0x0403, &[0xff]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 0xff);
+    cpu.bus.load(0x0100, &[0x01, 0x02, 0xf3, 0x04]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0xf3);
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDA_INDIRECT_X, 0x02, 0x00])
+    cpu.load_and_run_test(&[LDA_ABSOLUTE, 0x00, 0x02, 0x00])
         .unwrap();
     assert_eq!(cpu.register_a, 0x00);
     assert!(!cpu.status.get(Flag::Negative));
@@ -3069,279 +3357,4504 @@ fn lda_indirect_x() {
 }
 
 #[test]
-fn adc_indirect_y() {
-    use super::opcodes::{ADC_INDIRECT_Y, LDA_IMMEDIATE};
+fn operands_wrap_around_memory() {
+    use super::opcodes::{LDA_ABSOLUTE, LDA_IMMEDIATE};
 
+    let mut memory = Memory::new();
+    memory.load(0xfffe, &[LDA_ABSOLUTE, 0x34]).unwrap();
+    memory.write(0x0000, 0x12);
     assert!(matches!(
-        get_instruction(&[ADC_INDIRECT_Y, 0xab]).unwrap(),
+        Instruction::get_instruction(&memory, &0xfffe, &CpuConfig::default()).unwrap(),
         (
-            Instruction::Adc {
-                addressing_mode: AdcAddressingMode::AdcAddressAddressingMode {
-                    mode: AdcAddressAddressingMode::IndirectY {
-                        mode: AM::IndirectY { address: 0xab },
+            Instruction::Ld {
+                addressing_mode: LdAddressingMode::LdAddressAddressingMode {
+                    mode: LdAddressAddressingMode::Absolute {
+                        mode: AM::Absolute { address: 0x1234 },
                     },
                 },
+                ..
             },
-            0x8002
+            0x0001,
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[ADC_INDIRECT_Y, 0x01, 0x00]).unwrap();
-    cpu.reset().unwrap();
-    cpu.program_counter = 0x8000;
-    cpu.register_y = 0x01;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
-    cpu.memory.load(0x0303, &[0xf0]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 0xf0);
-    assert!(cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
-    assert!(!cpu.status.get(Flag::Overflow));
-    assert!(!cpu.status.get(Flag::Carry));
+    cpu.bus.write(0xffff, LDA_IMMEDIATE);
+    cpu.bus.write(0x0000, 0x42);
+    cpu.program_counter = 0xffff;
+    cpu.step().unwrap();
+    assert_eq!(cpu.register_a, 0x42);
+    assert_eq!(cpu.program_counter, 0x0001);
+}
+
+#[test]
+fn ldx_absolute() {
+    use super::opcodes::LDX_ABSOLUTE;
+
+    assert!(matches!(
+        get_instruction(&[LDX_ABSOLUTE, 0xab, 0xcd]).unwrap(),
+        (
+            Instruction::Ld {
+                destination: Register::X,
+                addressing_mode: LdAddressingMode::LdAddressAddressingMode {
+                    mode: LdAddressAddressingMode::Absolute {
+                        mode: AM::Absolute { address: 0xcdab },
+                    },
+                },
+            },
+            0x8003,
+        )
+    ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDA_IMMEDIATE, 0x71, ADC_INDIRECT_Y, 0x02, 0x00])
-        .unwrap();
+    cpu.load(&[LDX_ABSOLUTE, 0x02, 0x01, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_y = 0x05;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
-    cpu.memory.load(0x0408, &[0x72]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, u8::wrapping_add(0x71, 0x72));
+    cpu.bus.load(0x0100, &[0x01, 0x02, 0xf3, 0x04]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_x, 0xf3);
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Overflow));
-    assert!(!cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDA_IMMEDIATE, 0x80, ADC_INDIRECT_Y, 0x00, 0x00])
+    cpu.load_and_run_test(&[LDX_ABSOLUTE, 0x00, 0x02, 0x00])
         .unwrap();
-    cpu.reset().unwrap();
-    cpu.program_counter = 0x8000;
-    cpu.register_y = 0x00;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
-    cpu.memory.load(0x0201, &[0x80]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 0x00);
+    assert_eq!(cpu.register_x, 0x00);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Overflow));
-    assert!(cpu.status.get(Flag::Carry));
 }
 
 #[test]
-fn and_indirect_y() {
-    use super::opcodes::{AND_INDIRECT_Y, LDA_IMMEDIATE};
+fn ldy_absolute() {
+    use super::opcodes::LDY_ABSOLUTE;
 
     assert!(matches!(
-        get_instruction(&[AND_INDIRECT_Y, 0xab]).unwrap(),
+        get_instruction(&[LDY_ABSOLUTE, 0xab, 0xcd]).unwrap(),
         (
-            Instruction::And {
-                addressing_mode: AndAddressingMode::AndAddressAddressingMode {
-                    mode: AndAddressAddressingMode::IndirectY {
-                        mode: AM::IndirectY { address: 0xab },
+            Instruction::Ld {
+                destination: Register::Y,
+                addressing_mode: LdAddressingMode::LdAddressAddressingMode {
+                    mode: LdAddressAddressingMode::Absolute {
+                        mode: AM::Absolute { address: 0xcdab },
                     },
                 },
             },
-            0x8002
+            0x8003,
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[AND_INDIRECT_Y, 0x01, 0x00]).unwrap();
-    cpu.reset().unwrap();
-    cpu.program_counter = 0x8000;
-    cpu.register_y = 0x01;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
-    cpu.memory.load(0x0303, &[0xf0]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 0b00000000);
-    assert!(!cpu.status.get(Flag::Negative));
-    assert!(cpu.status.get(Flag::Zero));
-
-    let mut cpu = Cpu::new();
-    cpu.load(&[LDA_IMMEDIATE, 0b11010011, AND_INDIRECT_Y, 0x02, 0x00])
-        .unwrap();
+    cpu.load(&[LDY_ABSOLUTE, 0x02, 0x01, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_y = 0x05;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
-    cpu.memory.load(0x0408, &[0b10110001]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 0b10010001);
+    cpu.bus.load(0x0100, &[0x01, 0x02, 0xf3, 0x04]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_y, 0xf3);
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0b00110111, AND_INDIRECT_Y, 0x00, 0x00])
+    cpu.load_and_run_test(&[LDY_ABSOLUTE, 0x00, 0x02, 0x00])
         .unwrap();
-    cpu.reset().unwrap();
-    cpu.program_counter = 0x8000;
-    cpu.register_y = 0x00;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
-    cpu.memory.load(0x0201, &[0b00110111]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 0b00110111);
+    assert_eq!(cpu.register_y, 0x00);
     assert!(!cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Zero));
 }
 
 #[test]
-fn cmp_indirect_y() {
-    use super::opcodes::CMP_INDIRECT_Y;
+fn sta_absolute() {
+    use super::opcodes::STA_ABSOLUTE;
 
     assert!(matches!(
-        get_instruction(&[CMP_INDIRECT_Y, 0xc1]).unwrap(),
+        get_instruction(&[STA_ABSOLUTE, 0xab, 0xcd]).unwrap(),
         (
-            Instruction::Cmp {
-                addressing_mode: CmpAddressingMode::CmpAddressAddressingMode {
-                    mode: CmpAddressAddressingMode::IndirectY {
-                        mode: AM::IndirectY { address: 0xc1 }
-                    }
+            Instruction::St {
+                origin: Register::A,
+                addressing_mode: StAddressingMode::Absolute {
+                    mode: AM::Absolute { address: 0xcdab }
                 }
             },
-            0x8002
+            0x8003,
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[CMP_INDIRECT_Y, 0x01]).unwrap();
+    cpu.load(&[STA_ABSOLUTE, 0x02, 0x01, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_y = 0x01;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
-    cpu.memory.write(0x0303, 123);
-    cpu.register_a = 127;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 127);
-    assert!(!cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Carry));
+    cpu.register_a = 0x42;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x0102), 0x42);
+}
+
+#[test]
+fn stx_absolute() {
+    use super::opcodes::STX_ABSOLUTE;
+
+    assert!(matches!(
+        get_instruction(&[STX_ABSOLUTE, 0xab, 0xcd]).unwrap(),
+        (
+            Instruction::St {
+                origin: Register::X,
+                addressing_mode: StAddressingMode::Absolute {
+                    mode: AM::Absolute { address: 0xcdab }
+                }
+            },
+            0x8003,
+        )
+    ));
 
     let mut cpu = Cpu::new();
+    cpu.load(&[STX_ABSOLUTE, 0x02, 0x01, 0x00]).unwrap();
     cpu.reset().unwrap();
-    cpu.load(&[CMP_INDIRECT_Y, 0x02]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_y = 0x05;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
-    cpu.memory.load(0x0408, &[200]).unwrap();
-    cpu.register_a = 127;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 127);
-    assert!(cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
-    assert!(!cpu.status.get(Flag::Carry));
+    cpu.register_x = 0x42;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x0102), 0x42);
+}
+
+#[test]
+fn sty_absolute() {
+    use super::opcodes::STY_ABSOLUTE;
+
+    assert!(matches!(
+        get_instruction(&[STY_ABSOLUTE, 0xab, 0xcd]).unwrap(),
+        (
+            Instruction::St {
+                origin: Register::Y,
+                addressing_mode: StAddressingMode::Absolute {
+                    mode: AM::Absolute { address: 0xcdab }
+                }
+            },
+            0x8003,
+        )
+    ));
 
     let mut cpu = Cpu::new();
+    cpu.load(&[STY_ABSOLUTE, 0x02, 0x01, 0x00]).unwrap();
     cpu.reset().unwrap();
-    cpu.load(&[CMP_INDIRECT_Y, 0x00]).unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_y = 0x00;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
-    cpu.memory.load(0x0201, &[200]).unwrap();
-    cpu.register_a = 200;
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 200);
-    assert!(!cpu.status.get(Flag::Negative));
-    assert!(cpu.status.get(Flag::Zero));
-    assert!(cpu.status.get(Flag::Carry));
+    cpu.register_y = 0x42;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x0102), 0x42);
 }
 
 #[test]
-fn lda_indirect_y() {
-    use super::opcodes::LDA_INDIRECT_Y;
+fn adc_absolute_x() {
+    use super::opcodes::{ADC_ABSOLUTE_X, LDA_IMMEDIATE};
 
     assert!(matches!(
-        get_instruction(&[LDA_INDIRECT_Y, 0xab]).unwrap(),
+        get_instruction(&[ADC_ABSOLUTE_X, 0xab, 0xcd]).unwrap(),
         (
-            Instruction::Ld {
-                destination: Register::A,
-                addressing_mode: LdAddressingMode::LdAddressAddressingMode {
-                    mode: LdAddressAddressingMode::IndirectY {
-                        mode: AM::IndirectY { address: 0xab },
+            Instruction::Adc {
+                addressing_mode: AdcAddressingMode::AdcAddressAddressingMode {
+                    mode: AdcAddressAddressingMode::AbsoluteX {
+                        mode: AM::AbsoluteX { address: 0xcdab },
                     },
                 },
             },
-            0x8002,
+            0x8003
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load(&[LDA_INDIRECT_Y, 0x01, 0x00]).unwrap();
+    cpu.load(&[ADC_ABSOLUTE_X, 0x02, 0x01, 0x00]).unwrap();
     cpu.reset().unwrap();
     cpu.program_counter = 0x8000;
-    cpu.register_y = 0x01;
-    cpu.memory.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
-    cpu.memory.load(0x0303, &[0xff]).unwrap();
-    cpu.run().unwrap();
-    assert_eq!(cpu.register_a, 0xff);
+    cpu.register_x = 0x01;
+    cpu.bus.load(0x0100, &[0x01, 0x02, 0x03, 0xf0]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0xf0);
     assert!(cpu.status.get(Flag::Negative));
     assert!(!cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Overflow));
+    assert!(!cpu.status.get(Flag::Carry));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDA_INDIRECT_Y, 0x02, 0x00])
+    cpu.load(&[LDA_IMMEDIATE, 0x71, ADC_ABSOLUTE_X, 0x01, 0x01, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x02;
+    cpu.bus.load(0x0100, &[0x01, 0x02, 0x03, 0x72]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, u8::wrapping_add(0x71, 0x72));
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Overflow));
+    assert!(!cpu.status.get(Flag::Carry));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0x80, ADC_ABSOLUTE_X, 0x02, 0x01, 0x00])
         .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x00;
+    cpu.bus.load(0x0100, &[0x01, 0x02, 0x80, 0x04]).unwrap();
+    cpu.run_until_brk().unwrap();
     assert_eq!(cpu.register_a, 0x00);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Overflow));
+    assert!(cpu.status.get(Flag::Carry));
 }
 
 #[test]
-fn tax() {
-    use super::opcodes::{LDA_IMMEDIATE, LDX_IMMEDIATE, TAX};
+fn sbc_absolute_x() {
+    use super::opcodes::{LDA_IMMEDIATE, SBC_ABSOLUTE_X};
 
     assert!(matches!(
-        get_instruction(&[TAX, 0x00]).unwrap(),
+        get_instruction(&[SBC_ABSOLUTE_X, 0xab, 0xcd]).unwrap(),
         (
-            Instruction::Trr {
-                origin: Register::A,
-                destination: Register::X,
+            Instruction::Sbc {
+                addressing_mode: SbcAddressingMode::SbcAddressAddressingMode {
+                    mode: SbcAddressAddressingMode::AbsoluteX {
+                        mode: AM::AbsoluteX { address: 0xcdab }
+                    }
+                }
             },
-            0x8001
+            0x8003
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0xf0, TAX, 0x00])
-        .unwrap();
-    assert_eq!(cpu.register_x, 0xf0);
-    assert!(cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
-
-    let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDX_IMMEDIATE, 0x50, TAX, 0x00])
+    cpu.load(&[LDA_IMMEDIATE, 0x10, SBC_ABSOLUTE_X, 0x02, 0x01, 0x00])
         .unwrap();
-    assert_eq!(cpu.register_x, 0x00);
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Carry, true);
+    cpu.register_x = 0x01;
+    cpu.bus.load(0x0100, &[0x01, 0x02, 0x03, 0x10]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0x00);
     assert!(!cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Overflow));
+    assert!(cpu.status.get(Flag::Carry));
 }
 
 #[test]
-fn inx() {
-    use super::opcodes::{INX, LDX_IMMEDIATE};
+fn and_absolute_x() {
+    use super::opcodes::{AND_ABSOLUTE_X, LDA_IMMEDIATE};
 
     assert!(matches!(
-        get_instruction(&[INX, 0x00]).unwrap(),
+        get_instruction(&[AND_ABSOLUTE_X, 0xab, 0xcd]).unwrap(),
         (
-            Instruction::In {
-                destination: Register::X
+            Instruction::And {
+                addressing_mode: AndAddressingMode::AndAddressAddressingMode {
+                    mode: AndAddressAddressingMode::AbsoluteX {
+                        mode: AM::AbsoluteX { address: 0xcdab },
+                    },
+                },
             },
-            0x8001
+            0x8003
         )
     ));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDX_IMMEDIATE, 0xf1, INX, 0x00])
-        .unwrap();
-    assert!(cpu.status.get(Flag::Negative));
-    assert!(!cpu.status.get(Flag::Zero));
+    cpu.load(&[AND_ABSOLUTE_X, 0x01, 0x01, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x01;
+    cpu.bus.load(0x1000, &[0x01, 0x02, 0xf0, 0x04]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b00000000);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
 
     let mut cpu = Cpu::new();
-    cpu.load_and_run_test(&[LDX_IMMEDIATE, 0xff, INX, 0x00])
+    cpu.load(&[LDA_IMMEDIATE, 0b11010011, AND_ABSOLUTE_X, 0x02, 0x01, 0x00])
         .unwrap();
-    assert_eq!(cpu.register_x, 0x00);
-    assert!(!cpu.status.get(Flag::Negative));
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x01;
+    cpu.bus
+        .load(0x0100, &[0x01, 0x02, 0x03, 0b10110001])
+        .unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b10010001);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0b00110111, AND_ABSOLUTE_X, 0x02, 0x01, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x00;
+    cpu.bus
+        .load(0x0100, &[0x01, 0x02, 0b00110111, 0x04])
+        .unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b00110111);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn eor_absolute_x() {
+    use super::opcodes::{EOR_ABSOLUTE_X, LDA_IMMEDIATE};
+
+    assert!(matches!(
+        get_instruction(&[EOR_ABSOLUTE_X, 0xab, 0xcd]).unwrap(),
+        (
+            Instruction::Eor {
+                addressing_mode: EorAddressingMode::EorAddressAddressingMode {
+                    mode: EorAddressAddressingMode::AbsoluteX {
+                        mode: AM::AbsoluteX { address: 0xcdab }
+                    }
+                }
+            },
+            0x8003
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0b1101_0011, EOR_ABSOLUTE_X, 0x00, 0x02, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x10;
+    cpu.bus.write(0x0210, 0b1011_0001);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0110_0010);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0b0101_1010, EOR_ABSOLUTE_X, 0x00, 0x02, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x10;
+    cpu.bus.write(0x0210, 0b0101_1010);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0000_0000);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn ora_absolute_x() {
+    use super::opcodes::{LDA_IMMEDIATE, ORA_ABSOLUTE_X};
+
+    assert!(matches!(
+        get_instruction(&[ORA_ABSOLUTE_X, 0xab, 0xcd]).unwrap(),
+        (
+            Instruction::Ora {
+                addressing_mode: OraAddressingMode::OraAddressAddressingMode {
+                    mode: OraAddressAddressingMode::AbsoluteX {
+                        mode: AM::AbsoluteX { address: 0xcdab }
+                    }
+                }
+            },
+            0x8003
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0b1101_0011, ORA_ABSOLUTE_X, 0x00, 0x02, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x10;
+    cpu.bus.write(0x0210, 0b1011_0001);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b1111_0011);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0b0000_0000, ORA_ABSOLUTE_X, 0x00, 0x02, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x10;
+    cpu.bus.write(0x0210, 0b0000_0000);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0000_0000);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn asl_absolute_x() {
+    use super::opcodes::ASL_ABSOLUTE_X;
+
+    assert!(matches!(
+        get_instruction(&[ASL_ABSOLUTE_X, 0xab, 0xcd]).unwrap(),
+        (
+            Instruction::Asl {
+                addressing_mode: AslAddressingMode::AslAddressAddressingMode {
+                    mode: AslAddressAddressingMode::AbsoluteX {
+                        mode: AM::AbsoluteX { address: 0xcdab },
+                    },
+                },
+            },
+            0x8003
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[ASL_ABSOLUTE_X, 0x01, 0x01, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x01;
+    cpu.bus
+        .load(0x0100, &[0x01, 0x02, 0b10000000, 0x04])
+        .unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x0102), 0b00000000);
+    assert!(cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Carry));
+    assert!(!cpu.status.get(Flag::Negative));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[ASL_ABSOLUTE_X, 0x03, 0x01, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.register_x = 0x00;
+    cpu.program_counter = 0x8000;
+    cpu.bus
+        .load(0x0100, &[0x01, 0x02, 0x03, 0b0101_0101])
+        .unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x0103), 0b1010_1010);
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Carry));
+}
+
+#[test]
+fn lsr_absolute_x() {
+    use super::opcodes::LSR_ABSOLUTE_X;
+
+    assert!(matches!(
+        get_instruction(&[LSR_ABSOLUTE_X, 0xab, 0xcd]).unwrap(),
+        (
+            Instruction::Lsr {
+                addressing_mode: LsrAddressingMode::LsrAddressAddressingMode {
+                    mode: LsrAddressAddressingMode::AbsoluteX {
+                        mode: AM::AbsoluteX { address: 0xcdab }
+                    }
+                }
+            },
+            0x8003
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LSR_ABSOLUTE_X, 0x00, 0x02, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x10;
+    cpu.bus.write(0x0210, 0b1000_0001);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x0210), 0b0100_0000);
+    assert!(cpu.status.get(Flag::Carry));
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LSR_ABSOLUTE_X, 0x00, 0x02, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x10;
+    cpu.bus.write(0x0210, 0b0000_0001);
+    cpu.status.set(Flag::Carry, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x0210), 0b0000_0000);
+    assert!(cpu.status.get(Flag::Carry));
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn rol_absolute_x() {
+    use super::opcodes::ROL_ABSOLUTE_X;
+
+    assert!(matches!(
+        get_instruction(&[ROL_ABSOLUTE_X, 0xab, 0xcd]).unwrap(),
+        (
+            Instruction::Rol {
+                addressing_mode: RolAddressingMode::RolAddressAddressingMode {
+                    mode: RolAddressAddressingMode::AbsoluteX {
+                        mode: AM::AbsoluteX { address: 0xcdab }
+                    }
+                }
+            },
+            0x8003
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[ROL_ABSOLUTE_X, 0x00, 0x02, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x10;
+    cpu.bus.write(0x0210, 0b1000_0000);
+    cpu.status.set(Flag::Carry, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x0210), 0b0000_0001);
+    assert!(cpu.status.get(Flag::Carry));
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[ROL_ABSOLUTE_X, 0x00, 0x02, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x10;
+    cpu.bus.write(0x0210, 0b0100_0000);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x0210), 0b1000_0000);
+    assert!(!cpu.status.get(Flag::Carry));
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[ROL_ABSOLUTE_X, 0x00, 0x02, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x10;
+    cpu.bus.write(0x0210, 0b1000_0000);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x0210), 0b0000_0000);
+    assert!(cpu.status.get(Flag::Carry));
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn ror_absolute_x() {
+    use super::opcodes::ROR_ABSOLUTE_X;
+
+    assert!(matches!(
+        get_instruction(&[ROR_ABSOLUTE_X, 0xab, 0xcd]).unwrap(),
+        (
+            Instruction::Ror {
+                addressing_mode: RorAddressingMode::RorAddressAddressingMode {
+                    mode: RorAddressAddressingMode::AbsoluteX {
+                        mode: AM::AbsoluteX { address: 0xcdab }
+                    }
+                }
+            },
+            0x8003
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[ROR_ABSOLUTE_X, 0x00, 0x02, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x10;
+    cpu.bus.write(0x0210, 0b0000_0001);
+    cpu.status.set(Flag::Carry, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x0210), 0b1000_0000);
+    assert!(cpu.status.get(Flag::Carry));
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[ROR_ABSOLUTE_X, 0x00, 0x02, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x10;
+    cpu.bus.write(0x0210, 0b0000_0010);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x0210), 0b0000_0001);
+    assert!(!cpu.status.get(Flag::Carry));
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[ROR_ABSOLUTE_X, 0x00, 0x02, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x10;
+    cpu.bus.write(0x0210, 0b0000_0001);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x0210), 0b0000_0000);
+    assert!(cpu.status.get(Flag::Carry));
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn cmp_absolute_x() {
+    use super::opcodes::CMP_ABSOLUTE_X;
+
+    assert!(matches!(
+        get_instruction(&[CMP_ABSOLUTE_X, 0xab, 0xcd]).unwrap(),
+        (
+            Instruction::Cmp {
+                addressing_mode: CmpAddressingMode::CmpAddressAddressingMode {
+                    mode: CmpAddressAddressingMode::AbsoluteX {
+                        mode: AM::AbsoluteX { address: 0xcdab }
+                    }
+                }
+            },
+            0x8003
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[CMP_ABSOLUTE_X, 0x02, 0x10]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 1;
+    cpu.bus.load(0x1000, &[0x01, 0x02, 0x03, 123]).unwrap();
+    cpu.register_a = 127;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 127);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Carry));
+
+    let mut cpu = Cpu::new();
+    cpu.reset().unwrap();
+    cpu.load(&[CMP_ABSOLUTE_X, 0x01, 0x10]).unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x00;
+    cpu.bus.load(0x1000, &[0x01, 200, 0x03, 0x04]).unwrap();
+    cpu.register_a = 127;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 127);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Carry));
+
+    let mut cpu = Cpu::new();
+    cpu.reset().unwrap();
+    cpu.load(&[CMP_ABSOLUTE_X, 0x00, 0x10]).unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x02;
+    cpu.bus.load(0x1000, &[0x01, 0x02, 200, 0x04]).unwrap();
+    cpu.register_a = 200;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 200);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Carry));
+}
+
+#[test]
+fn inc_absolute_x() {
+    use super::opcodes::INC_ABSOLUTE_X;
+
+    assert!(matches!(
+        get_instruction(&[INC_ABSOLUTE_X, 0xab, 0xcd]).unwrap(),
+        (
+            Instruction::Inc {
+                addressing_mode: IncAddressingMode::AbsoluteX {
+                    mode: AM::AbsoluteX { address: 0xcdab }
+                }
+            },
+            0x8003
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[INC_ABSOLUTE_X, 0x00, 0x02, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x10;
+    cpu.bus.write(0x0210, 0x41);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x0210), 0x42);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn lda_absolute_x() {
+    use super::opcodes::LDA_ABSOLUTE_X;
+
+    assert!(matches!(
+        get_instruction(&[LDA_ABSOLUTE_X, 0xab, 0xcd]).unwrap(),
+        (
+            Instruction::Ld {
+                destination: Register::A,
+                addressing_mode: LdAddressingMode::LdAddressAddressingMode {
+                    mode: LdAddressAddressingMode::AbsoluteX {
+                        mode: AM::AbsoluteX { address: 0xcdab },
+                    },
+                },
+            },
+            0x8003,
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_ABSOLUTE_X, 0x02, 0x01, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x01;
+    cpu.bus.load(0x0100, &[0x01, 0x02, 0x03, 0xf4]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0xf4);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDA_ABSOLUTE_X, 0x00, 0x02, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_a, 0x00);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn ldy_absolute_x() {
+    use super::opcodes::LDY_ABSOLUTE_X;
+
+    assert!(matches!(
+        get_instruction(&[LDY_ABSOLUTE_X, 0xab, 0xcd]).unwrap(),
+        (
+            Instruction::Ld {
+                destination: Register::Y,
+                addressing_mode: LdAddressingMode::LdAddressAddressingMode {
+                    mode: LdAddressAddressingMode::AbsoluteX {
+                        mode: AM::AbsoluteX { address: 0xcdab },
+                    },
+                },
+            },
+            0x8003,
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDY_ABSOLUTE_X, 0x02, 0x01, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x01;
+    cpu.bus.load(0x0100, &[0x01, 0x02, 0x03, 0xf4]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_y, 0xf4);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDY_ABSOLUTE_X, 0x00, 0x02, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_y, 0x00);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn sta_absolute_x() {
+    use super::opcodes::STA_ABSOLUTE_X;
+
+    assert!(matches!(
+        get_instruction(&[STA_ABSOLUTE_X, 0xab, 0xcd]).unwrap(),
+        (
+            Instruction::St {
+                origin: Register::A,
+                addressing_mode: StAddressingMode::AbsoluteX {
+                    mode: AM::AbsoluteX { address: 0xcdab }
+                }
+            },
+            0x8003,
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[STA_ABSOLUTE_X, 0xff, 0x01, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_a = 0x42;
+    cpu.register_x = 0x02;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x0201), 0x42);
+}
+
+#[test]
+fn adc_absolute_y() {
+    use super::opcodes::{ADC_ABSOLUTE_Y, LDA_IMMEDIATE};
+
+    assert!(matches!(
+        get_instruction(&[ADC_ABSOLUTE_Y, 0xab, 0xcd]).unwrap(),
+        (
+            Instruction::Adc {
+                addressing_mode: AdcAddressingMode::AdcAddressAddressingMode {
+                    mode: AdcAddressAddressingMode::AbsoluteY {
+                        mode: AM::AbsoluteY { address: 0xcdab },
+                    },
+                },
+            },
+            0x8003
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[ADC_ABSOLUTE_Y, 0x02, 0x01, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x01;
+    cpu.bus.load(0x0100, &[0x01, 0x02, 0x03, 0xf0]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0xf0);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Overflow));
+    assert!(!cpu.status.get(Flag::Carry));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0x71, ADC_ABSOLUTE_Y, 0x01, 0x01, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x02;
+    cpu.bus.load(0x0100, &[0x01, 0x02, 0x03, 0x72]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, u8::wrapping_add(0x71, 0x72));
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Overflow));
+    assert!(!cpu.status.get(Flag::Carry));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0x80, ADC_ABSOLUTE_Y, 0x02, 0x01, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x00;
+    cpu.bus.load(0x0100, &[0x01, 0x02, 0x80, 0x04]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0x00);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Overflow));
+    assert!(cpu.status.get(Flag::Carry));
+}
+
+#[test]
+fn and_absolute_y() {
+    use super::opcodes::{AND_ABSOLUTE_Y, LDA_IMMEDIATE};
+
+    assert!(matches!(
+        get_instruction(&[AND_ABSOLUTE_Y, 0xab, 0xcd]).unwrap(),
+        (
+            Instruction::And {
+                addressing_mode: AndAddressingMode::AndAddressAddressingMode {
+                    mode: AndAddressAddressingMode::AbsoluteY {
+                        mode: AM::AbsoluteY { address: 0xcdab },
+                    },
+                },
+            },
+            0x8003
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[AND_ABSOLUTE_Y, 0x01, 0x01, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x01;
+    cpu.bus.load(0x1000, &[0x01, 0x02, 0xf0, 0x04]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b00000000);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0b11010011, AND_ABSOLUTE_Y, 0x02, 0x01, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x01;
+    cpu.bus
+        .load(0x0100, &[0x01, 0x02, 0x03, 0b10110001])
+        .unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b10010001);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0b00110111, AND_ABSOLUTE_Y, 0x02, 0x01, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x00;
+    cpu.bus
+        .load(0x0100, &[0x01, 0x02, 0b00110111, 0x04])
+        .unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b00110111);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn eor_absolute_y() {
+    use super::opcodes::{EOR_ABSOLUTE_Y, LDA_IMMEDIATE};
+
+    assert!(matches!(
+        get_instruction(&[EOR_ABSOLUTE_Y, 0xab, 0xcd]).unwrap(),
+        (
+            Instruction::Eor {
+                addressing_mode: EorAddressingMode::EorAddressAddressingMode {
+                    mode: EorAddressAddressingMode::AbsoluteY {
+                        mode: AM::AbsoluteY { address: 0xcdab }
+                    }
+                }
+            },
+            0x8003
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0b1101_0011, EOR_ABSOLUTE_Y, 0x00, 0x02, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x10;
+    cpu.bus.write(0x0210, 0b1011_0001);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0110_0010);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0b0101_1010, EOR_ABSOLUTE_Y, 0x00, 0x02, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x10;
+    cpu.bus.write(0x0210, 0b0101_1010);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0000_0000);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn ora_absolute_y() {
+    use super::opcodes::{LDA_IMMEDIATE, ORA_ABSOLUTE_Y};
+
+    assert!(matches!(
+        get_instruction(&[ORA_ABSOLUTE_Y, 0xab, 0xcd]).unwrap(),
+        (
+            Instruction::Ora {
+                addressing_mode: OraAddressingMode::OraAddressAddressingMode {
+                    mode: OraAddressAddressingMode::AbsoluteY {
+                        mode: AM::AbsoluteY { address: 0xcdab }
+                    }
+                }
+            },
+            0x8003
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0b1101_0011, ORA_ABSOLUTE_Y, 0x00, 0x02, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x10;
+    cpu.bus.write(0x0210, 0b1011_0001);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b1111_0011);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0b0000_0000, ORA_ABSOLUTE_Y, 0x00, 0x02, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x10;
+    cpu.bus.write(0x0210, 0b0000_0000);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0000_0000);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn cmp_absolute_y() {
+    use super::opcodes::CMP_ABSOLUTE_Y;
+
+    assert!(matches!(
+        get_instruction(&[CMP_ABSOLUTE_Y, 0xab, 0xcd]).unwrap(),
+        (
+            Instruction::Cmp {
+                addressing_mode: CmpAddressingMode::CmpAddressAddressingMode {
+                    mode: CmpAddressAddressingMode::AbsoluteY {
+                        mode: AM::AbsoluteY { address: 0xcdab }
+                    }
+                }
+            },
+            0x8003
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[CMP_ABSOLUTE_Y, 0x02, 0x10]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 1;
+    cpu.bus.load(0x1000, &[0x01, 0x02, 0x03, 123]).unwrap();
+    cpu.register_a = 127;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 127);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Carry));
+
+    let mut cpu = Cpu::new();
+    cpu.reset().unwrap();
+    cpu.load(&[CMP_ABSOLUTE_Y, 0x01, 0x10]).unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x00;
+    cpu.bus.load(0x1000, &[0x01, 200, 0x03, 0x04]).unwrap();
+    cpu.register_a = 127;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 127);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Carry));
+
+    let mut cpu = Cpu::new();
+    cpu.reset().unwrap();
+    cpu.load(&[CMP_ABSOLUTE_Y, 0x00, 0x10]).unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x02;
+    cpu.bus.load(0x1000, &[0x01, 0x02, 200, 0x04]).unwrap();
+    cpu.register_a = 200;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 200);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Carry));
+}
+
+#[test]
+fn lda_absolute_y() {
+    use super::opcodes::LDA_ABSOLUTE_Y;
+
+    assert!(matches!(
+        get_instruction(&[LDA_ABSOLUTE_Y, 0xab, 0xcd]).unwrap(),
+        (
+            Instruction::Ld {
+                destination: Register::A,
+                addressing_mode: LdAddressingMode::LdAddressAddressingMode {
+                    mode: LdAddressAddressingMode::AbsoluteY {
+                        mode: AM::AbsoluteY { address: 0xcdab },
+                    },
+                },
+            },
+            0x8003,
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_ABSOLUTE_Y, 0x02, 0x01, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x01;
+    cpu.bus.load(0x0100, &[0x01, 0x02, 0x03, 0xf4]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0xf4);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDA_ABSOLUTE_Y, 0x00, 0x02, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_a, 0x00);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn ldx_absolute_y() {
+    use super::opcodes::LDX_ABSOLUTE_Y;
+
+    assert!(matches!(
+        get_instruction(&[LDX_ABSOLUTE_Y, 0xab, 0xcd]).unwrap(),
+        (
+            Instruction::Ld {
+                destination: Register::X,
+                addressing_mode: LdAddressingMode::LdAddressAddressingMode {
+                    mode: LdAddressAddressingMode::AbsoluteY {
+                        mode: AM::AbsoluteY { address: 0xcdab },
+                    },
+                },
+            },
+            0x8003,
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDX_ABSOLUTE_Y, 0x02, 0x01, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x01;
+    cpu.bus.load(0x0100, &[0x01, 0x02, 0x03, 0xf4]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_x, 0xf4);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDX_ABSOLUTE_Y, 0x00, 0x02, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_x, 0x00);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero))
+}
+
+#[test]
+fn sta_absolute_y() {
+    use super::opcodes::STA_ABSOLUTE_Y;
+
+    assert!(matches!(
+        get_instruction(&[STA_ABSOLUTE_Y, 0xab, 0xcd]).unwrap(),
+        (
+            Instruction::St {
+                origin: Register::A,
+                addressing_mode: StAddressingMode::AbsoluteY {
+                    mode: AM::AbsoluteY { address: 0xcdab }
+                }
+            },
+            0x8003,
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[STA_ABSOLUTE_Y, 0xff, 0x01, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_a = 0x42;
+    cpu.register_y = 0x02;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x0201), 0x42);
+}
+
+#[test]
+fn adc_indirect_x() {
+    use super::opcodes::{ADC_INDIRECT_X, LDA_IMMEDIATE};
+
+    assert!(matches!(
+        get_instruction(&[ADC_INDIRECT_X, 0xab]).unwrap(),
+        (
+            Instruction::Adc {
+                addressing_mode: AdcAddressingMode::AdcAddressAddressingMode {
+                    mode: AdcAddressAddressingMode::IndirectX {
+                        mode: AM::IndirectX { address: 0xab },
+                    },
+                },
+            },
+            0x8002
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[ADC_INDIRECT_X, 0x01, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x01;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
+    cpu.bus.load(0x0403, &[0xf0]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0xf0);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Overflow));
+    assert!(!cpu.status.get(Flag::Carry));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0x71, ADC_INDIRECT_X, 0x00, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x01;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
+    cpu.bus.load(0x0302, &[0x72]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, u8::wrapping_add(0x71, 0x72));
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Overflow));
+    assert!(!cpu.status.get(Flag::Carry));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0x80, ADC_INDIRECT_X, 0x02, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x00;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
+    cpu.bus.load(0x0403, &[0x80]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0x00);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Overflow));
+    assert!(cpu.status.get(Flag::Carry));
+}
+
+#[test]
+fn and_indirect_x() {
+    use super::opcodes::{AND_INDIRECT_X, LDA_IMMEDIATE};
+
+    assert!(matches!(
+        get_instruction(&[AND_INDIRECT_X, 0xab]).unwrap(),
+        (
+            Instruction::And {
+                addressing_mode: AndAddressingMode::AndAddressAddressingMode {
+                    mode: AndAddressAddressingMode::IndirectX {
+                        mode: AM::IndirectX { address: 0xab },
+                    },
+                },
+            },
+            0x8002
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[AND_INDIRECT_X, 0x01, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0xff;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
+    cpu.bus.load(0x0201, &[0xf0]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b00000000);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0b11010011, AND_INDIRECT_X, 0x01, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x01;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
+    cpu.bus.load(0x0403, &[0b10110001]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b10010001);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0b00110111, AND_INDIRECT_X, 0x02, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x00;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
+    cpu.bus.load(0x0403, &[0b00110111]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b00110111);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn eor_indirect_x() {
+    use super::opcodes::{EOR_INDIRECT_X, LDA_IMMEDIATE};
+
+    assert!(matches!(
+        get_instruction(&[EOR_INDIRECT_X, 0xab]).unwrap(),
+        (
+            Instruction::Eor {
+                addressing_mode: EorAddressingMode::EorAddressAddressingMode {
+                    mode: EorAddressAddressingMode::IndirectX {
+                        mode: AM::IndirectX { address: 0xab }
+                    }
+                }
+            },
+            0x8002
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0b1101_0011, EOR_INDIRECT_X, 0x0f, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x01;
+    cpu.bus.write_u16(0x10, 0x0210);
+    cpu.bus.write(0x0210, 0b1011_0001);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0110_0010);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0b0101_1010, EOR_INDIRECT_X, 0x0f, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x01;
+    cpu.bus.write_u16(0x10, 0x0210);
+    cpu.bus.write(0x0210, 0b0101_1010);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0000_0000);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn ora_indirect_x() {
+    use super::opcodes::{LDA_IMMEDIATE, ORA_INDIRECT_X};
+
+    assert!(matches!(
+        get_instruction(&[ORA_INDIRECT_X, 0xab]).unwrap(),
+        (
+            Instruction::Ora {
+                addressing_mode: OraAddressingMode::OraAddressAddressingMode {
+                    mode: OraAddressAddressingMode::IndirectX {
+                        mode: AM::IndirectX { address: 0xab }
+                    }
+                }
+            },
+            0x8002
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0b1101_0011, ORA_INDIRECT_X, 0x0f, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x01;
+    cpu.bus.write_u16(0x10, 0x0210);
+    cpu.bus.write(0x0210, 0b1011_0001);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b1111_0011);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0b0000_0000, ORA_INDIRECT_X, 0x0f, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x01;
+    cpu.bus.write_u16(0x10, 0x0210);
+    cpu.bus.write(0x0210, 0b0000_0000);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0000_0000);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn cmp_indirect_x() {
+    use super::opcodes::CMP_INDIRECT_X;
+
+    assert!(matches!(
+        get_instruction(&[CMP_INDIRECT_X, 0xc1]).unwrap(),
+        (
+            Instruction::Cmp {
+                addressing_mode: CmpAddressingMode::CmpAddressAddressingMode {
+                    mode: CmpAddressAddressingMode::IndirectX {
+                        mode: AM::IndirectX { address: 0xc1 }
+                    }
+                }
+            },
+            0x8002
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[CMP_INDIRECT_X, 0x01]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0xff;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
+    cpu.bus.write(0x0201, 123);
+    cpu.register_a = 127;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 127);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Carry));
+
+    let mut cpu = Cpu::new();
+    cpu.reset().unwrap();
+    cpu.load(&[CMP_INDIRECT_X, 0x01]).unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x01;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
+    cpu.bus.load(0x0403, &[200]).unwrap();
+    cpu.register_a = 127;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 127);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Carry));
+
+    let mut cpu = Cpu::new();
+    cpu.reset().unwrap();
+    cpu.load(&[CMP_INDIRECT_X, 0x01]).unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x00;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
+    cpu.bus.load(0x0302, &[200]).unwrap();
+    cpu.register_a = 200;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 200);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Carry));
+}
+
+#[test]
+fn lda_indirect_x() {
+    use super::opcodes::LDA_INDIRECT_X;
+
+    assert!(matches!(
+        get_instruction(&[LDA_INDIRECT_X, 0xab]).unwrap(),
+        (
+            Instruction::Ld {
+                destination: Register::A,
+                addressing_mode: LdAddressingMode::LdAddressAddressingMode {
+                    mode: LdAddressAddressingMode::IndirectX {
+                        mode: AM::IndirectX { address: 0xab },
+                    },
+                },
+            },
+            0x8002,
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_INDIRECT_X, 0x01, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x01;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
+    cpu.bus.load(0x0403, &[0xff]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0xff);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDA_INDIRECT_X, 0x02, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_a, 0x00);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn sta_indirect_x() {
+    use super::opcodes::STA_INDIRECT_X;
+
+    assert!(matches!(
+        get_instruction(&[STA_INDIRECT_X, 0xab]).unwrap(),
+        (
+            Instruction::St {
+                origin: Register::A,
+                addressing_mode: StAddressingMode::IndirectX {
+                    mode: AM::IndirectX { address: 0xab }
+                }
+            },
+            0x8002,
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[STA_INDIRECT_X, 0x01, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_a = 0x42;
+    cpu.register_x = 0x01;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x0403), 0x42);
+}
+
+#[test]
+fn adc_indirect_y() {
+    use super::opcodes::{ADC_INDIRECT_Y, LDA_IMMEDIATE};
+
+    assert!(matches!(
+        get_instruction(&[ADC_INDIRECT_Y, 0xab]).unwrap(),
+        (
+            Instruction::Adc {
+                addressing_mode: AdcAddressingMode::AdcAddressAddressingMode {
+                    mode: AdcAddressAddressingMode::IndirectY {
+                        mode: AM::IndirectY { address: 0xab },
+                    },
+                },
+            },
+            0x8002
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[ADC_INDIRECT_Y, 0x01, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x01;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
+    cpu.bus.load(0x0303, &[0xf0]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0xf0);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Overflow));
+    assert!(!cpu.status.get(Flag::Carry));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0x71, ADC_INDIRECT_Y, 0x02, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x05;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
+    cpu.bus.load(0x0408, &[0x72]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, u8::wrapping_add(0x71, 0x72));
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Overflow));
+    assert!(!cpu.status.get(Flag::Carry));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0x80, ADC_INDIRECT_Y, 0x00, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x00;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
+    cpu.bus.load(0x0201, &[0x80]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0x00);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Overflow));
+    assert!(cpu.status.get(Flag::Carry));
+}
+
+#[test]
+fn sbc_indirect_y() {
+    use super::opcodes::{LDA_IMMEDIATE, SBC_INDIRECT_Y};
+
+    assert!(matches!(
+        get_instruction(&[SBC_INDIRECT_Y, 0xab]).unwrap(),
+        (
+            Instruction::Sbc {
+                addressing_mode: SbcAddressingMode::SbcAddressAddressingMode {
+                    mode: SbcAddressAddressingMode::IndirectY {
+                        mode: AM::IndirectY { address: 0xab }
+                    }
+                }
+            },
+            0x8002
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0x10, SBC_INDIRECT_Y, 0x01, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x01;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
+    cpu.bus.load(0x0303, &[0x0f]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0x00);
+    assert!(cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Carry));
+}
+
+#[test]
+fn and_indirect_y() {
+    use super::opcodes::{AND_INDIRECT_Y, LDA_IMMEDIATE};
+
+    assert!(matches!(
+        get_instruction(&[AND_INDIRECT_Y, 0xab]).unwrap(),
+        (
+            Instruction::And {
+                addressing_mode: AndAddressingMode::AndAddressAddressingMode {
+                    mode: AndAddressAddressingMode::IndirectY {
+                        mode: AM::IndirectY { address: 0xab },
+                    },
+                },
+            },
+            0x8002
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[AND_INDIRECT_Y, 0x01, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x01;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
+    cpu.bus.load(0x0303, &[0xf0]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b00000000);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0b11010011, AND_INDIRECT_Y, 0x02, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x05;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
+    cpu.bus.load(0x0408, &[0b10110001]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b10010001);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0b00110111, AND_INDIRECT_Y, 0x00, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x00;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
+    cpu.bus.load(0x0201, &[0b00110111]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b00110111);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn eor_indirect_y() {
+    use super::opcodes::{EOR_INDIRECT_Y, LDA_IMMEDIATE};
+
+    assert!(matches!(
+        get_instruction(&[EOR_INDIRECT_Y, 0xab]).unwrap(),
+        (
+            Instruction::Eor {
+                addressing_mode: EorAddressingMode::EorAddressAddressingMode {
+                    mode: EorAddressAddressingMode::IndirectY {
+                        mode: AM::IndirectY { address: 0xab }
+                    }
+                }
+            },
+            0x8002
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0b1101_0011, EOR_INDIRECT_Y, 0x10, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x10;
+    cpu.bus.write_u16(0x10, 0x0200);
+    cpu.bus.write(0x0210, 0b1011_0001);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0110_0010);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0b0101_1010, EOR_INDIRECT_Y, 0x10, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x10;
+    cpu.bus.write_u16(0x10, 0x0200);
+    cpu.bus.write(0x0210, 0b0101_1010);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0000_0000);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn ora_indirect_y() {
+    use super::opcodes::{LDA_IMMEDIATE, ORA_INDIRECT_Y};
+
+    assert!(matches!(
+        get_instruction(&[ORA_INDIRECT_Y, 0xab]).unwrap(),
+        (
+            Instruction::Ora {
+                addressing_mode: OraAddressingMode::OraAddressAddressingMode {
+                    mode: OraAddressAddressingMode::IndirectY {
+                        mode: AM::IndirectY { address: 0xab }
+                    }
+                }
+            },
+            0x8002
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0b1101_0011, ORA_INDIRECT_Y, 0x10, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x10;
+    cpu.bus.write_u16(0x10, 0x0200);
+    cpu.bus.write(0x0210, 0b1011_0001);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b1111_0011);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0b0000_0000, ORA_INDIRECT_Y, 0x10, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x10;
+    cpu.bus.write_u16(0x10, 0x0200);
+    cpu.bus.write(0x0210, 0b0000_0000);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0b0000_0000);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn cmp_indirect_y() {
+    use super::opcodes::CMP_INDIRECT_Y;
+
+    assert!(matches!(
+        get_instruction(&[CMP_INDIRECT_Y, 0xc1]).unwrap(),
+        (
+            Instruction::Cmp {
+                addressing_mode: CmpAddressingMode::CmpAddressAddressingMode {
+                    mode: CmpAddressAddressingMode::IndirectY {
+                        mode: AM::IndirectY { address: 0xc1 }
+                    }
+                }
+            },
+            0x8002
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[CMP_INDIRECT_Y, 0x01]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x01;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
+    cpu.bus.write(0x0303, 123);
+    cpu.register_a = 127;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 127);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Carry));
+
+    let mut cpu = Cpu::new();
+    cpu.reset().unwrap();
+    cpu.load(&[CMP_INDIRECT_Y, 0x02]).unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x05;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
+    cpu.bus.load(0x0408, &[200]).unwrap();
+    cpu.register_a = 127;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 127);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Carry));
+
+    let mut cpu = Cpu::new();
+    cpu.reset().unwrap();
+    cpu.load(&[CMP_INDIRECT_Y, 0x00]).unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x00;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
+    cpu.bus.load(0x0201, &[200]).unwrap();
+    cpu.register_a = 200;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 200);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Carry));
+}
+
+#[test]
+fn lda_indirect_y() {
+    use super::opcodes::LDA_INDIRECT_Y;
+
+    assert!(matches!(
+        get_instruction(&[LDA_INDIRECT_Y, 0xab]).unwrap(),
+        (
+            Instruction::Ld {
+                destination: Register::A,
+                addressing_mode: LdAddressingMode::LdAddressAddressingMode {
+                    mode: LdAddressAddressingMode::IndirectY {
+                        mode: AM::IndirectY { address: 0xab },
+                    },
+                },
+            },
+            0x8002,
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_INDIRECT_Y, 0x01, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x01;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
+    cpu.bus.load(0x0303, &[0xff]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0xff);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDA_INDIRECT_Y, 0x02, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_a, 0x00);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn sta_indirect_y() {
+    use super::opcodes::STA_INDIRECT_Y;
+
+    assert!(matches!(
+        get_instruction(&[STA_INDIRECT_Y, 0xab]).unwrap(),
+        (
+            Instruction::St {
+                origin: Register::A,
+                addressing_mode: StAddressingMode::IndirectY {
+                    mode: AM::IndirectY { address: 0xab }
+                }
+            },
+            0x8002,
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[STA_INDIRECT_Y, 0x01, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_a = 0x42;
+    cpu.register_y = 0x01;
+    cpu.bus.load(0x00, &[0x01, 0x02, 0x03, 0x04]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x0303), 0x42);
+}
+
+#[test]
+fn tax() {
+    use super::opcodes::{LDA_IMMEDIATE, LDX_IMMEDIATE, TAX};
+
+    assert!(matches!(
+        get_instruction(&[TAX, 0x00]).unwrap(),
+        (
+            Instruction::Trr {
+                origin: Register::A,
+                destination: Register::X,
+            },
+            0x8001
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0xf0, TAX, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_x, 0xf0);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDX_IMMEDIATE, 0x50, TAX, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_x, 0x00);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn tay() {
+    use super::opcodes::{LDA_IMMEDIATE, LDY_IMMEDIATE, TAY};
+
+    assert!(matches!(
+        get_instruction(&[TAY, 0x00]).unwrap(),
+        (
+            Instruction::Trr {
+                origin: Register::A,
+                destination: Register::Y,
+            },
+            0x8001
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0xf0, TAY, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_y, 0xf0);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDY_IMMEDIATE, 0x50, TAY, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_y, 0x00);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn txa() {
+    use super::opcodes::{LDA_IMMEDIATE, LDX_IMMEDIATE, TXA};
+
+    assert!(matches!(
+        get_instruction(&[TXA, 0x00]).unwrap(),
+        (
+            Instruction::Trr {
+                origin: Register::X,
+                destination: Register::A,
+            },
+            0x8001
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDX_IMMEDIATE, 0xf0, TXA, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_a, 0xf0);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0x50, TXA, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_a, 0x00);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn tya() {
+    use super::opcodes::{LDA_IMMEDIATE, LDY_IMMEDIATE, TYA};
+
+    assert!(matches!(
+        get_instruction(&[TYA, 0x00]).unwrap(),
+        (
+            Instruction::Trr {
+                origin: Register::Y,
+                destination: Register::A,
+            },
+            0x8001
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDY_IMMEDIATE, 0xf0, TYA, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_a, 0xf0);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0x50, TYA, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_a, 0x00);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn tsx() {
+    use super::opcodes::TSX;
+
+    assert!(matches!(
+        get_instruction(&[TSX, 0x00]).unwrap(),
+        (
+            Instruction::Trr {
+                origin: Register::StackPointer,
+                destination: Register::X,
+            },
+            0x8001
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[TSX, 0x00]).unwrap();
+    assert_eq!(cpu.register_x, 0xfd);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[TSX, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.stack_pointer = 0x00;
+    cpu.register_x = 0x42;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_x, 0x00);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn txs() {
+    use super::opcodes::{LDX_IMMEDIATE, PHA, TXS};
+
+    assert!(matches!(
+        get_instruction(&[TXS, 0x00]).unwrap(),
+        (
+            Instruction::Trr {
+                origin: Register::X,
+                destination: Register::StackPointer,
+            },
+            0x8001
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDX_IMMEDIATE, 0x80, TXS, PHA, 0x00])
+        .unwrap();
+    assert_eq!(cpu.stack_pointer, 0x7f);
+    assert_eq!(cpu.bus.read(0x0180), 0x00);
+
+    // TXS doesn't touch the flags.
+    let mut cpu = Cpu::new();
+    cpu.load(&[TXS, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x00;
+    cpu.status.set(Flag::Negative, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.stack_pointer, 0x00);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn inx() {
+    use super::opcodes::{INX, LDX_IMMEDIATE};
+
+    assert!(matches!(
+        get_instruction(&[INX, 0x00]).unwrap(),
+        (
+            Instruction::In {
+                destination: Register::X
+            },
+            0x8001
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDX_IMMEDIATE, 0xf1, INX, 0x00])
+        .unwrap();
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDX_IMMEDIATE, 0xff, INX, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_x, 0x00);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn pha() {
+    use super::opcodes::{LDA_IMMEDIATE, PHA};
+
+    assert!(matches!(
+        get_instruction(&[PHA, 0x00]).unwrap(),
+        (
+            Instruction::Push {
+                origin: Register::A
+            },
+            0x8001
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0xf1, PHA, LDA_IMMEDIATE, 0x02, PHA, 0x00])
+        .unwrap();
+    assert_eq!(cpu.stack_pointer, 0xfb);
+    assert_eq!(cpu.bus.read(0x01fd), 0xf1);
+    assert_eq!(cpu.bus.read(0x01fc), 0x02);
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[PHA, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.stack_pointer = 0x00;
+    cpu.register_a = 0x42;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.stack_pointer, 0xff);
+    assert_eq!(cpu.bus.read(0x0100), 0x42);
+}
+
+#[test]
+fn php() {
+    use super::opcodes::PHP;
+
+    assert!(matches!(
+        get_instruction(&[PHP, 0x00]).unwrap(),
+        (Instruction::PushStatus, 0x8001)
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[PHP, 0x00]).unwrap();
+    assert_eq!(cpu.stack_pointer, 0xfc);
+    assert_eq!(cpu.bus.read(0x01fd), 0b0011_0100);
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[PHP, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Carry, true);
+    cpu.status.set(Flag::Negative, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x01fd), 0b1011_0101);
+}
+
+#[test]
+fn pla() {
+    use super::opcodes::{LDA_IMMEDIATE, PHA, PLA};
+
+    assert!(matches!(
+        get_instruction(&[PLA, 0x00]).unwrap(),
+        (
+            Instruction::Pull {
+                destination: Register::A
+            },
+            0x8001
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0xf1, PHA, LDA_IMMEDIATE, 0x00, PLA, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_a, 0xf1);
+    assert_eq!(cpu.stack_pointer, 0xfd);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[PLA, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_a = 0x42;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0x00);
+    assert_eq!(cpu.stack_pointer, 0xfe);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn plp() {
+    use super::opcodes::{PHP, PLP};
+
+    assert!(matches!(
+        get_instruction(&[PLP, 0x00]).unwrap(),
+        (Instruction::PullStatus, 0x8001)
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[PLP, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.stack_pointer = 0xfe;
+    cpu.bus.write(0x01ff, 0b1111_1111);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.stack_pointer, 0xff);
+    assert!(cpu.status.get(Flag::Carry));
+    assert!(cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::InterruptDisable));
+    assert!(cpu.status.get(Flag::Decimal));
+    assert!(cpu.status.get(Flag::Overflow));
+    assert!(cpu.status.get(Flag::Negative));
+    assert_eq!(cpu.status.to_stack(false), 0b1110_1111);
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[PHP, PLP, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Overflow, true);
+    cpu.status.set(Flag::Zero, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.stack_pointer, 0xfd);
+    assert!(cpu.status.get(Flag::Overflow));
+    assert!(cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Carry));
+    assert!(!cpu.status.get(Flag::Negative));
+}
+
+#[test]
+fn jmp_absolute() {
+    use super::opcodes::{JMP_ABSOLUTE, LDA_IMMEDIATE};
+
+    assert!(matches!(
+        get_instruction(&[JMP_ABSOLUTE, 0xab, 0xcd]).unwrap(),
+        (
+            Instruction::Jmp {
+                addressing_mode: JmpAddressingMode::Absolute {
+                    mode: AM::Absolute { address: 0xcdab }
+                }
+            },
+            0x8003
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[
+        JMP_ABSOLUTE,
+        0x05,
+        0x80,
+        LDA_IMMEDIATE,
+        0x01,
+        LDA_IMMEDIATE,
+        0x02,
+        0x00,
+    ])
+    .unwrap();
+    assert_eq!(cpu.register_a, 0x02);
+    assert_eq!(cpu.program_counter, 0x8007);
+}
+
+#[test]
+fn jmp_indirect() {
+    use super::opcodes::JMP_INDIRECT;
+
+    assert!(matches!(
+        get_instruction(&[JMP_INDIRECT, 0xab, 0xcd]).unwrap(),
+        (
+            Instruction::Jmp {
+                addressing_mode: JmpAddressingMode::Indirect {
+                    mode: AM::Indirect { address: 0xcdab }
+                }
+            },
+            0x8003
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[JMP_INDIRECT, 0x20, 0x01]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.bus.load(0x0120, &[0x00, 0x90]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x9000);
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[JMP_INDIRECT, 0xff, 0x02]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.bus.write(0x02ff, 0x00);
+    cpu.bus.write(0x0300, 0x80);
+    cpu.bus.write(0x0200, 0x90);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x9000);
+}
+
+#[test]
+fn jsr() {
+    use super::opcodes::{JSR, LDA_IMMEDIATE};
+
+    assert!(matches!(
+        get_instruction(&[JSR, 0xab, 0xcd]).unwrap(),
+        (
+            Instruction::Jsr {
+                addressing_mode: AM::Absolute { address: 0xcdab }
+            },
+            0x8003
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[
+        JSR,
+        0x05,
+        0x80,
+        LDA_IMMEDIATE,
+        0x01,
+        LDA_IMMEDIATE,
+        0x02,
+        0x00,
+    ])
+    .unwrap();
+    assert_eq!(cpu.register_a, 0x02);
+    assert_eq!(cpu.stack_pointer, 0xfb);
+    assert_eq!(cpu.bus.read(0x01fd), 0x80);
+    assert_eq!(cpu.bus.read(0x01fc), 0x02);
+}
+
+#[test]
+fn rts() {
+    use super::opcodes::{JSR, LDA_IMMEDIATE, LDX_IMMEDIATE, RTS};
+
+    assert!(matches!(
+        get_instruction(&[RTS, 0x00]).unwrap(),
+        (Instruction::Rts, 0x8001)
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[
+        JSR,
+        0x06,
+        0x80,
+        LDX_IMMEDIATE,
+        0x03,
+        0x00,
+        LDA_IMMEDIATE,
+        0x07,
+        RTS,
+    ])
+    .unwrap();
+    assert_eq!(cpu.register_a, 0x07);
+    assert_eq!(cpu.register_x, 0x03);
+    assert_eq!(cpu.stack_pointer, 0xfd);
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[RTS]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.stack_pointer = 0xfd;
+    cpu.bus.load(0x01fe, &[0xff, 0x8f]).unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x9000);
+    assert_eq!(cpu.stack_pointer, 0xff);
+}
+
+#[test]
+fn brk() {
+    use super::opcodes::BRK;
+
+    assert!(matches!(
+        get_instruction(&[BRK, 0x00]).unwrap(),
+        (Instruction::Break, 0x8001)
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[BRK, 0xff]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Carry, true);
+    cpu.bus.write_u16(0xfffe, 0x9000);
+    cpu.step().unwrap();
+    assert_eq!(cpu.program_counter, 0x9000);
+    assert_eq!(cpu.stack_pointer, 0xfa);
+    assert_eq!(cpu.bus.read(0x01fd), 0x80);
+    assert_eq!(cpu.bus.read(0x01fc), 0x02);
+    assert_eq!(cpu.bus.read(0x01fb), 0b0011_0101);
+    assert!(cpu.status.get(Flag::InterruptDisable));
+}
+
+#[test]
+fn halt_on_brk() {
+    use super::opcodes::{BRK, LDA_IMMEDIATE};
+    use crate::cpu::HaltReason;
+
+    let mut cpu = Cpu::with_config(CpuConfig {
+        halt_on_brk: true,
+        ..CpuConfig::default()
+    });
+    cpu.load(&[BRK, 0xff]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    let step = cpu.step().unwrap();
+    assert_eq!(
+        step.halt_reason,
+        Some(HaltReason::Break { address: 0x8000 })
+    );
+    assert_eq!(step.cycles, 1);
+    assert_eq!(cpu.program_counter, 0x8000);
+    assert_eq!(cpu.stack_pointer, 0xfd);
+    assert_eq!(cpu.run().unwrap(), HaltReason::Break { address: 0x8000 });
+
+    // `load_and_run` halts on BRK by itself.
+    let mut cpu = Cpu::new();
+    cpu.bus.write_u16(0xfffc, 0x8000);
+    assert_eq!(
+        cpu.load_and_run(&[LDA_IMMEDIATE, 0x05, BRK]).unwrap(),
+        HaltReason::Break { address: 0x8002 }
+    );
+    assert_eq!(cpu.register_a, 0x05);
+    assert!(!cpu.config.halt_on_brk);
+}
+
+#[test]
+fn rti() {
+    use super::opcodes::{BRK, LDA_IMMEDIATE, RTI};
+
+    assert!(matches!(
+        get_instruction(&[RTI, 0x00]).unwrap(),
+        (Instruction::Rti, 0x8001)
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[BRK, 0xff, LDA_IMMEDIATE, 0x42, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::Carry, true);
+    cpu.status.set(Flag::InterruptDisable, false);
+    cpu.bus.write_u16(0xfffe, 0x9000);
+    cpu.bus.write(0x9000, RTI);
+    cpu.step().unwrap();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x8004);
+    assert_eq!(cpu.register_a, 0x42);
+    assert_eq!(cpu.stack_pointer, 0xfd);
+    assert!(cpu.status.get(Flag::Carry));
+    assert!(!cpu.status.get(Flag::InterruptDisable));
+}
+
+#[test]
+fn irq() {
+    use super::opcodes::{CLI, LDA_IMMEDIATE, LDX_IMMEDIATE, RTI};
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0x01, CLI, LDX_IMMEDIATE, 0x02, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::InterruptDisable, true);
+    cpu.bus.write_u16(0xfffe, 0x9000);
+    cpu.bus.write(0x9000, 0x00);
+    cpu.set_irq(true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x9000);
+    assert_eq!(cpu.register_a, 0x01);
+    assert_eq!(cpu.register_x, 0x00);
+    assert_eq!(cpu.bus.read(0x01fd), 0x80);
+    assert_eq!(cpu.bus.read(0x01fc), 0x03);
+    assert_eq!(cpu.bus.read(0x01fb), 0b0010_0000);
+    assert!(cpu.status.get(Flag::InterruptDisable));
+
+    cpu.set_irq(false);
+    cpu.bus.write(0x9000, RTI);
+    cpu.resume();
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x8005);
+    assert_eq!(cpu.register_x, 0x02);
+}
+
+#[test]
+fn nmi() {
+    use super::opcodes::{LDA_IMMEDIATE, RTI};
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0x01, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.status.set(Flag::InterruptDisable, true);
+    cpu.bus.write_u16(0xfffa, 0x9000);
+    cpu.bus.write(0x9000, RTI);
+    cpu.set_nmi(true);
+    cpu.step().unwrap();
+    assert_eq!(cpu.program_counter, 0x9000);
+    assert_eq!(cpu.bus.read(0x01fb), 0b0010_0100);
+
+    // Holding the line doesn't trigger another interrupt.
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.program_counter, 0x8002);
+    assert_eq!(cpu.register_a, 0x01);
+    assert_eq!(cpu.stack_pointer, 0xfd);
+
+    cpu.set_nmi(false);
+    cpu.set_nmi(true);
+    cpu.resume();
+    cpu.step().unwrap();
+    assert_eq!(cpu.program_counter, 0x9000);
+}
+
+#[test]
+fn iny() {
+    use super::opcodes::{INY, LDY_IMMEDIATE};
+
+    assert!(matches!(
+        get_instruction(&[INY, 0x00]).unwrap(),
+        (
+            Instruction::In {
+                destination: Register::Y
+            },
+            0x8001
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDY_IMMEDIATE, 0x7f, INY, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_y, 0x80);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Overflow));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDY_IMMEDIATE, 0xff, INY, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_y, 0x00);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Overflow));
+    assert!(!cpu.status.get(Flag::Carry));
+}
+
+#[test]
+fn dex() {
+    use super::opcodes::{DEX, LDX_IMMEDIATE};
+
+    assert!(matches!(
+        get_instruction(&[DEX, 0x00]).unwrap(),
+        (
+            Instruction::De {
+                destination: Register::X
+            },
+            0x8001
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDX_IMMEDIATE, 0x01, DEX, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_x, 0x00);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDX_IMMEDIATE, 0x00, DEX, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_x, 0xff);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Carry));
+}
+
+#[test]
+fn dey() {
+    use super::opcodes::{DEY, LDY_IMMEDIATE};
+
+    assert!(matches!(
+        get_instruction(&[DEY, 0x00]).unwrap(),
+        (
+            Instruction::De {
+                destination: Register::Y
+            },
+            0x8001
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[LDY_IMMEDIATE, 0x01, DEY, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_y, 0x00);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Zero));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[DEY, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_y = 0x80;
+    cpu.status.set(Flag::Overflow, true);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_y, 0x7f);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Overflow));
+}
+
+#[test]
+fn nop() {
+    use super::opcodes::{NOP, NOP_IMPLIED_1A};
+
+    assert!(matches!(
+        get_instruction(&[NOP, 0x00]).unwrap(),
+        (Instruction::Nop, 0x8001)
+    ));
+    assert!(matches!(
+        get_instruction(&[NOP_IMPLIED_1A, 0x00]).unwrap(),
+        (Instruction::Nop, 0x8001)
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[NOP, NOP_IMPLIED_1A, 0x00]).unwrap();
+    assert_eq!(cpu.program_counter, 0x8002);
+    assert_eq!(cpu.register_a, 0x00);
+    assert_eq!(cpu.stack_pointer, 0xfd);
+    assert!(!cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn ign() {
+    use super::opcodes::{NOP_ABSOLUTE_X_1C, NOP_IMMEDIATE_80, NOP_ZERO_PAGE_04};
+
+    assert!(matches!(
+        get_instruction(&[NOP_IMMEDIATE_80, 0xc0]).unwrap(),
+        (
+            Instruction::Ign {
+                addressing_mode: IgnAddressingMode::Immediate {
+                    mode: AM::Immediate { immediate: 0xc0 }
+                }
+            },
+            0x8002
+        )
+    ));
+    assert!(matches!(
+        get_instruction(&[NOP_ZERO_PAGE_04, 0xc0]).unwrap(),
+        (
+            Instruction::Ign {
+                addressing_mode: IgnAddressingMode::IgnAddressAddressingMode {
+                    mode: IgnAddressAddressingMode::ZeroPage {
+                        mode: AM::ZeroPage { address: 0xc0 }
+                    }
+                }
+            },
+            0x8002
+        )
+    ));
+    assert!(matches!(
+        get_instruction(&[NOP_ABSOLUTE_X_1C, 0xab, 0xcd]).unwrap(),
+        (
+            Instruction::Ign {
+                addressing_mode: IgnAddressingMode::IgnAddressAddressingMode {
+                    mode: IgnAddressAddressingMode::AbsoluteX {
+                        mode: AM::AbsoluteX { address: 0xcdab }
+                    }
+                }
+            },
+            0x8003
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load_and_run_test(&[
+        NOP_IMMEDIATE_80,
+        0xff,
+        NOP_ZERO_PAGE_04,
+        0x10,
+        NOP_ABSOLUTE_X_1C,
+        0x00,
+        0x02,
+        0x00,
+    ])
+    .unwrap();
+    assert_eq!(cpu.program_counter, 0x8007);
+    assert_eq!(cpu.register_a, 0x00);
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Negative));
+}
+
+#[test]
+fn lax_zero_page() {
+    use super::opcodes::LAX_ZERO_PAGE;
+
+    assert!(matches!(
+        get_instruction(&[LAX_ZERO_PAGE, 0xc0]).unwrap(),
+        (
+            Instruction::Lax {
+                addressing_mode: LaxAddressingMode::ZeroPage {
+                    mode: AM::ZeroPage { address: 0xc0 }
+                }
+            },
+            0x8002
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LAX_ZERO_PAGE, 0x10, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.bus.write(0x10, 0xf1);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.register_a, 0xf1);
+    assert_eq!(cpu.register_x, 0xf1);
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn sax_zero_page() {
+    use super::opcodes::SAX_ZERO_PAGE;
+
+    assert!(matches!(
+        get_instruction(&[SAX_ZERO_PAGE, 0xc0]).unwrap(),
+        (
+            Instruction::Sax {
+                addressing_mode: SaxAddressingMode::ZeroPage {
+                    mode: AM::ZeroPage { address: 0xc0 }
+                }
+            },
+            0x8002
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[SAX_ZERO_PAGE, 0x10, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_a = 0b1100_1100;
+    cpu.register_x = 0b1010_1010;
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x10), 0b1000_1000);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn dcp_zero_page() {
+    use super::opcodes::DCP_ZERO_PAGE;
+
+    assert!(matches!(
+        get_instruction(&[DCP_ZERO_PAGE, 0xc0]).unwrap(),
+        (
+            Instruction::Dcp {
+                addressing_mode: DcpAddressingMode::ZeroPage {
+                    mode: AM::ZeroPage { address: 0xc0 }
+                }
+            },
+            0x8002
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[DCP_ZERO_PAGE, 0x10, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_a = 0x41;
+    cpu.bus.write(0x10, 0x42);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x10), 0x41);
+    assert!(cpu.status.get(Flag::Zero));
+    assert!(cpu.status.get(Flag::Carry));
+    assert!(!cpu.status.get(Flag::Negative));
+}
+
+#[test]
+fn isb_zero_page() {
+    use super::opcodes::ISB_ZERO_PAGE;
+
+    assert!(matches!(
+        get_instruction(&[ISB_ZERO_PAGE, 0xc0]).unwrap(),
+        (
+            Instruction::Isb {
+                addressing_mode: IsbAddressingMode::ZeroPage {
+                    mode: AM::ZeroPage { address: 0xc0 }
+                }
+            },
+            0x8002
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[ISB_ZERO_PAGE, 0x10, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_a = 0x10;
+    cpu.status.set(Flag::Carry, true);
+    cpu.bus.write(0x10, 0x04);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x10), 0x05);
+    assert_eq!(cpu.register_a, 0x0b);
+    assert!(cpu.status.get(Flag::Carry));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Negative));
+}
+
+#[test]
+fn slo_zero_page() {
+    use super::opcodes::SLO_ZERO_PAGE;
+
+    assert!(matches!(
+        get_instruction(&[SLO_ZERO_PAGE, 0xc0]).unwrap(),
+        (
+            Instruction::Slo {
+                addressing_mode: SloAddressingMode::ZeroPage {
+                    mode: AM::ZeroPage { address: 0xc0 }
+                }
+            },
+            0x8002
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[SLO_ZERO_PAGE, 0x10, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_a = 0b0000_0001;
+    cpu.bus.write(0x10, 0b1100_0000);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x10), 0b1000_0000);
+    assert_eq!(cpu.register_a, 0b1000_0001);
+    assert!(cpu.status.get(Flag::Carry));
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn rla_zero_page() {
+    use super::opcodes::RLA_ZERO_PAGE;
+
+    assert!(matches!(
+        get_instruction(&[RLA_ZERO_PAGE, 0xc0]).unwrap(),
+        (
+            Instruction::Rla {
+                addressing_mode: RlaAddressingMode::ZeroPage {
+                    mode: AM::ZeroPage { address: 0xc0 }
+                }
+            },
+            0x8002
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[RLA_ZERO_PAGE, 0x10, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_a = 0b0000_0010;
+    cpu.status.set(Flag::Carry, true);
+    cpu.bus.write(0x10, 0b1000_0000);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x10), 0b0000_0001);
+    assert_eq!(cpu.register_a, 0b0000_0000);
+    assert!(cpu.status.get(Flag::Carry));
+    assert!(cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Negative));
+}
+
+#[test]
+fn sre_zero_page() {
+    use super::opcodes::SRE_ZERO_PAGE;
+
+    assert!(matches!(
+        get_instruction(&[SRE_ZERO_PAGE, 0xc0]).unwrap(),
+        (
+            Instruction::Sre {
+                addressing_mode: SreAddressingMode::ZeroPage {
+                    mode: AM::ZeroPage { address: 0xc0 }
+                }
+            },
+            0x8002
+        )
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[SRE_ZERO_PAGE, 0x10, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_a = 0b1000_0001;
+    cpu.bus.write(0x10, 0b0000_0011);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x10), 0b0000_0001);
+    assert_eq!(cpu.register_a, 0b1000_0000);
+    assert!(cpu.status.get(Flag::Carry));
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn rra_zero_page() {
+    use super::opcodes::RRA_ZERO_PAGE;
+
+    assert!(matches!(
+        get_instruction(&[RRA_ZERO_PAGE, 0xc0]).unwrap(),
+        (
+            Instruction::Rra {
+                addressing_mode: RraAddressingMode::ZeroPage {
+                    mode: AM::ZeroPage { address: 0xc0 }
+                }
+            },
+            0x8002
+        )
+    ));
+
+    // The carry shifted out by ROR feeds the ADC.
+    let mut cpu = Cpu::new();
+    cpu.load(&[RRA_ZERO_PAGE, 0x10, 0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_a = 0x10;
+    cpu.bus.write(0x10, 0b0000_0101);
+    cpu.run_until_brk().unwrap();
+    assert_eq!(cpu.bus.read(0x10), 0b0000_0010);
+    assert_eq!(cpu.register_a, 0x13);
+    assert!(!cpu.status.get(Flag::Carry));
+    assert!(!cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Negative));
+}
+
+#[test]
+fn unofficial_opcodes_strict() {
+    use super::opcodes::{LAX_ZERO_PAGE, NOP, NOP_IMPLIED_1A, SBC_IMMEDIATE_UNOFFICIAL};
+
+    let config = CpuConfig {
+        unofficial_opcodes: false,
+        ..CpuConfig::default()
+    };
+    let mut memory = Memory::new();
+    memory
+        .load(
+            0x8000,
+            &[NOP, NOP_IMPLIED_1A, LAX_ZERO_PAGE, SBC_IMMEDIATE_UNOFFICIAL],
+        )
+        .unwrap();
+    assert!(matches!(
+        Instruction::get_instruction(&memory, &0x8000, &config),
+        Ok((Instruction::Nop, 0x8001))
+    ));
+    assert!(matches!(
+        Instruction::get_instruction(&memory, &0x8001, &config),
+        Err(InstructionError::InvalidInstructionCode { code: 0x1a })
+    ));
+    assert!(matches!(
+        Instruction::get_instruction(&memory, &0x8002, &config),
+        Err(InstructionError::InvalidInstructionCode { code: 0xa7 })
+    ));
+    assert!(matches!(
+        Instruction::get_instruction(&memory, &0x8003, &config),
+        Err(InstructionError::InvalidInstructionCode { code: 0xeb })
+    ));
+
+    let mut cpu = Cpu::with_config(config);
+    assert!(cpu.load_and_run_test(&[NOP, NOP_IMPLIED_1A, 0x00]).is_err());
+    assert_eq!(cpu.program_counter, 0x8001);
+}
+
+#[test]
+fn invalid_instruction_error() {
+    use super::opcodes::{LDX_IMMEDIATE, NOP_IMPLIED_1A};
+    use crate::cpu::{CpuError, Registers};
+
+    let mut cpu = Cpu::with_config(CpuConfig {
+        unofficial_opcodes: false,
+        ..CpuConfig::default()
+    });
+    cpu.load(&[LDX_IMMEDIATE, 0x42, NOP_IMPLIED_1A, 0x05, 0x06])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.stack_pointer = 0xfd;
+    cpu.cycles = 0;
+
+    cpu.step().unwrap();
+    let error = cpu.step().unwrap_err();
+    assert!(matches!(
+        error,
+        CpuError::Instruction {
+            source: InstructionError::InvalidInstructionCode { code: 0x1a },
+            program_counter: 0x8002,
+            bytes: [0x1a, 0x05, 0x06],
+            registers: Registers {
+                register_a: 0x00,
+                register_x: 0x42,
+                register_y: 0x00,
+                stack_pointer: 0xfd,
+                status: 0b0010_0100,
+                program_counter: 0x8002,
+                cycles: 2,
+            },
+        }
+    ));
+    assert_eq!(
+        error.to_string(),
+        "invalid instruction code 0x1a at 0x8002 (bytes [1a, 05, 06]), \
+         A:00 X:42 Y:00 P:24 SP:FD PC:8002 CYC:2"
+    );
+}
+
+#[test]
+fn power_on() {
+    use crate::cpu::memory::RamInit;
+
+    let mut cpu = Cpu::with_config(CpuConfig {
+        ram_init: RamInit::Fill(0xff),
+        ..CpuConfig::default()
+    });
+    cpu.load(&[0x00]).unwrap();
+    cpu.bus.write_u16(0xfffc, 0x8000);
+    cpu.register_a = 0x01;
+    cpu.power_on().unwrap();
+    assert_eq!(cpu.register_a, 0x00);
+    assert_eq!(cpu.stack_pointer, 0xfd);
+    assert_eq!(cpu.status.to_stack(false), 0x24);
+    assert_eq!(cpu.program_counter, 0x8000);
+    assert_eq!(cpu.cycles, 7);
+    assert_eq!(cpu.bus.read(0x0000), 0xff);
+    assert_eq!(cpu.bus.read(0x07ff), 0xff);
+}
+
+#[test]
+fn ram_init() {
+    use crate::cpu::memory::RamInit;
+
+    let cpu = Cpu::with_config(CpuConfig {
+        ram_init: RamInit::Alternating,
+        ..CpuConfig::default()
+    });
+    assert_eq!(cpu.bus.read(0x0003), 0x00);
+    assert_eq!(cpu.bus.read(0x0004), 0xff);
+
+    let config = CpuConfig {
+        ram_init: RamInit::random(),
+        ..CpuConfig::default()
+    };
+    let first = Cpu::with_config(config);
+    let second = Cpu::with_config(first.config);
+    assert!(first.bus.iter().eq(second.bus.iter()));
+}
+
+#[test]
+fn reset() {
+    let mut cpu = Cpu::new();
+    cpu.bus.write_u16(0xfffc, 0x9000);
+    cpu.power_on().unwrap();
+    cpu.register_a = 0x01;
+    cpu.register_x = 0x02;
+    cpu.register_y = 0x03;
+    cpu.status.set(Flag::InterruptDisable, false);
+    cpu.status.set(Flag::Carry, true);
+    cpu.bus.write(0x01fd, 0x42);
+
+    cpu.reset().unwrap();
+    assert_eq!(cpu.register_a, 0x01);
+    assert_eq!(cpu.register_x, 0x02);
+    assert_eq!(cpu.register_y, 0x03);
+    assert_eq!(cpu.stack_pointer, 0xfa);
+    assert!(cpu.status.get(Flag::InterruptDisable));
+    assert!(cpu.status.get(Flag::Carry));
+    assert_eq!(cpu.program_counter, 0x9000);
+    assert_eq!(cpu.cycles, 14);
+    // Nothing is written to the stack.
+    assert_eq!(cpu.bus.read(0x01fd), 0x42);
+}
+
+#[test]
+fn step() {
+    use super::opcodes::{JMP_ABSOLUTE, LDA_IMMEDIATE};
+    use crate::cpu::Executed;
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDA_IMMEDIATE, 0x01, JMP_ABSOLUTE, 0x00, 0x90])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+
+    let step = cpu.step().unwrap();
+    assert!(matches!(
+        step.executed,
+        Executed::Instruction(Instruction::Ld {
+            destination: Register::A,
+            ..
+        })
+    ));
+    assert_eq!(step.cycles, 2);
+    assert_eq!(step.program_counter_before, 0x8000);
+    assert_eq!(step.program_counter_after, 0x8002);
+
+    let step = cpu.step().unwrap();
+    assert!(matches!(
+        step.executed,
+        Executed::Instruction(Instruction::Jmp { .. })
+    ));
+    assert_eq!(step.cycles, 3);
+    assert_eq!(step.program_counter_before, 0x8002);
+    assert_eq!(step.program_counter_after, 0x9000);
+
+    cpu.set_nmi(true);
+    let step = cpu.step().unwrap();
+    assert!(matches!(step.executed, Executed::Nmi));
+    assert_eq!(step.cycles, 7);
+}
+
+#[test]
+fn run_for_cycles() {
+    use super::opcodes::{INX, JMP_ABSOLUTE};
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[INX, JMP_ABSOLUTE, 0x00, 0x80]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+
+    assert_eq!(cpu.run_for_cycles(10).unwrap(), 10);
+    assert_eq!(cpu.register_x, 0x02);
+    // Whole instructions are run, so the last one can overshoot.
+    assert_eq!(cpu.run_for_cycles(1).unwrap(), 2);
+    assert_eq!(cpu.register_x, 0x03);
+    assert_eq!(cpu.cycles, 7 + 12);
+}
+
+#[test]
+fn run_until() {
+    use super::opcodes::{INX, JMP_ABSOLUTE};
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[INX, JMP_ABSOLUTE, 0x00, 0x80]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+
+    cpu.run_until(|cpu| cpu.register_x == 0x10).unwrap();
+    assert_eq!(cpu.register_x, 0x10);
+    assert_eq!(cpu.program_counter, 0x8001);
+
+    cpu.halt();
+    cpu.run_until(|_| false).unwrap();
+    assert_eq!(cpu.program_counter, 0x8001);
+}
+
+#[test]
+fn jam() {
+    use super::opcodes::{INX, JAM_02, JAM_F2};
+    use crate::cpu::HaltReason;
+
+    assert!(matches!(
+        get_instruction(&[JAM_02]).unwrap(),
+        (Instruction::Jam, 0x8001)
+    ));
+    assert!(matches!(
+        get_instruction(&[JAM_F2]).unwrap(),
+        (Instruction::Jam, 0x8001)
+    ));
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[INX, JAM_02, INX]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+
+    let step = cpu.step().unwrap();
+    assert_eq!(step.halt_reason, None);
+    let step = cpu.step().unwrap();
+    assert_eq!(step.halt_reason, Some(HaltReason::Jam { address: 0x8001 }));
+    assert_eq!(step.program_counter_after, 0x8001);
+    // It still fetches the opcode and the byte after it.
+    assert_eq!(step.cycles, 2);
+
+    // Stepping a halted CPU does nothing until it is reset.
+    let cycles = cpu.cycles;
+    let step = cpu.step().unwrap();
+    assert!(matches!(step.executed, crate::cpu::Executed::Halted));
+    assert_eq!(step.halt_reason, Some(HaltReason::Jam { address: 0x8001 }));
+    assert_eq!(step.cycles, 0);
+    assert_eq!(cpu.cycles, cycles);
+
+    assert_eq!(cpu.run().unwrap(), HaltReason::Jam { address: 0x8001 });
+    assert_eq!(cpu.register_x, 1);
+    assert_eq!(cpu.program_counter, 0x8001);
+
+    cpu.reset().unwrap();
+    assert!(!cpu.is_halted());
+
+    let config = CpuConfig {
+        unofficial_opcodes: false,
+        ..CpuConfig::default()
+    };
+    let mut memory = Memory::new();
+    memory.load(0x8000, &[JAM_02]).unwrap();
+    assert!(Instruction::get_instruction(&memory, &0x8000, &config).is_err());
+}
+
+#[test]
+fn self_loop_detection() {
+    use super::opcodes::{BNE, INX, JMP_ABSOLUTE, LDX_IMMEDIATE};
+    use crate::cpu::HaltReason;
+
+    let config = CpuConfig {
+        detect_self_loops: true,
+        ..CpuConfig::default()
+    };
+
+    let mut cpu = Cpu::with_config(config);
+    cpu.load(&[INX, JMP_ABSOLUTE, 0x01, 0x80]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    assert_eq!(cpu.run().unwrap(), HaltReason::SelfLoop { address: 0x8001 });
+    assert_eq!(cpu.register_x, 1);
+    assert_eq!(cpu.program_counter, 0x8001);
+
+    let mut cpu = Cpu::with_config(config);
+    cpu.load(&[LDX_IMMEDIATE, 0x01, BNE, 0xfe]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    assert_eq!(cpu.run().unwrap(), HaltReason::SelfLoop { address: 0x8002 });
+
+    // Off by default, where a self loop only ends through `run_until`.
+    let mut cpu = Cpu::new();
+    cpu.load(&[JMP_ABSOLUTE, 0x00, 0x80]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.run_for_cycles(30).unwrap();
+    assert!(!cpu.is_halted());
+}
+
+#[test]
+fn custom_bus() {
+    use super::opcodes::{LDA_ABSOLUTE, STA_ZERO_PAGE};
+    use crate::cpu::bus::Bus;
+
+    /// Bus with a flag at $2002 that is cleared by reading it.
+    struct FlagBus {
+        memory: Memory,
+        flag: bool,
+    }
+
+    impl Bus for FlagBus {
+        fn read(&mut self, address: u16) -> u8 {
+            let value = self.peek(address);
+            if address == 0x2002 {
+                self.flag = false;
+            }
+            value
+        }
+
+        fn write(&mut self, address: u16, value: u8) {
+            self.memory.write(address, value);
+        }
+
+        fn peek(&self, address: u16) -> u8 {
+            match address {
+                0x2002 => (self.flag as u8) << 7,
+                _ => self.memory.read(address),
+            }
+        }
+    }
+
+    let mut memory = Memory::new();
+    memory
+        .load(
+            0x8000,
+            &[
+                LDA_ABSOLUTE,
+                0x02,
+                0x20,
+                STA_ZERO_PAGE,
+                0x10,
+                LDA_ABSOLUTE,
+                0x02,
+                0x20,
+            ],
+        )
+        .unwrap();
+    memory.write_u16(0xfffc, 0x8000);
+
+    let mut cpu = Cpu::with_bus(FlagBus { memory, flag: true }, CpuConfig::default());
+    cpu.reset().unwrap();
+    assert_eq!(cpu.program_counter, 0x8000);
+    cpu.step().unwrap();
+    assert_eq!(cpu.register_a, 0x80);
+    assert!(!cpu.bus().flag);
+
+    cpu.step().unwrap();
+    cpu.step().unwrap();
+    assert_eq!(cpu.register_a, 0x00);
+    assert_eq!(cpu.bus().memory.read(0x10), 0x80);
+}
+
+#[test]
+fn operand_fetches_read_bus() {
+    use super::opcodes::{JSR, LDA_ABSOLUTE};
+    use crate::cpu::bus::Bus;
+
+    /// Bus that logs the addresses read from it.
+    struct LoggingBus {
+        memory: Memory,
+        reads: Vec<u16>,
+    }
+
+    impl Bus for LoggingBus {
+        fn read(&mut self, address: u16) -> u8 {
+            self.reads.push(address);
+            self.memory.read(address)
+        }
+
+        fn write(&mut self, address: u16, value: u8) {
+            self.memory.write(address, value);
+        }
+
+        fn peek(&self, address: u16) -> u8 {
+            self.memory.read(address)
+        }
+    }
+
+    let mut memory = Memory::new();
+    memory
+        .load(0x8000, &[LDA_ABSOLUTE, 0x34, 0x12, JSR, 0x00, 0x90])
+        .unwrap();
+    let mut cpu = Cpu::with_bus(
+        LoggingBus {
+            memory,
+            reads: Vec::new(),
+        },
+        CpuConfig::default(),
+    );
+    cpu.program_counter = 0x8000;
+    cpu.stack_pointer = 0xff;
+
+    cpu.step().unwrap();
+    assert_eq!(cpu.bus().reads, [0x8000, 0x8001, 0x8002, 0x1234]);
+
+    cpu.bus_mut().reads.clear();
+    cpu.step().unwrap();
+    // Between the two bytes of its target it reads the top of the stack.
+    assert_eq!(cpu.bus().reads, [0x8003, 0x8004, 0x01ff, 0x8005]);
+    assert_eq!(cpu.program_counter, 0x9000);
+    assert_eq!(cpu.bus().memory.read_u16(0x01fe), 0x8005);
+}
+
+#[test]
+fn observer() {
+    use super::opcodes::{CMP_IMMEDIATE, STA_ZERO_PAGE};
+    use crate::cpu::trace::TraceEvent;
+    use std::{cell::RefCell, rc::Rc};
+
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let mut cpu = Cpu::new();
+    cpu.load(&[CMP_IMMEDIATE, 0x05, STA_ZERO_PAGE, 0x10, 0x00])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_a = 0x07;
+    {
+        let events = events.clone();
+        cpu.set_observer(move |event: &TraceEvent| events.borrow_mut().push(*event));
+    }
+    cpu.run_until_brk().unwrap();
+
+    let events = events.borrow();
+    assert_eq!(events.len(), 9);
+    assert!(matches!(
+        events[0],
+        TraceEvent::Read {
+            address: 0x8000,
+            value: CMP_IMMEDIATE
+        }
+    ));
+    assert!(matches!(
+        events[1],
+        TraceEvent::Read {
+            address: 0x8001,
+            value: 0x05
+        }
+    ));
+    assert!(matches!(
+        events[2],
+        TraceEvent::InstructionFetched {
+            program_counter: 0x8000,
+            instruction: Instruction::Cmp { .. }
+        }
+    ));
+    assert!(matches!(events[3], TraceEvent::Operand { value: 0x05 }));
+    assert!(matches!(
+        events[4],
+        TraceEvent::Read {
+            address: 0x8002,
+            value: STA_ZERO_PAGE
+        }
+    ));
+    assert!(matches!(
+        events[5],
+        TraceEvent::Read {
+            address: 0x8003,
+            value: 0x10
+        }
+    ));
+    assert!(matches!(
+        events[6],
+        TraceEvent::InstructionFetched {
+            program_counter: 0x8002,
+            instruction: Instruction::St { .. }
+        }
+    ));
+    assert!(matches!(
+        events[7],
+        TraceEvent::Write {
+            address: 0x0010,
+            value: 0x07
+        }
+    ));
+    // The BRK that halts the CPU is fetched, but never decoded.
+    assert!(matches!(
+        events[8],
+        TraceEvent::Read {
+            address: 0x8004,
+            value: 0x00
+        }
+    ));
+
+    cpu.clear_observer();
+}
+
+#[test]
+fn observer_operand_reads() {
+    use super::opcodes::{ASL_ACCUMULATOR, INC_ZERO_PAGE, JSR, LDX_ABSOLUTE};
+    use crate::cpu::trace::TraceEvent;
+    use std::{cell::RefCell, rc::Rc};
+
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let mut cpu = Cpu::new();
+    cpu.load(&[LDX_ABSOLUTE, 0x10, 0x02, JSR, 0x00, 0x90])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.stack_pointer = 0xff;
+    cpu.bus.write(0x0210, 0x42);
+    cpu.bus
+        .load(0x9000, &[INC_ZERO_PAGE, 0x10, ASL_ACCUMULATOR])
+        .unwrap();
+    cpu.bus.write(0x0010, 0x41);
+    {
+        let events = events.clone();
+        cpu.set_observer(move |event: &TraceEvent| events.borrow_mut().push(*event));
+    }
+
+    // Both operand bytes are read before the instruction is reported, and
+    // the value it loads after.
+    cpu.step().unwrap();
+    {
+        let events = events.borrow();
+        assert_eq!(events.len(), 6);
+        assert!(matches!(
+            events[0],
+            TraceEvent::Read {
+                address: 0x8000,
+                value: LDX_ABSOLUTE
+            }
+        ));
+        assert!(matches!(
+            events[1],
+            TraceEvent::Read {
+                address: 0x8001,
+                value: 0x10
+            }
+        ));
+        assert!(matches!(
+            events[2],
+            TraceEvent::Read {
+                address: 0x8002,
+                value: 0x02
+            }
+        ));
+        assert!(matches!(
+            events[3],
+            TraceEvent::InstructionFetched {
+                program_counter: 0x8000,
+                instruction: Instruction::Ld { .. }
+            }
+        ));
+        assert!(matches!(
+            events[4],
+            TraceEvent::Read {
+                address: 0x0210,
+                value: 0x42
+            }
+        ));
+        assert!(matches!(events[5], TraceEvent::Operand { value: 0x42 }));
+    }
+
+    // JSR pushes the return address between the two bytes of its target.
+    events.borrow_mut().clear();
+    cpu.step().unwrap();
+    {
+        let events = events.borrow();
+        assert_eq!(events.len(), 7);
+        assert!(matches!(
+            events[0],
+            TraceEvent::Read {
+                address: 0x8003,
+                value: JSR
+            }
+        ));
+        assert!(matches!(
+            events[1],
+            TraceEvent::Read {
+                address: 0x8004,
+                value: 0x00
+            }
+        ));
+        assert!(matches!(
+            events[2],
+            TraceEvent::Read {
+                address: 0x01ff,
+                ..
+            }
+        ));
+        assert!(matches!(
+            events[3],
+            TraceEvent::Write {
+                address: 0x01ff,
+                value: 0x80
+            }
+        ));
+        assert!(matches!(
+            events[4],
+            TraceEvent::Write {
+                address: 0x01fe,
+                value: 0x05
+            }
+        ));
+        assert!(matches!(
+            events[5],
+            TraceEvent::Read {
+                address: 0x8005,
+                value: 0x90
+            }
+        ));
+        assert!(matches!(
+            events[6],
+            TraceEvent::InstructionFetched {
+                program_counter: 0x8003,
+                instruction: Instruction::Jsr { .. }
+            }
+        ));
+    }
+
+    // Read-modify-writes report the value they read, from memory or A.
+    events.borrow_mut().clear();
+    cpu.step().unwrap();
+    {
+        let events = events.borrow();
+        assert!(matches!(
+            events[3],
+            TraceEvent::Read {
+                address: 0x0010,
+                value: 0x41
+            }
+        ));
+        assert!(matches!(events[4], TraceEvent::Operand { value: 0x41 }));
+    }
+    assert_eq!(cpu.bus.read(0x0010), 0x42);
+
+    events.borrow_mut().clear();
+    cpu.register_a = 0x81;
+    cpu.step().unwrap();
+    let events = events.borrow();
+    assert!(matches!(
+        events.last(),
+        Some(TraceEvent::Operand { value: 0x81 })
+    ));
+}
+
+#[test]
+fn cycles() {
+    use super::opcodes::{LDA_ABSOLUTE_X, LDA_IMMEDIATE, LDX_IMMEDIATE, STA_ABSOLUTE_X};
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[
+        LDA_IMMEDIATE,
+        0x01,
+        LDX_IMMEDIATE,
+        0x01,
+        LDA_ABSOLUTE_X,
+        0x00,
+        0x02,
+        LDA_ABSOLUTE_X,
+        0xff,
+        0x02,
+        STA_ABSOLUTE_X,
+        0x00,
+        0x02,
+        STA_ABSOLUTE_X,
+        0xff,
+        0x02,
+        0x00,
+    ])
+    .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    assert_eq!(cpu.step().unwrap().cycles, 2);
+    assert_eq!(cpu.step().unwrap().cycles, 2);
+    assert_eq!(cpu.step().unwrap().cycles, 4);
+    // Reads pay for crossing a page.
+    assert_eq!(cpu.step().unwrap().cycles, 5);
+    // Writes always take the long path.
+    assert_eq!(cpu.step().unwrap().cycles, 5);
+    assert_eq!(cpu.step().unwrap().cycles, 5);
+    // On top of the 7 cycles of the reset.
+    assert_eq!(cpu.cycles, 7 + 23);
+}
+
+#[test]
+fn cycles_indirect_y() {
+    use super::opcodes::{LDA_INDIRECT_Y, LDY_IMMEDIATE};
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[
+        LDY_IMMEDIATE,
+        0x01,
+        LDA_INDIRECT_Y,
+        0x10,
+        LDA_INDIRECT_Y,
+        0x20,
+        0x00,
+    ])
+    .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.bus.write_u16(0x10, 0x0200);
+    cpu.bus.write_u16(0x20, 0x02ff);
+    assert_eq!(cpu.step().unwrap().cycles, 2);
+    assert_eq!(cpu.step().unwrap().cycles, 5);
+    assert_eq!(cpu.step().unwrap().cycles, 6);
+}
+
+#[test]
+fn cycles_branch() {
+    use super::opcodes::{BCC, BCS};
+
+    let mut cpu = Cpu::new();
+    cpu.load(&[BCS, 0x10, BCC, 0x02, 0x00, 0x00, BCC, 0x80])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    // Not taken.
+    assert_eq!(cpu.step().unwrap().cycles, 2);
+    // Taken, same page.
+    assert_eq!(cpu.step().unwrap().cycles, 3);
+    assert_eq!(cpu.program_counter, 0x8006);
+    // Taken, to the previous page.
+    assert_eq!(cpu.step().unwrap().cycles, 4);
+    assert_eq!(cpu.program_counter, 0x7f88);
+}
+
+#[test]
+fn cycles_interrupt() {
+    let mut cpu = Cpu::new();
+    cpu.load(&[0x00]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.set_nmi(true);
+    assert_eq!(cpu.step().unwrap().cycles, 7);
+    cpu.program_counter = 0x8000;
+    // BRK costs the same as a hardware interrupt.
+    assert_eq!(cpu.step().unwrap().cycles, 7);
+}
+
+/// Ticks through the next instruction, returning its step and the access
+/// made on each cycle.
+fn tick_instruction(
+    cpu: &mut Cpu,
+) -> Result<(crate::cpu::Step, Vec<crate::cpu::BusCycle>), crate::cpu::CpuError> {
+    let mut bus_cycles = Vec::new();
+    loop {
+        let tick = cpu.tick()?;
+        bus_cycles.extend(tick.bus_cycle);
+        if let Some(step) = tick.step {
+            return Ok((step, bus_cycles));
+        }
+    }
+}
+
+/// Runs the next instruction and checks every bus cycle it makes, given as
+/// (address, value, access), and that it took as many cycles.
+fn assert_bus_cycles(cpu: &mut Cpu, expected: &[(u16, u8, crate::cpu::BusAccess)]) {
+    let (step, bus_cycles) = tick_instruction(cpu).unwrap();
+    let expected: Vec<_> = expected
+        .iter()
+        .map(|&(address, value, access)| crate::cpu::BusCycle {
+            address,
+            value,
+            access,
+        })
+        .collect();
+    assert_eq!(bus_cycles, expected);
+    assert_eq!(step.cycles as usize, expected.len());
+}
+
+fn bus_cycles_cpu(program: &[u8]) -> Cpu {
+    let mut cpu = Cpu::new();
+    cpu.load(program).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.stack_pointer = 0xff;
+    cpu.cycles = 0;
+    cpu
+}
+
+#[test]
+fn bus_cycles_absolute_x() {
+    use super::opcodes::{LDA_ABSOLUTE_X, STA_ABSOLUTE_X};
+    use crate::cpu::BusAccess::{Read, Write};
+
+    let mut cpu = bus_cycles_cpu(&[LDA_ABSOLUTE_X, 0xff, 0x02, STA_ABSOLUTE_X, 0x00, 0x02]);
+    cpu.register_x = 0x01;
+    cpu.bus.write(0x0300, 0x42);
+    // The read crosses a page, so the unfixed address is read first.
+    assert_bus_cycles(
+        &mut cpu,
+        &[
+            (0x8000, LDA_ABSOLUTE_X, Read),
+            (0x8001, 0xff, Read),
+            (0x8002, 0x02, Read),
+            (0x0200, 0x00, Read),
+            (0x0300, 0x42, Read),
+        ],
+    );
+    // Writes always read the address before writing to it.
+    assert_bus_cycles(
+        &mut cpu,
+        &[
+            (0x8003, STA_ABSOLUTE_X, Read),
+            (0x8004, 0x00, Read),
+            (0x8005, 0x02, Read),
+            (0x0201, 0x00, Read),
+            (0x0201, 0x42, Write),
+        ],
+    );
+}
+
+#[test]
+fn bus_cycles_read_modify_write() {
+    use super::opcodes::{ASL_ZERO_PAGE, DEC_ZERO_PAGE_X};
+    use crate::cpu::BusAccess::{Read, Write};
+
+    let mut cpu = bus_cycles_cpu(&[ASL_ZERO_PAGE, 0x10, DEC_ZERO_PAGE_X, 0x10]);
+    cpu.register_x = 0x02;
+    cpu.bus.write(0x0010, 0x41);
+    cpu.bus.write(0x0012, 0x05);
+    assert_bus_cycles(
+        &mut cpu,
+        &[
+            (0x8000, ASL_ZERO_PAGE, Read),
+            (0x8001, 0x10, Read),
+            (0x0010, 0x41, Read),
+            (0x0010, 0x41, Write),
+            (0x0010, 0x82, Write),
+        ],
+    );
+    assert_bus_cycles(
+        &mut cpu,
+        &[
+            (0x8002, DEC_ZERO_PAGE_X, Read),
+            (0x8003, 0x10, Read),
+            (0x0010, 0x82, Read),
+            (0x0012, 0x05, Read),
+            (0x0012, 0x05, Write),
+            (0x0012, 0x04, Write),
+        ],
+    );
+}
+
+#[test]
+fn bus_cycles_indirect() {
+    use super::opcodes::{LDA_INDIRECT_X, LDA_INDIRECT_Y};
+    use crate::cpu::BusAccess::Read;
+
+    let mut cpu = bus_cycles_cpu(&[LDA_INDIRECT_X, 0x10, LDA_INDIRECT_Y, 0x20]);
+    cpu.register_x = 0x04;
+    cpu.register_y = 0x01;
+    cpu.bus.write_u16(0x0014, 0x0400);
+    cpu.bus.write(0x0400, 0x07);
+    cpu.bus.write_u16(0x0020, 0x02ff);
+    cpu.bus.write(0x0300, 0x09);
+    assert_bus_cycles(
+        &mut cpu,
+        &[
+            (0x8000, LDA_INDIRECT_X, Read),
+            (0x8001, 0x10, Read),
+            (0x0010, 0x00, Read),
+            (0x0014, 0x00, Read),
+            (0x0015, 0x04, Read),
+            (0x0400, 0x07, Read),
+        ],
+    );
+    assert_bus_cycles(
+        &mut cpu,
+        &[
+            (0x8002, LDA_INDIRECT_Y, Read),
+            (0x8003, 0x20, Read),
+            (0x0020, 0xff, Read),
+            (0x0021, 0x02, Read),
+            (0x0200, 0x00, Read),
+            (0x0300, 0x09, Read),
+        ],
+    );
+}
+
+#[test]
+fn bus_cycles_stack() {
+    use super::opcodes::{JSR, PHA, PLA, RTS};
+    use crate::cpu::BusAccess::{Read, Write};
+
+    let mut cpu = bus_cycles_cpu(&[JSR, 0x00, 0x90, PLA]);
+    cpu.bus.load(0x9000, &[PHA, RTS]).unwrap();
+    cpu.register_a = 0x33;
+    assert_bus_cycles(
+        &mut cpu,
+        &[
+            (0x8000, JSR, Read),
+            (0x8001, 0x00, Read),
+            (0x01ff, 0x00, Read),
+            (0x01ff, 0x80, Write),
+            (0x01fe, 0x02, Write),
+            (0x8002, 0x90, Read),
+        ],
+    );
+    assert_bus_cycles(
+        &mut cpu,
+        &[
+            (0x9000, PHA, Read),
+            (0x9001, RTS, Read),
+            (0x01fd, 0x33, Write),
+        ],
+    );
+    cpu.stack_pointer = 0xfd;
+    assert_bus_cycles(
+        &mut cpu,
+        &[
+            (0x9001, RTS, Read),
+            (0x9002, 0x00, Read),
+            (0x01fd, 0x33, Read),
+            (0x01fe, 0x02, Read),
+            (0x01ff, 0x80, Read),
+            (0x8002, 0x90, Read),
+        ],
+    );
+    assert_bus_cycles(
+        &mut cpu,
+        &[
+            (0x8003, PLA, Read),
+            (0x8004, 0x00, Read),
+            (0x01ff, 0x80, Read),
+            (0x0100, 0x00, Read),
+        ],
+    );
+}
+
+#[test]
+fn bus_cycles_branch() {
+    use super::opcodes::{BCC, BCS};
+    use crate::cpu::BusAccess::Read;
+
+    let mut cpu = bus_cycles_cpu(&[BCS, 0x10, BCC, 0x80]);
+    assert_bus_cycles(&mut cpu, &[(0x8000, BCS, Read), (0x8001, 0x10, Read)]);
+    assert_bus_cycles(
+        &mut cpu,
+        &[
+            (0x8002, BCC, Read),
+            (0x8003, 0x80, Read),
+            (0x8004, 0x00, Read),
+            (0x8084, 0x00, Read),
+        ],
+    );
+    assert_eq!(cpu.program_counter, 0x7f84);
+}
+
+#[test]
+fn bus_cycles_interrupts() {
+    use super::opcodes::{BRK, NOP};
+    use crate::cpu::BusAccess::{Read, Write};
+
+    let mut cpu = bus_cycles_cpu(&[BRK, NOP]);
+    cpu.bus.write_u16(0xfffe, 0x9000);
+    cpu.bus.write_u16(0xfffa, 0xa000);
+    assert_bus_cycles(
+        &mut cpu,
+        &[
+            (0x8000, BRK, Read),
+            (0x8001, NOP, Read),
+            (0x01ff, 0x80, Write),
+            (0x01fe, 0x02, Write),
+            (0x01fd, 0b0011_0100, Write),
+            (0xfffe, 0x00, Read),
+            (0xffff, 0x90, Read),
+        ],
+    );
+    cpu.set_nmi(true);
+    assert_bus_cycles(
+        &mut cpu,
+        &[
+            (0x9000, 0x00, Read),
+            (0x9000, 0x00, Read),
+            (0x01fc, 0x90, Write),
+            (0x01fb, 0x00, Write),
+            (0x01fa, 0b0010_0100, Write),
+            (0xfffa, 0x00, Read),
+            (0xfffb, 0xa0, Read),
+        ],
+    );
+}
+
+#[test]
+fn bus_cycles_cmos_decimal() {
+    use super::opcodes::{ADC_IMMEDIATE, NOP};
+    use crate::cpu::BusAccess::Read;
+
+    let mut cpu = bus_cycles_cpu(&[ADC_IMMEDIATE, 0x01, NOP]);
+    cpu.config.variant = crate::cpu::Variant::Cmos65C02;
+    cpu.status.set(Flag::Decimal, true);
+    cpu.register_a = 0x09;
+    // The extra cycle of decimal mode reads the next opcode.
+    assert_bus_cycles(
+        &mut cpu,
+        &[
+            (0x8000, ADC_IMMEDIATE, Read),
+            (0x8001, 0x01, Read),
+            (0x8002, NOP, Read),
+        ],
+    );
+    assert_eq!(cpu.register_a, 0x10);
+}
+
+#[test]
+fn bus_cycles_match_cycle_count() {
+    use crate::cpu::Variant;
+
+    for variant in [Variant::Nes2A03, Variant::Nmos6502, Variant::Cmos65C02] {
+        for decimal in [false, true] {
+            for code in 0..=0xff {
+                let mut cpu = bus_cycles_cpu(&[code, 0xf0, 0x02]);
+                cpu.config.variant = variant;
+                cpu.status.set(Flag::Decimal, decimal);
+                cpu.register_x = 0x20;
+                cpu.register_y = 0x20;
+                cpu.bus.write_u16(0x00f0, 0x02f0);
+                cpu.bus.write_u16(0x0010, 0x02f0);
+                let Ok((step, bus_cycles)) = tick_instruction(&mut cpu) else {
+                    continue;
+                };
+                assert_eq!(
+                    bus_cycles.len(),
+                    step.cycles as usize,
+                    "opcode {code:#04x} on {variant:?}, decimal: {decimal}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn tick_makes_one_access_per_call() {
+    use super::opcodes::{ASL_ZERO_PAGE, LDA_ABSOLUTE};
+    use crate::cpu::{bus::Bus, BusAccess::*, BusCycle};
+
+    /// Bus that counts the accesses made on it.
+    struct CountingBus {
+        memory: Memory,
+        accesses: usize,
+    }
+
+    impl Bus for CountingBus {
+        fn read(&mut self, address: u16) -> u8 {
+            self.accesses += 1;
+            self.memory.read(address)
+        }
+
+        fn write(&mut self, address: u16, value: u8) {
+            self.accesses += 1;
+            self.memory.write(address, value);
+        }
+
+        fn peek(&self, address: u16) -> u8 {
+            self.memory.read(address)
+        }
+    }
+
+    let mut memory = Memory::new();
+    memory
+        .load(0x8000, &[LDA_ABSOLUTE, 0x34, 0x12, ASL_ZERO_PAGE, 0x10])
+        .unwrap();
+    memory.write(0x1234, 0x11);
+    memory.write(0x0010, 0x41);
+    let mut cpu = Cpu::with_bus(
+        CountingBus {
+            memory,
+            accesses: 0,
+        },
+        CpuConfig::default(),
+    );
+    cpu.program_counter = 0x8000;
+
+    // The registers only change on the last cycle.
+    for accesses in 1..=3 {
+        assert!(cpu.tick().unwrap().step.is_none());
+        assert_eq!(cpu.bus().accesses, accesses);
+        assert_eq!(cpu.program_counter, 0x8000);
+        assert!(cpu.is_mid_instruction());
+    }
+    // A device gets to change the operand before it's read.
+    cpu.bus_mut().memory.write(0x1234, 0x22);
+    let tick = cpu.tick().unwrap();
+    assert_eq!(
+        tick.bus_cycle,
+        Some(BusCycle {
+            address: 0x1234,
+            value: 0x22,
+            access: Read
+        })
+    );
+    assert_eq!(tick.step.unwrap().cycles, 4);
+    assert_eq!(cpu.bus().accesses, 4);
+    assert_eq!(cpu.register_a, 0x22);
+    assert_eq!(cpu.program_counter, 0x8003);
+
+    // The original value is written back a cycle before the result.
+    for _ in 0..3 {
+        cpu.tick().unwrap();
+    }
+    let tick = cpu.tick().unwrap();
+    assert!(tick.step.is_none());
+    assert_eq!(cpu.bus().memory.read(0x0010), 0x41);
+    assert_eq!(
+        tick.bus_cycle,
+        Some(BusCycle {
+            address: 0x0010,
+            value: 0x41,
+            access: Write
+        })
+    );
+    let tick = cpu.tick().unwrap();
+    assert_eq!(tick.step.unwrap().cycles, 5);
+    assert_eq!(cpu.bus().memory.read(0x0010), 0x82);
+    assert_eq!(cpu.bus().accesses, 9);
+    assert!(!cpu.is_mid_instruction());
+}
+
+#[test]
+fn tick_interrupts() {
+    use super::opcodes::{LDA_IMMEDIATE, NOP};
+    use crate::cpu::Executed;
+
+    let mut cpu = bus_cycles_cpu(&[LDA_IMMEDIATE, 0x01, NOP]);
+    cpu.bus.write_u16(0xfffa, 0x9000);
+
+    // An NMI raised partway through an instruction waits for it to finish.
+    assert!(cpu.tick().unwrap().step.is_none());
+    cpu.set_nmi(true);
+    let step = cpu.tick().unwrap().step.unwrap();
+    assert!(matches!(
+        step.executed,
+        Executed::Instruction(Instruction::Ld { .. })
+    ));
+    assert_eq!(cpu.register_a, 0x01);
+
+    // `step` finishes what `tick` started.
+    assert!(cpu.tick().unwrap().step.is_none());
+    let step = cpu.step().unwrap();
+    assert!(matches!(step.executed, Executed::Nmi));
+    assert_eq!(step.cycles, 7);
+    assert_eq!(cpu.program_counter, 0x9000);
+    assert_eq!(cpu.cycles, 2 + 7);
+}
+
+#[test]
+fn tick_trace_events() {
+    use super::opcodes::{INC_ZERO_PAGE, JSR, LDA_ABSOLUTE_X};
+    use std::{cell::RefCell, rc::Rc};
+
+    let program = [
+        LDA_ABSOLUTE_X,
+        0xff,
+        0x02,
+        INC_ZERO_PAGE,
+        0x10,
+        JSR,
+        0x00,
+        0x90,
+    ];
+    let mut traces = Vec::new();
+    for ticking in [false, true] {
+        let mut cpu = bus_cycles_cpu(&program);
+        cpu.register_x = 0x01;
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let observer_events = events.clone();
+        cpu.set_observer(move |event: &crate::cpu::trace::TraceEvent| {
+            observer_events.borrow_mut().push(format!("{event:?}"))
+        });
+        for _ in 0..3 {
+            if ticking {
+                tick_instruction(&mut cpu).unwrap();
+            } else {
+                cpu.step().unwrap();
+            }
+        }
+        traces.push(events.take());
+    }
+    // Each event is reported once, in the same order.
+    assert_eq!(traces[0], traces[1]);
+}
+
+fn cpu_with_variant(variant: crate::cpu::Variant) -> Cpu {
+    Cpu::with_config(CpuConfig {
+        variant,
+        ..CpuConfig::default()
+    })
+}
+
+#[test]
+fn adc_decimal() {
+    use super::opcodes::{ADC_IMMEDIATE, LDA_IMMEDIATE, SED};
+    use crate::cpu::Variant;
+
+    let program = [SED, LDA_IMMEDIATE, 0x58, ADC_IMMEDIATE, 0x46, 0x00];
+
+    // The NES ignores the Decimal flag.
+    let mut cpu = cpu_with_variant(Variant::Nes2A03);
+    cpu.load_and_run_test(&program).unwrap();
+    assert_eq!(cpu.register_a, 0x9e);
+    assert!(!cpu.status.get(Flag::Carry));
+
+    let mut cpu = cpu_with_variant(Variant::Nmos6502);
+    cpu.load_and_run_test(&program).unwrap();
+    assert_eq!(cpu.register_a, 0x04);
+    assert!(cpu.status.get(Flag::Carry));
+    // Negative and Overflow come from the sum before adjusting it.
+    assert!(cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Overflow));
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = cpu_with_variant(Variant::Nmos6502);
+    cpu.load_and_run_test(&[SED, LDA_IMMEDIATE, 0x99, ADC_IMMEDIATE, 0x01, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_a, 0x00);
+    assert!(cpu.status.get(Flag::Carry));
+    // Zero comes from the binary sum, 0x9a.
+    assert!(!cpu.status.get(Flag::Zero));
+
+    let mut cpu = cpu_with_variant(Variant::Cmos65C02);
+    cpu.load_and_run_test(&[SED, LDA_IMMEDIATE, 0x99, ADC_IMMEDIATE, 0x01, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_a, 0x00);
+    assert!(cpu.status.get(Flag::Carry));
+    assert!(cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Negative));
+    // The reset, the instructions and the fetch of the BRK.
+    assert_eq!(cpu.cycles, 7 + 2 + 2 + 3 + 1);
+}
+
+#[test]
+fn sbc_decimal() {
+    use super::opcodes::{LDA_IMMEDIATE, SBC_IMMEDIATE, SEC, SED};
+    use crate::cpu::Variant;
+
+    let mut cpu = cpu_with_variant(Variant::Nmos6502);
+    cpu.load_and_run_test(&[SED, SEC, LDA_IMMEDIATE, 0x46, SBC_IMMEDIATE, 0x12, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_a, 0x34);
+    assert!(cpu.status.get(Flag::Carry));
+
+    for variant in [Variant::Nmos6502, Variant::Cmos65C02] {
+        let mut cpu = cpu_with_variant(variant);
+        cpu.load_and_run_test(&[SED, SEC, LDA_IMMEDIATE, 0x00, SBC_IMMEDIATE, 0x01, 0x00])
+            .unwrap();
+        assert_eq!(cpu.register_a, 0x99);
+        assert!(!cpu.status.get(Flag::Carry));
+        assert!(cpu.status.get(Flag::Negative));
+    }
+
+    let mut cpu = cpu_with_variant(Variant::Nes2A03);
+    cpu.load_and_run_test(&[SED, SEC, LDA_IMMEDIATE, 0x00, SBC_IMMEDIATE, 0x01, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_a, 0xff);
+}
+
+#[test]
+fn cmos_decoding() {
+    use super::opcodes::{BRA, LAX_ZERO_PAGE, LDA_ZERO_PAGE_INDIRECT, NOP_IMMEDIATE_80};
+    use crate::cpu::Variant;
+
+    let config = CpuConfig {
+        variant: Variant::Cmos65C02,
+        ..CpuConfig::default()
+    };
+    let mut memory = Memory::new();
+    memory
+        .load(
+            0x8000,
+            &[BRA, 0x02, LDA_ZERO_PAGE_INDIRECT, 0x10, LAX_ZERO_PAGE],
+        )
+        .unwrap();
+    assert!(matches!(
+        Instruction::get_instruction(&memory, &0x8000, &config),
+        Ok((
+            Instruction::Bra {
+                addressing_mode: AM::Relative { offset: 0x02 }
+            },
+            0x8002
+        ))
+    ));
+    assert!(matches!(
+        Instruction::get_instruction(&memory, &0x8002, &config),
+        Ok((
+            Instruction::Ld {
+                destination: Register::A,
+                addressing_mode: LdAddressingMode::LdAddressAddressingMode {
+                    mode: LdAddressAddressingMode::ZeroPageIndirect {
+                        mode: AM::ZeroPageIndirect { address: 0x10 }
+                    }
+                }
+            },
+            0x8004
+        ))
+    ));
+    // The NMOS unofficial opcodes are NOPs on the 65C02.
+    assert!(matches!(
+        Instruction::get_instruction(&memory, &0x8004, &config),
+        Ok((Instruction::SingleCycleNop, 0x8005))
+    ));
+
+    // The NMOS chips keep their unofficial NOP.
+    assert!(matches!(
+        get_instruction(&[NOP_IMMEDIATE_80, 0x02]).unwrap(),
+        (Instruction::Ign { .. }, 0x8002)
+    ));
+}
+
+#[test]
+fn bra() {
+    use super::opcodes::{BRA, LDA_IMMEDIATE};
+    use crate::cpu::Variant;
+
+    let mut cpu = cpu_with_variant(Variant::Cmos65C02);
+    cpu.load_and_run_test(&[BRA, 0x02, LDA_IMMEDIATE, 0x01, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_a, 0x00);
+    assert_eq!(cpu.program_counter, 0x8004);
+    assert_eq!(cpu.cycles, 7 + 3 + 1);
+}
+
+#[test]
+fn phx_plx_phy_ply() {
+    use super::opcodes::{LDX_IMMEDIATE, LDY_IMMEDIATE, PHX, PHY, PLX, PLY};
+    use crate::cpu::Variant;
+
+    let mut cpu = cpu_with_variant(Variant::Cmos65C02);
+    cpu.load_and_run_test(&[
+        LDX_IMMEDIATE,
+        0x12,
+        LDY_IMMEDIATE,
+        0x34,
+        PHX,
+        PHY,
+        PLX,
+        PLY,
+        0x00,
+    ])
+    .unwrap();
+    assert_eq!(cpu.register_x, 0x34);
+    assert_eq!(cpu.register_y, 0x12);
+    assert_eq!(cpu.stack_pointer, 0xfd);
+}
+
+#[test]
+fn stz() {
+    use super::opcodes::{LDX_IMMEDIATE, STZ_ABSOLUTE_X, STZ_ZERO_PAGE};
+    use crate::cpu::Variant;
+
+    let mut cpu = cpu_with_variant(Variant::Cmos65C02);
+    cpu.bus.write(0x0010, 0xff);
+    cpu.bus.write(0x0201, 0xff);
+    cpu.load_and_run_test(&[
+        STZ_ZERO_PAGE,
+        0x10,
+        LDX_IMMEDIATE,
+        0x01,
+        STZ_ABSOLUTE_X,
+        0x00,
+        0x02,
+        0x00,
+    ])
+    .unwrap();
+    assert_eq!(cpu.bus.read(0x0010), 0x00);
+    assert_eq!(cpu.bus.read(0x0201), 0x00);
+}
+
+#[test]
+fn trb_tsb() {
+    use super::opcodes::{LDA_IMMEDIATE, TRB_ZERO_PAGE, TSB_ABSOLUTE};
+    use crate::cpu::Variant;
+
+    let mut cpu = cpu_with_variant(Variant::Cmos65C02);
+    cpu.bus.write(0x0010, 0b1100_1100);
+    cpu.bus.write(0x0200, 0b0000_0011);
+    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0b0000_1111, TRB_ZERO_PAGE, 0x10, 0x00])
+        .unwrap();
+    assert_eq!(cpu.bus.read(0x0010), 0b1100_0000);
+    assert!(!cpu.status.get(Flag::Zero));
+
+    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0b0011_0000, TSB_ABSOLUTE, 0x00, 0x02, 0x00])
+        .unwrap();
+    assert_eq!(cpu.bus.read(0x0200), 0b0011_0011);
+    assert!(cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn zero_page_indirect() {
+    use super::opcodes::{LDA_ZERO_PAGE_INDIRECT, STA_ZERO_PAGE_INDIRECT};
+    use crate::cpu::Variant;
+
+    let mut cpu = cpu_with_variant(Variant::Cmos65C02);
+    cpu.bus.write_u16(0x0010, 0x0300);
+    cpu.bus.write_u16(0x0020, 0x0400);
+    cpu.bus.write(0x0300, 0x42);
+    cpu.load_and_run_test(&[
+        LDA_ZERO_PAGE_INDIRECT,
+        0x10,
+        STA_ZERO_PAGE_INDIRECT,
+        0x20,
+        0x00,
+    ])
+    .unwrap();
+    assert_eq!(cpu.register_a, 0x42);
+    assert_eq!(cpu.bus.read(0x0400), 0x42);
+}
+
+#[test]
+fn jmp_indirect_cmos() {
+    use super::opcodes::JMP_INDIRECT;
+    use crate::cpu::Variant;
+
+    let mut cpu = cpu_with_variant(Variant::Cmos65C02);
+    cpu.load(&[JMP_INDIRECT, 0xff, 0x02]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.bus.write(0x02ff, 0x34);
+    cpu.bus.write(0x0200, 0x56);
+    cpu.bus.write(0x0300, 0x12);
+    assert_eq!(cpu.step().unwrap().cycles, 6);
+    assert_eq!(cpu.program_counter, 0x1234);
+}
+
+#[test]
+fn cycles_shift_absolute_x_cmos() {
+    use super::opcodes::{ASL_ABSOLUTE_X, INC_ABSOLUTE_X, ROR_ABSOLUTE_X};
+    use crate::cpu::Variant;
+
+    let program = [
+        ASL_ABSOLUTE_X,
+        0x00,
+        0x02,
+        ROR_ABSOLUTE_X,
+        0xff,
+        0x02,
+        INC_ABSOLUTE_X,
+        0x00,
+        0x02,
+    ];
+
+    let mut cpu = cpu_with_variant(Variant::Cmos65C02);
+    cpu.load(&program).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x01;
+    assert_eq!(cpu.step().unwrap().cycles, 6);
+    // Crossing a page costs the cycle back.
+    assert_eq!(cpu.step().unwrap().cycles, 7);
+    // Other read-modify-writes still take the long path.
+    assert_eq!(cpu.step().unwrap().cycles, 7);
+
+    let mut cpu = cpu_with_variant(Variant::Nmos6502);
+    cpu.load(&program).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x01;
+    assert_eq!(cpu.step().unwrap().cycles, 7);
+    assert_eq!(cpu.step().unwrap().cycles, 7);
+    assert_eq!(cpu.step().unwrap().cycles, 7);
+}
+
+#[test]
+fn cmos_every_opcode_decodes() {
+    use crate::cpu::Variant;
+
+    let config = CpuConfig {
+        variant: Variant::Cmos65C02,
+        ..CpuConfig::default()
+    };
+    let mut memory = Memory::new();
+    for code in 0..=0xff {
+        memory.write(0x8000, code);
+        assert!(
+            Instruction::get_instruction(&memory, &0x8000, &config).is_ok(),
+            "opcode {code:#04x}"
+        );
+    }
+}
+
+#[test]
+fn inc_dec_accumulator_cmos() {
+    use super::opcodes::{DEC_ACCUMULATOR, INC_ACCUMULATOR, LDA_IMMEDIATE};
+    use crate::cpu::Variant;
+
+    let mut cpu = cpu_with_variant(Variant::Cmos65C02);
+    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0xff, INC_ACCUMULATOR, 0x00])
+        .unwrap();
+    assert_eq!(cpu.register_a, 0x00);
+    assert!(cpu.status.get(Flag::Zero));
+
+    let mut cpu = cpu_with_variant(Variant::Cmos65C02);
+    cpu.load_and_run_test(&[DEC_ACCUMULATOR, 0x00]).unwrap();
+    assert_eq!(cpu.register_a, 0xff);
+    assert!(cpu.status.get(Flag::Negative));
+    assert_eq!(cpu.cycles, 7 + 2 + 1);
+}
+
+#[test]
+fn bit_cmos() {
+    use super::opcodes::{BIT_ABSOLUTE_X, BIT_IMMEDIATE, BIT_ZERO_PAGE_X, LDA_IMMEDIATE};
+    use crate::cpu::Variant;
+
+    // The immediate form leaves Negative and Overflow alone.
+    let mut cpu = cpu_with_variant(Variant::Cmos65C02);
+    cpu.load_and_run_test(&[LDA_IMMEDIATE, 0x01, BIT_IMMEDIATE, 0xc0, 0x00])
+        .unwrap();
+    assert!(cpu.status.get(Flag::Zero));
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(!cpu.status.get(Flag::Overflow));
+
+    let mut cpu = cpu_with_variant(Variant::Cmos65C02);
+    cpu.load(&[BIT_ZERO_PAGE_X, 0x10, BIT_ABSOLUTE_X, 0xff, 0x02])
+        .unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_a = 0x01;
+    cpu.register_x = 0x01;
+    cpu.bus.write(0x0011, 0x80);
+    cpu.bus.write(0x0300, 0x41);
+    assert_eq!(cpu.step().unwrap().cycles, 4);
+    assert!(cpu.status.get(Flag::Negative));
     assert!(cpu.status.get(Flag::Zero));
+    assert_eq!(cpu.step().unwrap().cycles, 5);
+    assert!(!cpu.status.get(Flag::Negative));
+    assert!(cpu.status.get(Flag::Overflow));
+    assert!(!cpu.status.get(Flag::Zero));
+}
+
+#[test]
+fn jmp_absolute_x_indirect() {
+    use super::opcodes::JMP_ABSOLUTE_X_INDIRECT;
+    use crate::cpu::Variant;
+
+    let mut cpu = cpu_with_variant(Variant::Cmos65C02);
+    cpu.load(&[JMP_ABSOLUTE_X_INDIRECT, 0xff, 0x02]).unwrap();
+    cpu.reset().unwrap();
+    cpu.program_counter = 0x8000;
+    cpu.register_x = 0x02;
+    cpu.bus.write_u16(0x0301, 0x1234);
+    assert_eq!(cpu.step().unwrap().cycles, 6);
+    assert_eq!(cpu.program_counter, 0x1234);
+}
+
+#[test]
+fn cmos_nops() {
+    use crate::cpu::Variant;
+
+    // (opcode, length, cycles)
+    let nops = [
+        (0x02, 2, 2),
+        (0xe2, 2, 2),
+        (0x03, 1, 1),
+        (0x07, 1, 1),
+        (0xcb, 1, 1),
+        (0xff, 1, 1),
+        (0x44, 2, 3),
+        (0xf4, 2, 4),
+        (0x5c, 3, 8),
+        (0xdc, 3, 4),
+    ];
+    for (code, length, cycles) in nops {
+        let mut cpu = cpu_with_variant(Variant::Cmos65C02);
+        cpu.load(&[code, 0xff, 0x02]).unwrap();
+        cpu.reset().unwrap();
+        cpu.program_counter = 0x8000;
+        cpu.register_x = 0x01;
+        let step = cpu.step().unwrap();
+        assert_eq!(step.cycles, cycles, "opcode {code:#04x}");
+        assert_eq!(cpu.program_counter, 0x8000 + length, "opcode {code:#04x}");
+        assert_eq!(cpu.register_a, 0x00);
+        assert_eq!(cpu.register_x, 0x01);
+    }
 }
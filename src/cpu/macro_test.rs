@@ -2,10 +2,11 @@ use derives::AddressingEnum;
 
 use super::instruction::addressing_mode as AM;
 
-use super::instruction::addressing_mode::{IntoAddress, IntoValue};
+use super::instruction::addressing_mode::{IntoAddress, IntoTarget, IntoValue};
 
 fn assert_into_address<T: IntoAddress>(_: T) {}
 fn assert_into_value<T: IntoValue>(_: T) {}
+fn assert_into_target<T: IntoTarget>(_: T) {}
 fn assert_debug<T: std::fmt::Debug>(_: T) {}
 fn assert_type<T>(_: T) {}
 
@@ -44,6 +45,7 @@ fn accumulator_address() {
     let a: Option<BarAddressingMode> = None;
     if let Some(a) = a {
         assert_debug(a);
+        assert_into_target(a);
         match a {
             BarAddressingMode::Accumulator { mode } => {
                 assert_type::<AM::Accumulator>(mode);
@@ -160,6 +162,7 @@ fn accumulator_addresses() {
     let a: Option<BarAddressingMode> = None;
     if let Some(a) = a {
         assert_debug(a);
+        assert_into_target(a);
         match a {
             BarAddressingMode::Accumulator { mode } => {
                 assert_type::<AM::Accumulator>(mode);
@@ -1,11 +1,74 @@
+use super::bus::Bus;
 use thiserror::Error;
 
+#[cfg(test)]
+mod tests;
+
 #[derive(Error, Debug)]
 pub enum CpuMemoryError {
     #[error("memory address ({address}) out of bounds")]
     IndexOutOfBounds { address: u16 },
 }
 
+/// Contents RAM is given at power-on. Real consoles come up with a mix
+/// that varies between units, so it's worth testing a program under more
+/// than one of these.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RamInit {
+    #[default]
+    Zero,
+    Fill(u8),
+    /// Four bytes of $00 followed by four of $FF, over and over, as many
+    /// consoles are found.
+    Alternating,
+    /// Pseudo-random bytes, always the same for the same seed.
+    Random {
+        seed: u64,
+    },
+}
+
+impl RamInit {
+    /// Random contents with a seed taken from the clock. The seed is kept
+    /// in the result, so the run can be repeated.
+    pub fn random() -> RamInit {
+        let seed = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_nanos() as u64);
+        RamInit::Random { seed }
+    }
+
+    pub fn fill(self, ram: &mut [u8]) {
+        match self {
+            RamInit::Zero => ram.fill(0),
+            RamInit::Fill(value) => ram.fill(value),
+            RamInit::Alternating => {
+                for (index, byte) in ram.iter_mut().enumerate() {
+                    *byte = if index & 0b100 == 0 { 0x00 } else { 0xff };
+                }
+            }
+            RamInit::Random { seed } => {
+                let mut state = seed;
+                for chunk in ram.chunks_mut(8) {
+                    let bytes = split_mix_64(&mut state).to_le_bytes();
+                    chunk.copy_from_slice(&bytes[..chunk.len()]);
+                }
+            }
+        }
+    }
+}
+
+/// SplitMix64, which is plenty for filling RAM with noise.
+fn split_mix_64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Everything below where programs are loaded is treated as RAM.
+const RAM_END: usize = 0x8000;
+
 pub struct Memory {
     memory: [u8; 0x10000],
 }
@@ -16,6 +79,12 @@ impl std::fmt::Debug for Memory {
     }
 }
 
+impl Default for Memory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Memory {
     pub fn new() -> Memory {
         Memory {
@@ -23,9 +92,14 @@ impl Memory {
         }
     }
 
-    pub fn iter(&self) -> std::slice::Iter<u8> {
+    pub fn iter(&self) -> std::slice::Iter<'_, u8> {
         self.memory.iter()
     }
+
+    /// Initializes RAM as it would be found at power-on.
+    pub fn power_on(&mut self, init: RamInit) {
+        init.fill(&mut self.memory[..RAM_END]);
+    }
 }
 
 impl Memory {
@@ -39,11 +113,11 @@ impl Memory {
         (hi << 8) | lo
     }
 
-    pub fn write(&mut self, address: u16, data: u8) -> () {
+    pub fn write(&mut self, address: u16, data: u8) {
         self.memory[address as usize] = data;
     }
 
-    pub fn write_u16(&mut self, address: u16, data: u16) -> () {
+    pub fn write_u16(&mut self, address: u16, data: u16) {
         let hi = (data >> 8) as u8;
         let lo = (data & 0xff) as u8;
         self.write(address, lo);
@@ -61,3 +135,22 @@ impl Memory {
         Ok(())
     }
 }
+
+/// A flat 64 KiB address space, with no devices mapped in.
+impl Bus for Memory {
+    fn read(&mut self, address: u16) -> u8 {
+        Memory::read(self, address)
+    }
+
+    fn write(&mut self, address: u16, value: u8) {
+        Memory::write(self, address, value)
+    }
+
+    fn peek(&self, address: u16) -> u8 {
+        Memory::read(self, address)
+    }
+
+    fn power_on(&mut self, init: RamInit) {
+        Memory::power_on(self, init)
+    }
+}
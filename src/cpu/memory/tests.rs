@@ -29,3 +29,45 @@ fn write() {
     memory.write_u16(0x8002, 0x0403);
     assert_eq!(memory.memory[0x8000..0x8004], [0x01, 0x02, 0x03, 0x04]);
 }
+
+#[test]
+fn power_on() {
+    let mut memory = Memory::new();
+    memory.load(0x8000, &[0x01, 0x02]).unwrap();
+    memory.power_on(RamInit::Fill(0xff));
+    assert_eq!(memory.memory[..0x8000], [0xff; 0x8000]);
+    assert_eq!(memory.memory[0x8000..0x8002], [0x01, 0x02]);
+
+    memory.power_on(RamInit::Zero);
+    assert_eq!(memory.memory[..0x8000], [0; 0x8000]);
+}
+
+#[test]
+fn power_on_alternating() {
+    let mut memory = Memory::new();
+    memory.power_on(RamInit::Alternating);
+    assert_eq!(
+        memory.memory[..0x10],
+        [
+            0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff,
+            0xff, 0xff
+        ]
+    );
+    assert_eq!(memory.memory[0x7ffc..0x8000], [0xff; 4]);
+}
+
+#[test]
+fn power_on_random() {
+    let mut memory = Memory::new();
+    memory.power_on(RamInit::Random { seed: 1 });
+    let first: Vec<u8> = memory.memory[..0x8000].to_vec();
+    assert!(first.iter().any(|&byte| byte != first[0]));
+
+    memory.power_on(RamInit::Random { seed: 2 });
+    assert_ne!(memory.memory[..0x8000], first[..]);
+
+    memory.power_on(RamInit::Random { seed: 1 });
+    assert_eq!(memory.memory[..0x8000], first[..]);
+
+    assert!(matches!(RamInit::random(), RamInit::Random { .. }));
+}
@@ -1,56 +1,227 @@
+pub mod bus;
 pub mod instruction;
 pub mod memory;
 pub mod status;
+pub mod tick;
+pub mod trace;
 
 #[cfg(test)]
 mod macro_test;
+#[cfg(test)]
+mod single_step_tests;
 
 use instruction::*;
 use thiserror::Error;
 
 use crate::cpu::{
-    instruction::addressing_mode::{IntoAddress, IntoValue},
+    instruction::addressing_mode::{IntoAddress, IntoTarget, IntoValue, Target},
     status::Flag,
 };
 
-use self::{memory::Memory, status::Status};
+use self::{
+    bus::Bus,
+    memory::{Memory, RamInit},
+    status::Status,
+    tick::{Replay, ReplayedAccess},
+    trace::{Observer, ObserverSlot, TraceEvent},
+};
 
 #[derive(Debug)]
-pub struct Cpu {
+pub struct Cpu<B = Memory> {
     pub register_a: u8,
     pub register_x: u8,
     pub register_y: u8,
     pub stack_pointer: u8,
     pub status: Status,
     pub program_counter: u16,
-    memory: Memory,
+    /// Cycles elapsed since the CPU was created.
+    pub cycles: u64,
+    pub config: CpuConfig,
+    bus: B,
+    halt_reason: Option<HaltReason>,
+    irq_line: bool,
+    nmi_line: bool,
+    nmi_pending: bool,
+    /// Instruction `tick` is partway through.
+    pending_tick: Option<Replay>,
+    /// Set while `tick` runs the pending instruction again.
+    replay: Option<Replay>,
+    observer: ObserverSlot,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BusAccess {
+    Read,
+    Write,
+}
+
+/// A single cycle of CPU bus activity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BusCycle {
+    pub address: u16,
+    pub value: u8,
+    pub access: BusAccess,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CpuConfig {
+    /// Decode NOP variants and the stable unofficial opcodes instead of
+    /// rejecting them as invalid.
+    pub unofficial_opcodes: bool,
+    /// Halt on a jump or branch to itself, which can only be left through
+    /// an interrupt. Meant for headless test ROMs that end in such a loop;
+    /// NES games often wait for NMI this way, so it's off by default.
+    pub detect_self_loops: bool,
+    /// Halt before a BRK instead of taking the interrupt. For programs that
+    /// end in BRK, like the ones run with `load_and_run`.
+    pub halt_on_brk: bool,
+    pub variant: Variant,
+    /// What RAM holds when the CPU is created with `with_config`, and after
+    /// every `power_on`.
+    pub ram_init: RamInit,
+}
+
+impl Default for CpuConfig {
+    fn default() -> Self {
+        CpuConfig {
+            unofficial_opcodes: true,
+            detect_self_loops: false,
+            halt_on_brk: false,
+            variant: Variant::default(),
+            ram_init: RamInit::default(),
+        }
+    }
+}
+
+/// The chip being emulated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Variant {
+    /// The NES CPU, an NMOS 6502 with decimal mode disabled.
+    #[default]
+    Nes2A03,
+    /// The original NMOS 6502, with decimal mode.
+    Nmos6502,
+    /// The CMOS 65C02, with decimal mode, its extra instructions and the
+    /// NMOS bugs fixed. The NMOS unofficial opcodes are rejected.
+    Cmos65C02,
+}
+
+impl Variant {
+    pub fn has_decimal_mode(&self) -> bool {
+        !matches!(self, Variant::Nes2A03)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Register {
     X,
     Y,
     A,
+    StackPointer,
+}
+
+impl Default for Cpu {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Cpu {
     pub fn new() -> Self {
+        Self::with_config(CpuConfig::default())
+    }
+
+    pub fn with_config(config: CpuConfig) -> Self {
+        let mut memory = Memory::new();
+        memory.power_on(config.ram_init);
+        Self::with_bus(memory, config)
+    }
+
+    /// Loads `program`, powers on and runs until the CPU reaches a BRK or
+    /// halts otherwise.
+    pub fn load_and_run(&mut self, program: &[u8]) -> Result<HaltReason, CpuError> {
+        self.load(program)?;
+        self.power_on()?;
+        self.run_until_brk()
+    }
+
+    #[cfg(test)]
+    pub fn load_and_run_test(&mut self, program: &[u8]) -> Result<(), CpuError> {
+        self.load(program)?;
+        self.reset()?;
+        self.program_counter = 0x8000;
+        self.run_until_brk()?;
+
+        Ok(())
+    }
+
+    pub fn load(&mut self, program: &[u8]) -> Result<(), CpuError> {
+        self.bus.load(0x8000, program)?;
+
+        Ok(())
+    }
+}
+
+impl<B: Bus> Cpu<B> {
+    /// Takes the bus as is, leaving its RAM alone until `power_on`.
+    pub fn with_bus(bus: B, config: CpuConfig) -> Self {
         Cpu {
             register_a: 0x0,
             register_x: 0x0,
             register_y: 0x0,
-            stack_pointer: 0xff,
-            status: Status::new(),
+            // The state registers are found in at power-on, before the
+            // reset sequence takes SP down to 0xFD.
+            stack_pointer: 0x00,
+            status: Status::from_stack(POWER_ON_STATUS),
             program_counter: 0x0,
-            memory: Memory::new(),
+            cycles: 0,
+            config,
+            bus,
+            halt_reason: None,
+            irq_line: false,
+            nmi_line: false,
+            nmi_pending: false,
+            pending_tick: None,
+            replay: None,
+            observer: ObserverSlot::default(),
         }
     }
 
+    pub fn bus(&self) -> &B {
+        &self.bus
+    }
+
+    pub fn bus_mut(&mut self) -> &mut B {
+        &mut self.bus
+    }
+
     pub fn set_register(&mut self, destination: &Register, value: u8) {
         match destination {
             Register::X => self.register_x = value,
             Register::Y => self.register_y = value,
             Register::A => self.register_a = value,
+            Register::StackPointer => self.stack_pointer = value,
+        }
+    }
+
+    /// Reports every trace event to `observer` from now on, replacing the
+    /// previous one.
+    pub fn set_observer(&mut self, observer: impl Observer + 'static) {
+        self.observer = ObserverSlot(Some(Box::new(observer)));
+    }
+
+    pub fn clear_observer(&mut self) {
+        self.observer = ObserverSlot(None);
+    }
+
+    fn trace(&mut self, event: TraceEvent) {
+        if let Some(replay) = &mut self.replay {
+            if !replay.report_event() {
+                return;
+            }
+        }
+        if let Some(observer) = &mut self.observer.0 {
+            observer.on_event(&event);
         }
     }
 
@@ -59,190 +230,907 @@ impl Cpu {
             Register::X => self.register_x,
             Register::Y => self.register_y,
             Register::A => self.register_a,
+            Register::StackPointer => self.stack_pointer,
         }
     }
 }
 
-impl Cpu {
+impl<B: Bus> Cpu<B> {
+    fn read(&mut self, address: u16) -> u8 {
+        let value = match self.replay.as_mut().map(Replay::next_access) {
+            None => self.bus.read(address),
+            Some(ReplayedAccess::Logged(value)) => value,
+            Some(ReplayedAccess::Live) => {
+                let value = self.bus.read(address);
+                self.log(address, value, BusAccess::Read);
+                value
+            }
+            // Nothing depends on the value, the run is thrown away.
+            Some(ReplayedAccess::Skipped) => return 0,
+        };
+        self.trace(TraceEvent::Read { address, value });
+        value
+    }
+
+    fn write(&mut self, address: u16, value: u8) {
+        match self.replay.as_mut().map(Replay::next_access) {
+            None => self.bus.write(address, value),
+            Some(ReplayedAccess::Logged(_)) => {}
+            Some(ReplayedAccess::Live) => {
+                self.bus.write(address, value);
+                self.log(address, value, BusAccess::Write);
+            }
+            Some(ReplayedAccess::Skipped) => return,
+        }
+        self.trace(TraceEvent::Write { address, value });
+    }
+
+    /// Reads the CPU makes only because it can't leave the bus idle. They
+    /// still reach the bus, as devices react to them.
+    fn dummy_read(&mut self, address: u16) {
+        self.read(address);
+    }
+
+    fn read_u16(&mut self, address: u16) -> u16 {
+        let lo = self.read(address) as u16;
+        let hi = self.read(address.wrapping_add(1)) as u16;
+        (hi << 8) | lo
+    }
+
+    fn log(&mut self, address: u16, value: u8, access: BusAccess) {
+        if let Some(replay) = &mut self.replay {
+            replay.log(address, value, access);
+        }
+    }
+}
+
+impl<B: Bus> Cpu<B> {
     fn set_zero_and_negative(&mut self, register_value: u8) {
         self.status.set(Flag::Zero, register_value == 0);
         self.status.set(Flag::Negative, (register_value as i8) < 0);
     }
 
-    pub fn reset(&mut self) -> Result<(), CpuError> {
+    /// Binary core shared by ADC and SBC. Overflow is set when both operands
+    /// have the same sign and the result's sign differs from it.
+    fn add_with_carry(&mut self, value: u8) {
+        let carry = self.status.get(Flag::Carry) as u16;
+        let sum = self.register_a as u16 + value as u16 + carry;
+        let result = sum as u8;
+
+        let overflow = (self.register_a ^ result) & (value ^ result) & 0b1000_0000 != 0;
+
+        self.register_a = result;
+
+        self.status.set(Flag::Overflow, overflow);
+        self.status.set(Flag::Carry, sum > 0xff);
+        self.set_zero_and_negative(result);
+    }
+
+    fn decimal_mode(&self) -> bool {
+        self.config.variant.has_decimal_mode() && self.status.get(Flag::Decimal)
+    }
+
+    fn adc(&mut self, value: u8) {
+        if self.decimal_mode() {
+            self.add_decimal(value);
+        } else {
+            self.add_with_carry(value);
+        }
+    }
+
+    fn sbc(&mut self, value: u8) {
+        if self.decimal_mode() {
+            self.subtract_decimal(value);
+        } else {
+            // A - M - (1 - C) is the same as A + !M + C.
+            self.add_with_carry(!value);
+        }
+    }
+
+    /// BCD addition. The NMOS 6502 sets Zero from the binary sum, and
+    /// Negative and Overflow before adjusting the high digit. The 65C02 sets
+    /// Zero and Negative from the result and spends an extra cycle, reading
+    /// the next opcode.
+    fn add_decimal(&mut self, value: u8) {
+        let a = self.register_a;
+        let carry = self.status.get(Flag::Carry) as u16;
+        let binary = (a as u16 + value as u16 + carry) as u8;
+
+        let mut lo = (a & 0x0f) as u16 + (value & 0x0f) as u16 + carry;
+        if lo > 0x09 {
+            lo = ((lo + 0x06) & 0x0f) + 0x10;
+        }
+        let mut sum = (a & 0xf0) as u16 + (value & 0xf0) as u16 + lo;
+        let intermediate = sum as u8;
+        if sum > 0x9f {
+            sum += 0x60;
+        }
+        let result = sum as u8;
+
+        self.register_a = result;
+
+        let overflow = (a ^ intermediate) & (value ^ intermediate) & 0b1000_0000 != 0;
+        self.status.set(Flag::Overflow, overflow);
+        self.status.set(Flag::Carry, sum > 0xff);
+        if self.config.variant == Variant::Cmos65C02 {
+            self.cycles += 1;
+            self.dummy_read(self.program_counter);
+            self.set_zero_and_negative(result);
+        } else {
+            self.status.set(Flag::Zero, binary == 0);
+            self.status
+                .set(Flag::Negative, intermediate & 0b1000_0000 != 0);
+        }
+    }
+
+    /// BCD subtraction. Carry and Overflow are those of the binary
+    /// subtraction, and so are Zero and Negative on the NMOS 6502.
+    fn subtract_decimal(&mut self, value: u8) {
+        let a = self.register_a;
+        let carry = self.status.get(Flag::Carry) as i16;
+        let lo = (a & 0x0f) as i16 - (value & 0x0f) as i16 + carry - 1;
+
+        self.add_with_carry(!value);
+
+        let result = if self.config.variant == Variant::Cmos65C02 {
+            let mut result = a as i16 - value as i16 + carry - 1;
+            if result < 0 {
+                result -= 0x60;
+            }
+            if lo < 0 {
+                result -= 0x06;
+            }
+            result as u8
+        } else {
+            let lo = if lo < 0 {
+                ((lo - 0x06) & 0x0f) - 0x10
+            } else {
+                lo
+            };
+            let mut result = (a & 0xf0) as i16 - (value & 0xf0) as i16 + lo;
+            if result < 0 {
+                result -= 0x60;
+            }
+            result as u8
+        };
+
+        self.register_a = result;
+
+        if self.config.variant == Variant::Cmos65C02 {
+            self.cycles += 1;
+            self.dummy_read(self.program_counter);
+            self.set_zero_and_negative(result);
+        }
+    }
+
+    fn compare(&mut self, register: u8, value: u8) {
+        let result = register.wrapping_sub(value);
+        self.set_zero_and_negative(result);
+        self.status.set(Flag::Carry, register >= value);
+    }
+
+    fn asl(&mut self, value: u8) -> u8 {
+        self.status.set(Flag::Carry, value & 0b1000_0000 != 0);
+        value << 1
+    }
+
+    fn lsr(&mut self, value: u8) -> u8 {
+        self.status.set(Flag::Carry, value & 0b0000_0001 != 0);
+        value >> 1
+    }
+
+    fn rol(&mut self, value: u8) -> u8 {
+        let carry = self.status.get(Flag::Carry) as u8;
+        self.status.set(Flag::Carry, value & 0b1000_0000 != 0);
+        (value << 1) | carry
+    }
+
+    fn ror(&mut self, value: u8) -> u8 {
+        let carry = self.status.get(Flag::Carry) as u8;
+        self.status.set(Flag::Carry, value & 0b0000_0001 != 0);
+        (value >> 1) | (carry << 7)
+    }
+
+    /// Reads the operand, applies `operation` to it, writes the result back
+    /// and sets Zero and Negative from it. Returns the written value.
+    fn read_modify_write(
+        &mut self,
+        target: Target,
+        operation: impl FnOnce(&mut Self, u8) -> u8,
+    ) -> u8 {
+        let value = match target {
+            Target::Accumulator => self.register_a,
+            Target::Memory(address) => self.read(address),
+        };
+        self.trace(TraceEvent::Operand { value });
+
+        let modified = operation(self, value);
+        self.set_zero_and_negative(modified);
+
+        match target {
+            Target::Accumulator => self.register_a = modified,
+            Target::Memory(address) => {
+                // The NMOS chips write the original value back while it is
+                // modified, the 65C02 reads it again instead.
+                if self.config.variant == Variant::Cmos65C02 {
+                    self.dummy_read(address);
+                } else {
+                    self.write(address, value);
+                }
+                self.write(address, modified);
+            }
+        }
+
+        modified
+    }
+
+    /// Puts the CPU and RAM in the state they are found when the machine is
+    /// turned on, and runs the reset sequence.
+    pub fn power_on(&mut self) -> Result<(), CpuError> {
+        self.bus.power_on(self.config.ram_init);
+
         self.register_a = 0;
         self.register_x = 0;
         self.register_y = 0;
-        self.stack_pointer = 0xff;
-        self.status = Status::new();
+        self.stack_pointer = 0x00;
+        self.status = Status::from_stack(POWER_ON_STATUS);
+        self.cycles = 0;
 
-        self.program_counter = self.memory.read_u16(0xFFFC);
-        Ok(())
+        self.reset()
     }
 
-    pub fn load_and_run(&mut self, program: &[u8]) -> color_eyre::Result<()> {
-        self.load(program)?;
-        self.reset()?;
-        self.run()?;
+    /// Pulls the reset line. It runs an interrupt sequence whose pushes
+    /// are turned into reads, so the registers are kept and only the stack
+    /// pointer moves.
+    pub fn reset(&mut self) -> Result<(), CpuError> {
+        self.stack_pointer = self.stack_pointer.wrapping_sub(3);
+        self.status.set(Flag::InterruptDisable, true);
+        self.halt_reason = None;
+        self.nmi_pending = false;
+        self.cycles += RESET_CYCLES;
 
+        let lo = self.bus.read(RESET_VECTOR) as u16;
+        let hi = self.bus.read(RESET_VECTOR + 1) as u16;
+        self.program_counter = (hi << 8) | lo;
         Ok(())
     }
+}
 
-    #[cfg(test)]
-    pub fn load_and_run_test(&mut self, program: &[u8]) -> color_eyre::Result<()> {
-        self.load(program)?;
-        self.reset()?;
-        self.program_counter = 0x8000;
-        self.run()?;
+const STACK_PAGE: u16 = 0x0100;
 
-        Ok(())
+const NMI_VECTOR: u16 = 0xFFFA;
+const RESET_VECTOR: u16 = 0xFFFC;
+const IRQ_VECTOR: u16 = 0xFFFE;
+
+const INTERRUPT_CYCLES: u64 = 7;
+const RESET_CYCLES: u64 = 7;
+
+const POWER_ON_STATUS: u8 = 0x34;
+
+impl<B: Bus> Cpu<B> {
+    pub fn stack_push(&mut self, value: u8) {
+        self.write(STACK_PAGE | self.stack_pointer as u16, value);
+        self.stack_pointer = self.stack_pointer.wrapping_sub(1);
     }
 
-    pub fn load(&mut self, program: &[u8]) -> Result<(), CpuError> {
-        self.memory.load(0x8000, &program)?;
+    pub fn stack_pull(&mut self) -> u8 {
+        self.stack_pointer = self.stack_pointer.wrapping_add(1);
+        self.read(STACK_PAGE | self.stack_pointer as u16)
+    }
 
-        Ok(())
+    /// Read of the current top of the stack made while the stack pointer is
+    /// incremented or a return address pushed.
+    fn stack_dummy_read(&mut self) {
+        self.dummy_read(STACK_PAGE | self.stack_pointer as u16);
+    }
+
+    pub fn stack_push_u16(&mut self, value: u16) {
+        self.stack_push((value >> 8) as u8);
+        self.stack_push((value & 0xff) as u8);
+    }
+
+    pub fn stack_pull_u16(&mut self) -> u16 {
+        let lo = self.stack_pull() as u16;
+        let hi = self.stack_pull() as u16;
+        (hi << 8) | lo
     }
 }
 
+impl<B: Bus> Cpu<B> {
+    /// Drives the IRQ line. It is level triggered, so it keeps interrupting
+    /// for as long as it is asserted and InterruptDisable is clear.
+    pub fn set_irq(&mut self, asserted: bool) {
+        self.irq_line = asserted;
+    }
+
+    /// Drives the NMI line. It is edge triggered, so only the transition to
+    /// asserted requests an interrupt.
+    pub fn set_nmi(&mut self, asserted: bool) {
+        if asserted && !self.nmi_line {
+            self.nmi_pending = true;
+        }
+        self.nmi_line = asserted;
+    }
+
+    /// Stops `run` before the next instruction.
+    pub fn halt(&mut self) {
+        self.halt_reason = Some(HaltReason::Requested);
+    }
+
+    /// Lets a halted CPU run again. After a JAM it's only halted again, as
+    /// the program counter is still on the opcode.
+    pub fn resume(&mut self) {
+        self.halt_reason = None;
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.halt_reason.is_some()
+    }
+
+    pub fn halt_reason(&self) -> Option<HaltReason> {
+        self.halt_reason
+    }
+
+    fn interrupt(&mut self, vector: u16, break_flag: bool) {
+        self.stack_push_u16(self.program_counter);
+        self.stack_push(self.status.to_stack(break_flag));
+        self.status.set(Flag::InterruptDisable, true);
+        if self.config.variant == Variant::Cmos65C02 {
+            self.status.set(Flag::Decimal, false);
+        }
+        self.program_counter = self.read_u16(vector);
+    }
+
+    /// Services a pending interrupt, if any. NMI takes priority over IRQ.
+    fn poll_interrupts(&mut self) -> Option<Executed> {
+        if self.nmi_pending {
+            self.nmi_pending = false;
+            self.interrupt_dummy_reads();
+            self.interrupt(NMI_VECTOR, false);
+            self.cycles += INTERRUPT_CYCLES;
+            Some(Executed::Nmi)
+        } else if self.irq_line && !self.status.get(Flag::InterruptDisable) {
+            self.interrupt_dummy_reads();
+            self.interrupt(IRQ_VECTOR, false);
+            self.cycles += INTERRUPT_CYCLES;
+            Some(Executed::Irq)
+        } else {
+            None
+        }
+    }
+
+    /// Hardware interrupts fetch the next opcode twice and throw it away.
+    fn interrupt_dummy_reads(&mut self) {
+        self.dummy_read(self.program_counter);
+        self.dummy_read(self.program_counter);
+    }
+}
+
+/// What a single call to `Cpu::step` did.
+#[derive(Debug, Clone, Copy)]
+pub struct Step {
+    pub executed: Executed,
+    pub cycles: u8,
+    pub program_counter_before: u16,
+    pub program_counter_after: u16,
+    /// Set when the CPU is halted after the step, be it by this step or an
+    /// earlier one.
+    pub halt_reason: Option<HaltReason>,
+}
+
+/// Why the CPU stopped running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HaltReason {
+    /// `Cpu::halt` was called.
+    Requested,
+    /// A JAM opcode at `address` locked the CPU up.
+    Jam { address: u16 },
+    /// The instruction at `address` jumps or branches to itself.
+    SelfLoop { address: u16 },
+    /// There is a BRK at `address`, and BRK was set to halt the CPU.
+    Break { address: u16 },
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Executed {
+    Instruction(Instruction),
+    Nmi,
+    Irq,
+    /// Nothing, as the CPU is halted.
+    Halted,
+}
+
 #[derive(Debug, Error)]
 pub enum CpuError {
     #[error(transparent)]
     CpuMemoryError(#[from] memory::CpuMemoryError),
+    /// The instruction at `program_counter` couldn't be executed. `bytes`
+    /// holds as many bytes as the longest instruction takes.
+    #[error("{source} at {program_counter:#06x} (bytes {bytes:02x?}), {registers}")]
+    Instruction {
+        source: InstructionError,
+        program_counter: u16,
+        bytes: [u8; 3],
+        registers: Registers,
+    },
 }
 
-impl Cpu {
-    pub fn run(&mut self) -> color_eyre::Result<()> {
+/// Copy of the CPU registers, taken when an error happens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Registers {
+    pub register_a: u8,
+    pub register_x: u8,
+    pub register_y: u8,
+    pub stack_pointer: u8,
+    /// Status as it would be pushed by an interrupt.
+    pub status: u8,
+    pub program_counter: u16,
+    pub cycles: u64,
+}
+
+impl std::fmt::Display for Registers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "A:{:02X} X:{:02X} Y:{:02X} P:{:02X} SP:{:02X} PC:{:04X} CYC:{}",
+            self.register_a,
+            self.register_x,
+            self.register_y,
+            self.status,
+            self.stack_pointer,
+            self.program_counter,
+            self.cycles
+        )
+    }
+}
+
+impl<B: Bus> Cpu<B> {
+    pub fn registers(&self) -> Registers {
+        Registers {
+            register_a: self.register_a,
+            register_x: self.register_x,
+            register_y: self.register_y,
+            stack_pointer: self.stack_pointer,
+            status: self.status.to_stack(false),
+            program_counter: self.program_counter,
+            cycles: self.cycles,
+        }
+    }
+
+    fn instruction_error(&self, source: InstructionError) -> CpuError {
+        let program_counter = self.program_counter;
+        let bytes = [0, 1, 2].map(|offset| self.bus.peek(program_counter.wrapping_add(offset)));
+        CpuError::Instruction {
+            source,
+            program_counter,
+            bytes,
+            registers: self.registers(),
+        }
+    }
+}
+
+impl<B: Bus> Cpu<B> {
+    /// Runs until the CPU is halted, and returns why.
+    pub fn run(&mut self) -> Result<HaltReason, CpuError> {
         loop {
-            let (instruction, program_counter) =
-                Instruction::get_instruction(&self.memory, &self.program_counter)?;
-            self.program_counter = program_counter;
-
-            use Instruction::*;
-
-            match instruction {
-                Adc { addressing_mode } => {
-                    let value = addressing_mode.into_value(self);
-                    let carry = self.status.get(Flag::Carry) as u8;
-
-                    let carry_flag = {
-                        let (value, first_carry) = self.register_a.overflowing_add(value);
-                        let (_, second_carry) = value.overflowing_add(carry);
-                        first_carry || second_carry
-                    };
-
-                    let (value, overflow_flag) = {
-                        let (value, first_overflow) =
-                            (self.register_a as i8).overflowing_add(value as i8);
-                        let (value, second_overflow) = value.overflowing_add(carry as i8);
-                        (value as u8, first_overflow || second_overflow)
-                    };
-
-                    self.register_a = value;
-
-                    self.status.set(Flag::Overflow, overflow_flag);
-                    self.status.set(Flag::Carry, carry_flag);
-                    self.set_zero_and_negative(value);
-                }
-                And { addressing_mode } => {
-                    let value = addressing_mode.into_value(self);
-                    let value = self.register_a & value;
-                    self.register_a = value;
-                    self.set_zero_and_negative(value);
-                }
-                Asl { addressing_mode } => {
-                    let value = match addressing_mode {
-                        AslAddressingMode::Accumulator { mode: _ } => self.register_a,
-                        AslAddressingMode::AslAddressAddressingMode { mode } => {
-                            mode.into_value(self)
-                        }
-                    };
-
-                    self.status.set(Flag::Carry, (value as i8) < 0);
-                    let value = value.wrapping_shl(1);
-                    self.set_zero_and_negative(value);
+            if let Some(halt_reason) = self.halt_reason {
+                return Ok(halt_reason);
+            }
+            self.step()?;
+        }
+    }
 
-                    match addressing_mode {
-                        AslAddressingMode::Accumulator { mode: _ } => {
-                            self.register_a = value;
-                        }
-                        AslAddressingMode::AslAddressAddressingMode { mode } => {
-                            let address = mode.into_address(self);
-                            self.memory.write(address, value);
-                        }
-                    };
-                }
-                Branch {
-                    addressing_mode,
-                    flag,
-                    branch_if,
-                } => {
-                    if self.status.get(flag) == branch_if {
-                        let new_address = addressing_mode.into_address(self);
-                        self.program_counter = new_address;
-                    }
-                }
-                Bit { addressing_mode } => {
-                    let value = addressing_mode.into_value(self);
-                    self.status.set(Flag::Negative, value & 0b1000_0000 != 0);
-                    self.status.set(Flag::Overflow, value & 0b0100_0000 != 0);
-                    self.status.set(Flag::Zero, self.register_a & value == 0);
-                }
-                Break => break,
-                Clear { flag } => self.status.set(flag, false),
-                Cmp { addressing_mode } => {
-                    let value = addressing_mode.into_value(self);
-                    dbg!(value);
-                    let result = self.register_a.wrapping_sub(value);
-                    self.set_zero_and_negative(result);
-                    self.status.set(Flag::Carry, self.register_a >= value);
-                }
-                Cpx { addressing_mode } => {
-                    let value = addressing_mode.into_value(self);
-                    dbg!(value);
-                    let result = self.register_x.wrapping_sub(value);
-                    self.set_zero_and_negative(result);
-                    self.status.set(Flag::Carry, self.register_x >= value);
-                }
-                Cpy { addressing_mode } => {
-                    let value = addressing_mode.into_value(self);
-                    dbg!(value);
-                    let result = self.register_y.wrapping_sub(value);
-                    self.set_zero_and_negative(result);
-                    self.status.set(Flag::Carry, self.register_y >= value);
-                }
-                Dec { addressing_mode } => {
-                    let value = addressing_mode.into_value(self);
-                    let value = value.wrapping_sub(1);
-                    self.set_zero_and_negative(value);
-                    self.memory.write(addressing_mode.into_address(self), value);
+    /// Runs until `predicate` holds before an instruction, or the CPU is
+    /// halted.
+    pub fn run_until(
+        &mut self,
+        mut predicate: impl FnMut(&Cpu<B>) -> bool,
+    ) -> Result<(), CpuError> {
+        while !self.is_halted() && !predicate(self) {
+            self.step()?;
+        }
+
+        Ok(())
+    }
+
+    /// Runs whole instructions until at least `cycles` cycles have passed,
+    /// or the CPU is halted. Returns the number of cycles actually run,
+    /// which can overshoot by part of an instruction.
+    pub fn run_for_cycles(&mut self, cycles: u64) -> Result<u64, CpuError> {
+        let start = self.cycles;
+        self.run_until(|cpu| cpu.cycles - start >= cycles)?;
+
+        Ok(self.cycles - start)
+    }
+
+    /// Runs like `run` with `halt_on_brk` set, so it also halts before the
+    /// next BRK.
+    pub fn run_until_brk(&mut self) -> Result<HaltReason, CpuError> {
+        let halt_on_brk = std::mem::replace(&mut self.config.halt_on_brk, true);
+        let result = self.run();
+        self.config.halt_on_brk = halt_on_brk;
+        result
+    }
+
+    /// Executes the next instruction, or services a pending interrupt.
+    pub fn step(&mut self) -> Result<Step, CpuError> {
+        let start = self.cycles;
+        if self.pending_tick.is_some() {
+            // Finish the instruction `tick` started.
+            loop {
+                if let Some(step) = self.tick()?.step {
+                    return Ok(step);
                 }
-                Ld {
-                    destination,
-                    addressing_mode,
-                } => {
-                    let value = addressing_mode.into_value(self);
-                    self.set_register(&destination, value);
-                    self.set_zero_and_negative(value);
+            }
+        }
+        let program_counter_before = self.program_counter;
+        if let Some(halt_reason) = self.halt_reason {
+            return Ok(Step {
+                executed: Executed::Halted,
+                cycles: 0,
+                program_counter_before,
+                program_counter_after: program_counter_before,
+                halt_reason: Some(halt_reason),
+            });
+        }
+
+        let executed = match self.poll_interrupts() {
+            Some(executed) => executed,
+            None => Executed::Instruction(self.execute_next()?),
+        };
+
+        let jumped = matches!(
+            executed,
+            Executed::Instruction(
+                Instruction::Jmp { .. } | Instruction::Branch { .. } | Instruction::Bra { .. }
+            )
+        );
+        if self.config.detect_self_loops && jumped && self.program_counter == program_counter_before
+        {
+            self.halt_reason = Some(HaltReason::SelfLoop {
+                address: program_counter_before,
+            });
+        }
+
+        Ok(Step {
+            executed,
+            cycles: (self.cycles - start) as u8,
+            program_counter_before,
+            program_counter_after: self.program_counter,
+            halt_reason: self.halt_reason,
+        })
+    }
+
+    /// Jumps to a branch target, spending another cycle if it lands on a
+    /// different page.
+    fn take_branch(&mut self, new_address: u16) {
+        self.dummy_read(self.program_counter);
+        if new_address & 0xff00 != self.program_counter & 0xff00 {
+            self.cycles += 1;
+            self.dummy_read((self.program_counter & 0xff00) | (new_address & 0x00ff));
+        }
+        self.program_counter = new_address;
+    }
+
+    /// TRB and TSB set Zero like BIT and then apply A to memory as a mask.
+    fn test_and_modify_bits(&mut self, address: u16, operation: impl FnOnce(u8, u8) -> u8) {
+        let value = self.read(address);
+        self.trace(TraceEvent::Operand { value });
+        self.status.set(Flag::Zero, self.register_a & value == 0);
+        self.dummy_read(address);
+        self.write(address, operation(value, self.register_a));
+    }
+
+    /// JSR reads the high byte of its target only after pushing the return
+    /// address, so it's fetched and run apart from other instructions.
+    fn jsr(&mut self) -> Instruction {
+        let low = self.read(self.program_counter.wrapping_add(1)) as u16;
+        // The return address pushed is that of the last byte of the JSR
+        // instruction, RTS makes up for it.
+        let return_address = self.program_counter.wrapping_add(2);
+        self.stack_dummy_read();
+        self.stack_push_u16(return_address);
+        let high = self.read(return_address) as u16;
+
+        let instruction = Instruction::Jsr {
+            addressing_mode: addressing_mode::Absolute {
+                address: (high << 8) | low,
+            },
+        };
+        self.trace(TraceEvent::InstructionFetched {
+            program_counter: self.program_counter,
+            instruction,
+        });
+        self.program_counter = (high << 8) | low;
+        instruction
+    }
+
+    fn execute_next(&mut self) -> Result<Instruction, CpuError> {
+        let code = self.read(self.program_counter);
+        let cycles = match self.config.variant {
+            Variant::Cmos65C02 => &opcodes::CMOS_CYCLES,
+            Variant::Nes2A03 | Variant::Nmos6502 => &opcodes::CYCLES,
+        };
+        if code == opcodes::BRK && self.config.halt_on_brk {
+            // Fetching the opcode is all it gets to do.
+            self.cycles += 1;
+            self.halt_reason = Some(HaltReason::Break {
+                address: self.program_counter,
+            });
+            return Ok(Instruction::Break);
+        }
+        if code == opcodes::JSR {
+            self.cycles += cycles[code as usize] as u64;
+            return Ok(self.jsr());
+        }
+
+        let config = self.config;
+        let operands = self.program_counter.wrapping_add(1);
+        let decoded =
+            Instruction::decode(code, &mut |address| self.read(address), operands, &config);
+        let (instruction, program_counter) =
+            decoded.map_err(|source| self.instruction_error(source))?;
+        self.cycles += cycles[code as usize] as u64;
+
+        let single_cycle = matches!(instruction, Instruction::SingleCycleNop);
+        if program_counter == self.program_counter.wrapping_add(1) && !single_cycle {
+            // Single byte instructions still read the byte after the opcode.
+            self.dummy_read(program_counter);
+        }
+        self.trace(TraceEvent::InstructionFetched {
+            program_counter: self.program_counter,
+            instruction,
+        });
+        self.program_counter = program_counter;
+
+        use Instruction::*;
+
+        match instruction {
+            Adc { addressing_mode } => {
+                let value = addressing_mode.into_value(self);
+                self.adc(value);
+            }
+            And { addressing_mode } => {
+                let value = addressing_mode.into_value(self);
+                let value = self.register_a & value;
+                self.register_a = value;
+                self.set_zero_and_negative(value);
+            }
+            Asl { addressing_mode } => {
+                let target = addressing_mode.into_target(self);
+                self.read_modify_write(target, Cpu::asl);
+            }
+            Branch {
+                addressing_mode,
+                flag,
+                branch_if,
+            } => {
+                if self.status.get(flag) == branch_if {
+                    // A taken branch costs one cycle.
+                    self.cycles += 1;
+                    let new_address = addressing_mode.into_address(self);
+                    self.take_branch(new_address);
                 }
-                Trr {
-                    origin,
-                    destination,
-                } => {
-                    let value = self.get_register(&origin);
-                    self.set_register(&destination, value);
+            }
+            Bit { addressing_mode } => {
+                let value = addressing_mode.into_value(self);
+                self.status.set(Flag::Negative, value & 0b1000_0000 != 0);
+                self.status.set(Flag::Overflow, value & 0b0100_0000 != 0);
+                self.status.set(Flag::Zero, self.register_a & value == 0);
+            }
+            Break => {
+                // BRK skips the padding byte that follows it.
+                self.program_counter = self.program_counter.wrapping_add(1);
+                self.interrupt(IRQ_VECTOR, true);
+            }
+            Clear { flag } => self.status.set(flag, false),
+            Cmp { addressing_mode } => {
+                let value = addressing_mode.into_value(self);
+                self.compare(self.register_a, value);
+            }
+            Cpx { addressing_mode } => {
+                let value = addressing_mode.into_value(self);
+                self.compare(self.register_x, value);
+            }
+            Cpy { addressing_mode } => {
+                let value = addressing_mode.into_value(self);
+                self.compare(self.register_y, value);
+            }
+            Dec { addressing_mode } => {
+                let target = Target::Memory(addressing_mode.into_write_address(self));
+                self.read_modify_write(target, |_, value| value.wrapping_sub(1));
+            }
+            Eor { addressing_mode } => {
+                let value = addressing_mode.into_value(self);
+                let value = self.register_a ^ value;
+                self.register_a = value;
+                self.set_zero_and_negative(value);
+            }
+            Inc { addressing_mode } => {
+                let target = Target::Memory(addressing_mode.into_write_address(self));
+                self.read_modify_write(target, |_, value| value.wrapping_add(1));
+            }
+            Jmp { addressing_mode } => {
+                self.program_counter = addressing_mode.into_address(self);
+            }
+            Jsr { .. } => unreachable!("JSR is run by `jsr`"),
+            Ld {
+                destination,
+                addressing_mode,
+            } => {
+                let value = addressing_mode.into_value(self);
+                self.set_register(&destination, value);
+                self.set_zero_and_negative(value);
+            }
+            St {
+                origin,
+                addressing_mode,
+            } => {
+                let address = addressing_mode.into_write_address(self);
+                self.write(address, self.get_register(&origin));
+            }
+            Lsr { addressing_mode } => {
+                let target = addressing_mode.into_target(self);
+                self.read_modify_write(target, Cpu::lsr);
+            }
+            Ora { addressing_mode } => {
+                let value = addressing_mode.into_value(self);
+                let value = self.register_a | value;
+                self.register_a = value;
+                self.set_zero_and_negative(value);
+            }
+            Push { origin } => {
+                let value = self.get_register(&origin);
+                self.stack_push(value);
+            }
+            PushStatus => {
+                let value = self.status.to_stack(true);
+                self.stack_push(value);
+            }
+            Pull { destination } => {
+                self.stack_dummy_read();
+                let value = self.stack_pull();
+                self.set_register(&destination, value);
+                self.set_zero_and_negative(value);
+            }
+            PullStatus => {
+                self.stack_dummy_read();
+                let value = self.stack_pull();
+                self.status = Status::from_stack(value);
+            }
+            Rol { addressing_mode } => {
+                let target = addressing_mode.into_target(self);
+                self.read_modify_write(target, Cpu::rol);
+            }
+            Ror { addressing_mode } => {
+                let target = addressing_mode.into_target(self);
+                self.read_modify_write(target, Cpu::ror);
+            }
+            Rti => {
+                self.stack_dummy_read();
+                let value = self.stack_pull();
+                self.status = Status::from_stack(value);
+                self.program_counter = self.stack_pull_u16();
+            }
+            Rts => {
+                self.stack_dummy_read();
+                let return_address = self.stack_pull_u16();
+                self.dummy_read(return_address);
+                self.program_counter = return_address.wrapping_add(1);
+            }
+            Sbc { addressing_mode } => {
+                let value = addressing_mode.into_value(self);
+                self.sbc(value);
+            }
+            Set { flag } => self.status.set(flag, true),
+            Trr {
+                origin,
+                destination,
+            } => {
+                let value = self.get_register(&origin);
+                self.set_register(&destination, value);
+                // TXS is the only transfer that leaves the flags alone.
+                if !matches!(destination, Register::StackPointer) {
                     self.set_zero_and_negative(value);
                 }
-                In { destination } => {
-                    let value = self.get_register(&destination);
-                    let (value, overflow) = value.overflowing_add(1);
-                    self.set_register(&destination, value);
-                    self.status.set(Flag::Overflow, overflow);
-                    self.set_zero_and_negative(value);
+            }
+            In { destination } => {
+                let value = self.get_register(&destination).wrapping_add(1);
+                self.set_register(&destination, value);
+                self.set_zero_and_negative(value);
+            }
+            De { destination } => {
+                let value = self.get_register(&destination).wrapping_sub(1);
+                self.set_register(&destination, value);
+                self.set_zero_and_negative(value);
+            }
+            Nop => {}
+            Jam => {
+                // The CPU stays stuck on the opcode until it is reset.
+                self.program_counter = self.program_counter.wrapping_sub(1);
+                self.halt_reason = Some(HaltReason::Jam {
+                    address: self.program_counter,
+                });
+            }
+            Ign { addressing_mode } => {
+                addressing_mode.into_value(self);
+            }
+            Lax { addressing_mode } => {
+                let value = addressing_mode.into_value(self);
+                self.register_a = value;
+                self.register_x = value;
+                self.set_zero_and_negative(value);
+            }
+            Sax { addressing_mode } => {
+                let address = addressing_mode.into_write_address(self);
+                self.write(address, self.register_a & self.register_x);
+            }
+            Dcp { addressing_mode } => {
+                let target = Target::Memory(addressing_mode.into_write_address(self));
+                let value = self.read_modify_write(target, |_, value| value.wrapping_sub(1));
+                self.compare(self.register_a, value);
+            }
+            Isb { addressing_mode } => {
+                let target = Target::Memory(addressing_mode.into_write_address(self));
+                let value = self.read_modify_write(target, |_, value| value.wrapping_add(1));
+                self.sbc(value);
+            }
+            Slo { addressing_mode } => {
+                let target = Target::Memory(addressing_mode.into_write_address(self));
+                let value = self.read_modify_write(target, Cpu::asl);
+                let value = self.register_a | value;
+                self.register_a = value;
+                self.set_zero_and_negative(value);
+            }
+            Rla { addressing_mode } => {
+                let target = Target::Memory(addressing_mode.into_write_address(self));
+                let value = self.read_modify_write(target, Cpu::rol);
+                let value = self.register_a & value;
+                self.register_a = value;
+                self.set_zero_and_negative(value);
+            }
+            Sre { addressing_mode } => {
+                let target = Target::Memory(addressing_mode.into_write_address(self));
+                let value = self.read_modify_write(target, Cpu::lsr);
+                let value = self.register_a ^ value;
+                self.register_a = value;
+                self.set_zero_and_negative(value);
+            }
+            Rra { addressing_mode } => {
+                let target = Target::Memory(addressing_mode.into_write_address(self));
+                let value = self.read_modify_write(target, Cpu::ror);
+                self.adc(value);
+            }
+            Bra { addressing_mode } => {
+                let new_address = addressing_mode.into_address(self);
+                self.take_branch(new_address);
+            }
+            Stz { addressing_mode } => {
+                let address = addressing_mode.into_write_address(self);
+                self.write(address, 0);
+            }
+            Trb { addressing_mode } => {
+                let address = addressing_mode.into_write_address(self);
+                self.test_and_modify_bits(address, |value, mask| value & !mask);
+            }
+            Tsb { addressing_mode } => {
+                let address = addressing_mode.into_write_address(self);
+                self.test_and_modify_bits(address, |value, mask| value | mask);
+            }
+            BitImmediate { addressing_mode } => {
+                let value = addressing_mode.into_value(self);
+                self.status.set(Flag::Zero, self.register_a & value == 0);
+            }
+            SingleCycleNop => {}
+            LongNop { addressing_mode } => {
+                // It reads from the top page, and then keeps reading $FFFF.
+                self.dummy_read(0xff00 | (addressing_mode.address & 0x00ff));
+                for _ in 0..4 {
+                    self.dummy_read(0xffff);
                 }
             }
         }
 
-        Ok(())
+        Ok(instruction)
     }
 }
@@ -0,0 +1,413 @@
+//! Runs per-cycle test vectors in the format of the SingleStepTests project
+//! (https://github.com/SingleStepTests/65x02), ticking the CPU through each
+//! instruction.
+//!
+//! `single_step_tests/` holds a few vectors for each variant, written by
+//! hand from the documented cycle timings and laid out like the project,
+//! and they always run. The published ones take a few hundred
+//! megabytes, so they aren't part of the repository. Point
+//! `NESLUZ_SINGLE_STEP_TESTS` to a checkout of the project to run them too.
+
+use std::path::Path;
+
+use super::{bus::Bus, status::Status, BusAccess, BusCycle, Cpu, CpuConfig, Variant};
+
+const VECTORS_VARIABLE: &str = "NESLUZ_SINGLE_STEP_TESTS";
+/// Directory of the vectors of each variant, inside the project. The
+/// 65C02 modelled is the original one, without the Rockwell and WDC
+/// additions.
+const VARIANT_DIRECTORIES: [(Variant, &str); 3] = [
+    (Variant::Nes2A03, "nes6502/v1"),
+    (Variant::Nmos6502, "6502/v1"),
+    (Variant::Cmos65C02, "synertek65c02/v1"),
+];
+/// How many failures are listed for each file.
+const REPORTED_FAILURES: usize = 10;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Json {
+    Number(u64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    fn get(&self, key: &str) -> &Json {
+        match self {
+            Json::Object(members) => members
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value)
+                .unwrap_or_else(|| panic!("missing key {key:?}")),
+            _ => panic!("expected an object"),
+        }
+    }
+
+    fn number(&self) -> u64 {
+        match self {
+            Json::Number(number) => *number,
+            _ => panic!("expected a number"),
+        }
+    }
+
+    fn string(&self) -> &str {
+        match self {
+            Json::String(string) => string,
+            _ => panic!("expected a string"),
+        }
+    }
+
+    fn array(&self) -> &[Json] {
+        match self {
+            Json::Array(values) => values,
+            _ => panic!("expected an array"),
+        }
+    }
+}
+
+/// A parser for the little JSON the vectors use: objects, arrays, strings
+/// without escapes and unsigned integers.
+struct Parser<'a> {
+    input: &'a [u8],
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn parse(input: &str) -> Json {
+        let mut parser = Parser {
+            input: input.as_bytes(),
+            position: 0,
+        };
+        let value = parser.value();
+        parser.skip_whitespace();
+        assert_eq!(parser.position, parser.input.len(), "trailing characters");
+        value
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self
+            .input
+            .get(self.position)
+            .is_some_and(|byte| byte.is_ascii_whitespace())
+        {
+            self.position += 1;
+        }
+    }
+
+    fn next(&mut self) -> u8 {
+        self.skip_whitespace();
+        let byte = *self
+            .input
+            .get(self.position)
+            .expect("unexpected end of input");
+        self.position += 1;
+        byte
+    }
+
+    fn expect(&mut self, expected: u8) {
+        let byte = self.next();
+        assert_eq!(
+            byte as char,
+            expected as char,
+            "at byte {}",
+            self.position - 1
+        );
+    }
+
+    fn value(&mut self) -> Json {
+        match self.next() {
+            b'{' => {
+                let mut members = Vec::new();
+                if !self.end_of(b'}') {
+                    loop {
+                        self.expect(b'"');
+                        let key = self.string();
+                        self.expect(b':');
+                        members.push((key, self.value()));
+                        if self.end_of(b'}') {
+                            break;
+                        }
+                        self.expect(b',');
+                    }
+                }
+                Json::Object(members)
+            }
+            b'[' => {
+                let mut values = Vec::new();
+                if !self.end_of(b']') {
+                    loop {
+                        values.push(self.value());
+                        if self.end_of(b']') {
+                            break;
+                        }
+                        self.expect(b',');
+                    }
+                }
+                Json::Array(values)
+            }
+            b'"' => Json::String(self.string()),
+            byte @ b'0'..=b'9' => {
+                let mut number = (byte - b'0') as u64;
+                while let Some(byte @ b'0'..=b'9') = self.input.get(self.position) {
+                    number = number * 10 + (byte - b'0') as u64;
+                    self.position += 1;
+                }
+                Json::Number(number)
+            }
+            byte => panic!(
+                "unexpected {:?} at byte {}",
+                byte as char,
+                self.position - 1
+            ),
+        }
+    }
+
+    /// Consumes `close` if it comes next.
+    fn end_of(&mut self, close: u8) -> bool {
+        self.skip_whitespace();
+        let found = self.input.get(self.position) == Some(&close);
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    /// The rest of a string whose opening quote was already consumed.
+    fn string(&mut self) -> String {
+        let start = self.position;
+        while self.input[self.position] != b'"' {
+            self.position += 1;
+        }
+        self.position += 1;
+        String::from_utf8(self.input[start..self.position - 1].to_vec()).unwrap()
+    }
+}
+
+fn set_state(cpu: &mut Cpu, state: &Json) {
+    cpu.program_counter = state.get("pc").number() as u16;
+    cpu.stack_pointer = state.get("s").number() as u8;
+    cpu.register_a = state.get("a").number() as u8;
+    cpu.register_x = state.get("x").number() as u8;
+    cpu.register_y = state.get("y").number() as u8;
+    cpu.status = Status::from_stack(state.get("p").number() as u8);
+    for entry in state.get("ram").array() {
+        let [address, value] = entry.array() else {
+            panic!("expected an address and a value");
+        };
+        cpu.bus.write(address.number() as u16, value.number() as u8);
+    }
+}
+
+/// Describes every way `cpu` differs from `state`.
+fn state_differences(cpu: &Cpu, state: &Json) -> Vec<String> {
+    let mut differences = Vec::new();
+    let mut compare = |name: &str, actual: u64, expected: u64| {
+        if actual != expected {
+            differences.push(format!("{name} is {actual:#x}, expected {expected:#x}"));
+        }
+    };
+    compare("pc", cpu.program_counter as u64, state.get("pc").number());
+    compare("s", cpu.stack_pointer as u64, state.get("s").number());
+    compare("a", cpu.register_a as u64, state.get("a").number());
+    compare("x", cpu.register_x as u64, state.get("x").number());
+    compare("y", cpu.register_y as u64, state.get("y").number());
+    // B and the unused bit aren't part of the register.
+    compare(
+        "p",
+        cpu.status.to_stack(true) as u64,
+        state.get("p").number() | 0b0011_0000,
+    );
+    for entry in state.get("ram").array() {
+        let [address, value] = entry.array() else {
+            panic!("expected an address and a value");
+        };
+        compare(
+            &format!("${:04x}", address.number()),
+            cpu.bus.peek(address.number() as u16) as u64,
+            value.number(),
+        );
+    }
+    differences
+}
+
+fn expected_bus_cycles(cycles: &Json) -> Vec<BusCycle> {
+    cycles
+        .array()
+        .iter()
+        .map(|cycle| {
+            let [address, value, access] = cycle.array() else {
+                panic!("expected an address, a value and an access");
+            };
+            BusCycle {
+                address: address.number() as u16,
+                value: value.number() as u8,
+                access: match access.string() {
+                    "read" => BusAccess::Read,
+                    "write" => BusAccess::Write,
+                    access => panic!("unknown access {access:?}"),
+                },
+            }
+        })
+        .collect()
+}
+
+/// Runs every test in `tests`, a parsed vector file, and returns a
+/// description of each failure. Opcodes the CPU rejects count as failures,
+/// while those that halt it are skipped, as the vectors have JAM run on.
+fn run_vectors(config: CpuConfig, tests: &Json) -> Vec<String> {
+    let mut cpu = Cpu::with_config(config);
+    let mut failures = Vec::new();
+
+    for test in tests.array() {
+        if cpu.is_halted() {
+            cpu = Cpu::with_config(config);
+        }
+        set_state(&mut cpu, test.get("initial"));
+        let name = test.get("name").string();
+
+        let mut bus_cycles = Vec::new();
+        let step = loop {
+            match cpu.tick() {
+                Ok(tick) => {
+                    bus_cycles.extend(tick.bus_cycle);
+                    if let Some(step) = tick.step {
+                        break Ok(step);
+                    }
+                }
+                Err(error) => break Err(error),
+            }
+        };
+        if let Err(error) = step {
+            failures.push(format!("{name}: {error}"));
+            continue;
+        }
+        if cpu.is_halted() {
+            continue;
+        }
+
+        let mut differences = state_differences(&cpu, test.get("final"));
+        let expected = expected_bus_cycles(test.get("cycles"));
+        if bus_cycles != expected {
+            differences.push(format!(
+                "bus cycles are {bus_cycles:?}, expected {expected:?}"
+            ));
+        }
+        if !differences.is_empty() {
+            failures.push(format!("{name}: {}", differences.join(", ")));
+        }
+    }
+    failures
+}
+
+/// Runs the vector files of every variant found under `root`. Returns how
+/// many files were run, and a description of each one that failed.
+fn run_directory(root: &Path) -> (usize, Vec<String>) {
+    let mut files = 0;
+    let mut failures = Vec::new();
+    for (variant, directory) in VARIANT_DIRECTORIES {
+        let config = CpuConfig {
+            variant,
+            ..CpuConfig::default()
+        };
+        let directory = root.join(directory);
+        for code in 0..=0xffu8 {
+            let path = directory.join(format!("{code:02x}.json"));
+            let Ok(input) = std::fs::read_to_string(&path) else {
+                continue;
+            };
+            files += 1;
+            let file_failures = run_vectors(config, &Parser::parse(&input));
+            if !file_failures.is_empty() {
+                failures.push(format!(
+                    "{} ({} failed):\n  {}",
+                    path.display(),
+                    file_failures.len(),
+                    file_failures[..file_failures.len().min(REPORTED_FAILURES)].join("\n  ")
+                ));
+            }
+        }
+    }
+    (files, failures)
+}
+
+#[test]
+fn committed_vectors() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/cpu/single_step_tests");
+    let (files, failures) = run_directory(&root);
+    assert!(files > 0, "no vectors found in {}", root.display());
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn published_vectors() {
+    let Some(root) = std::env::var_os(VECTORS_VARIABLE) else {
+        eprintln!("{VECTORS_VARIABLE} isn't set, skipping the published SingleStepTests vectors");
+        return;
+    };
+    let (files, failures) = run_directory(Path::new(&root));
+    assert!(files > 0, "no vectors found in {root:?}");
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn parser() {
+    assert_eq!(
+        Parser::parse(r#" { "a": [1, 23, "b"], "c": {}, "d": [] } "#),
+        Json::Object(vec![
+            (
+                "a".to_string(),
+                Json::Array(vec![
+                    Json::Number(1),
+                    Json::Number(23),
+                    Json::String("b".to_string())
+                ])
+            ),
+            ("c".to_string(), Json::Object(vec![])),
+            ("d".to_string(), Json::Array(vec![])),
+        ])
+    );
+}
+
+/// Checks the runner itself on a test laid out like the published ones.
+#[test]
+fn runner() {
+    let test = r#"[{
+        "name": "bd ff 02",
+        "initial": {
+            "pc": 32768, "s": 253, "a": 0, "x": 1, "y": 0, "p": 36,
+            "ram": [[32768, 189], [32769, 255], [32770, 2], [512, 0], [768, 66]]
+        },
+        "final": {
+            "pc": 32771, "s": 253, "a": 66, "x": 1, "y": 0, "p": 36,
+            "ram": [[32768, 189], [32769, 255], [32770, 2], [512, 0], [768, 66]]
+        },
+        "cycles": [
+            [32768, 189, "read"],
+            [32769, 255, "read"],
+            [32770, 2, "read"],
+            [512, 0, "read"],
+            [768, 66, "read"]
+        ]
+    }]"#;
+    let config = CpuConfig::default();
+    assert_eq!(
+        run_vectors(config, &Parser::parse(test)),
+        Vec::<String>::new()
+    );
+
+    let wrong_access = test.replace(r#"[768, 66, "read"]"#, r#"[768, 66, "write"]"#);
+    let failures = run_vectors(config, &Parser::parse(&wrong_access));
+    assert_eq!(failures.len(), 1);
+    assert!(failures[0].starts_with("bd ff 02: bus cycles are"));
+
+    // An opcode the CPU refuses to run is a failure, not a skipped test.
+    let config = CpuConfig {
+        unofficial_opcodes: false,
+        ..CpuConfig::default()
+    };
+    let rejected = test.replace("[32768, 189]", "[32768, 167]");
+    let failures = run_vectors(config, &Parser::parse(&rejected));
+    assert_eq!(failures.len(), 1);
+    assert!(failures[0].starts_with("bd ff 02: "));
+}
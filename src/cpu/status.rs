@@ -1,4 +1,4 @@
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Flag {
     Carry,
     Zero,
@@ -22,11 +22,22 @@ impl From<Flag> for u8 {
     }
 }
 
+/// Set in the copy of the status pushed by PHP and BRK, clear for interrupts.
+const BREAK: u8 = 0b0001_0000;
+/// Unused bit, always set in the copy of the status pushed to the stack.
+const UNUSED: u8 = 0b0010_0000;
+
 #[derive(Debug)]
 pub struct Status {
     flags: u8,
 }
 
+impl Default for Status {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Status {
     pub fn new() -> Status {
         Status { flags: 0b0000_0000 }
@@ -44,3 +55,23 @@ impl Status {
         self.flags & u8::from(flag) != 0b0000_0000
     }
 }
+
+impl Status {
+    /// Byte pushed to the stack, with the unused bit set and B as requested.
+    pub fn to_stack(&self, break_flag: bool) -> u8 {
+        let value = self.flags | UNUSED;
+        if break_flag {
+            value | BREAK
+        } else {
+            value
+        }
+    }
+
+    /// Status pulled from the stack. B and the unused bit don't exist in the
+    /// register itself, so they are discarded.
+    pub fn from_stack(value: u8) -> Status {
+        Status {
+            flags: value & !(BREAK | UNUSED),
+        }
+    }
+}
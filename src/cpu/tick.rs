@@ -0,0 +1,182 @@
+//! Running the CPU one bus cycle at a time.
+//!
+//! Instructions are written as straight code, not as state machines, so
+//! `Cpu::tick` runs the whole instruction again on every call. Accesses made
+//! by earlier ticks are answered from a log instead of the bus, the first
+//! new one reaches the bus, and anything after it is skipped. The
+//! instruction is done once it runs to the end without skipping anything.
+
+use super::{bus::Bus, BusAccess, BusCycle, Cpu, CpuError, Registers, Step};
+
+/// What a single call to `Cpu::tick` did.
+#[derive(Debug, Clone, Copy)]
+pub struct Tick {
+    /// The access made on this cycle. None only when the CPU is halted.
+    pub bus_cycle: Option<BusCycle>,
+    /// Set on the last cycle of an instruction or interrupt, and right away
+    /// when the CPU is halted.
+    pub step: Option<Step>,
+}
+
+/// An instruction started by `tick` and not yet finished.
+#[derive(Debug)]
+pub(super) struct Replay {
+    /// The CPU as it was before the instruction.
+    start: Registers,
+    irq_line: bool,
+    nmi_pending: bool,
+    /// Every access made so far, in order.
+    bus_cycles: Vec<BusCycle>,
+    /// How many accesses the instruction had made before this tick.
+    known: usize,
+    /// How many accesses the current run has gone through.
+    position: usize,
+    /// Trace events already reported by earlier runs.
+    events_reported: usize,
+    /// Trace events the current run has produced.
+    events: usize,
+    /// Whether the current run went past the access of this tick.
+    overran: bool,
+}
+
+/// How an access is made while an instruction is replayed.
+pub(super) enum ReplayedAccess {
+    /// An earlier tick already made it, and this is the value it saw.
+    Logged(u8),
+    /// The access of this tick, which reaches the bus.
+    Live,
+    /// It belongs to a later tick, so the bus is left alone.
+    Skipped,
+}
+
+impl Replay {
+    pub(super) fn next_access(&mut self) -> ReplayedAccess {
+        let position = self.position;
+        self.position += 1;
+        if position < self.known {
+            ReplayedAccess::Logged(self.bus_cycles[position].value)
+        } else if position == self.known {
+            ReplayedAccess::Live
+        } else {
+            self.overran = true;
+            ReplayedAccess::Skipped
+        }
+    }
+
+    pub(super) fn log(&mut self, address: u16, value: u8, access: BusAccess) {
+        self.bus_cycles.push(BusCycle {
+            address,
+            value,
+            access,
+        });
+    }
+
+    /// Whether the next trace event is new and happens by this tick.
+    pub(super) fn report_event(&mut self) -> bool {
+        let index = self.events;
+        self.events += 1;
+        let report = index >= self.events_reported && !self.overran;
+        if report {
+            self.events_reported = index + 1;
+        }
+        report
+    }
+}
+
+impl<B: Bus> Cpu<B> {
+    /// Advances the CPU by a single cycle, making exactly one bus access, so
+    /// devices can do their own work between the accesses of an instruction.
+    ///
+    /// The registers and `cycles` keep their values from before the
+    /// instruction until its last cycle. Interrupt lines are sampled when
+    /// the instruction starts, and `halt` takes effect once it's done.
+    pub fn tick(&mut self) -> Result<Tick, CpuError> {
+        let replay = match self.pending_tick.take() {
+            Some(replay) => replay,
+            None => {
+                if self.halt_reason.is_some() {
+                    return Ok(Tick {
+                        bus_cycle: None,
+                        step: Some(self.step()?),
+                    });
+                }
+                let replay = Replay {
+                    start: self.registers(),
+                    irq_line: self.irq_line,
+                    nmi_pending: self.nmi_pending,
+                    bus_cycles: Vec::new(),
+                    known: 0,
+                    position: 0,
+                    events_reported: 0,
+                    events: 0,
+                    overran: false,
+                };
+                // From here on it only holds edges the instruction missed.
+                self.nmi_pending = false;
+                replay
+            }
+        };
+
+        let irq_line = self.irq_line;
+        let nmi_edge = self.nmi_pending;
+        let halt_reason = self.halt_reason.take();
+        self.restore(replay.start);
+        self.irq_line = replay.irq_line;
+        self.nmi_pending = replay.nmi_pending;
+        self.replay = Some(Replay {
+            known: replay.bus_cycles.len(),
+            position: 0,
+            events: 0,
+            overran: false,
+            ..replay
+        });
+
+        let result = self.step();
+
+        let replay = self.replay.take().expect("only tick takes the replay");
+        let bus_cycle = replay.bus_cycles.get(replay.known).copied();
+        self.irq_line = irq_line;
+        match result {
+            Ok(mut step) if !replay.overran => {
+                self.nmi_pending |= nmi_edge;
+                self.halt_reason = self.halt_reason.or(halt_reason);
+                step.halt_reason = self.halt_reason;
+                Ok(Tick {
+                    bus_cycle,
+                    step: Some(step),
+                })
+            }
+            Ok(_) => {
+                self.restore(replay.start);
+                self.nmi_pending = nmi_edge;
+                self.halt_reason = halt_reason;
+                self.pending_tick = Some(replay);
+                Ok(Tick {
+                    bus_cycle,
+                    step: None,
+                })
+            }
+            Err(error) => {
+                self.restore(replay.start);
+                self.nmi_pending = replay.nmi_pending || nmi_edge;
+                self.halt_reason = halt_reason;
+                Err(error)
+            }
+        }
+    }
+
+    /// Whether `tick` is in the middle of an instruction.
+    pub fn is_mid_instruction(&self) -> bool {
+        self.pending_tick.is_some()
+    }
+
+    fn restore(&mut self, registers: Registers) {
+        self.register_a = registers.register_a;
+        self.register_x = registers.register_x;
+        self.register_y = registers.register_y;
+        self.stack_pointer = registers.stack_pointer;
+        self.status = super::status::Status::from_stack(registers.status);
+        self.program_counter = registers.program_counter;
+        self.cycles = registers.cycles;
+    }
+}
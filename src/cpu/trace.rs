@@ -0,0 +1,49 @@
+use super::instruction::Instruction;
+
+/// Something the CPU did, reported to its observer as it happens.
+#[derive(Debug, Clone, Copy)]
+pub enum TraceEvent {
+    /// An instruction was decoded at `program_counter` and is about to run.
+    /// JSR fetches its target last, so it's only reported once it has run.
+    InstructionFetched {
+        program_counter: u16,
+        instruction: Instruction,
+    },
+    /// The value an instruction read as its operand.
+    Operand {
+        value: u8,
+    },
+    Read {
+        address: u16,
+        value: u8,
+    },
+    Write {
+        address: u16,
+        value: u8,
+    },
+}
+
+/// Receives the trace events of a `Cpu`. Implemented for closures, so a
+/// test can collect events with `cpu.set_observer(move |event| ...)`.
+pub trait Observer {
+    fn on_event(&mut self, event: &TraceEvent);
+}
+
+impl<F: FnMut(&TraceEvent)> Observer for F {
+    fn on_event(&mut self, event: &TraceEvent) {
+        self(event)
+    }
+}
+
+/// Holder for the optional observer, so `Cpu` can still derive Debug.
+#[derive(Default)]
+pub(super) struct ObserverSlot(pub(super) Option<Box<dyn Observer>>);
+
+impl std::fmt::Debug for ObserverSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Some(_) => write!(f, "Some(..)"),
+            None => write!(f, "None"),
+        }
+    }
+}
@@ -1,8 +1,11 @@
 use derives::AddressingEnum;
 
 pub mod cpu;
+pub mod nes;
 
 fn main() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
     #[derive(AddressingEnum)]
     enum _Gente {
         #[modes(mode = "immediate", mode = "zero_page")]
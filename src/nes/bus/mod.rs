@@ -0,0 +1,150 @@
+use crate::cpu::{bus::Bus, memory::RamInit};
+
+use super::{
+    cartridge::{Cartridge, CartridgeError},
+    mapper::{Mapper, Nrom},
+};
+
+#[cfg(test)]
+mod tests;
+
+const RAM_SIZE: usize = 0x0800;
+const RAM_MIRRORS_END: u16 = 0x1fff;
+const PPU_REGISTERS_START: u16 = 0x2000;
+const PPU_REGISTERS_MIRRORS_END: u16 = 0x3fff;
+const IO_REGISTERS_START: u16 = 0x4000;
+const IO_REGISTERS_END: u16 = 0x401f;
+const CARTRIDGE_START: u16 = 0x4020;
+const APU_STATUS: u16 = 0x4015;
+const JOYPAD_1: u16 = 0x4016;
+const JOYPAD_2: u16 = 0x4017;
+const TRAINER_START: u16 = 0x7000;
+
+/// The NES CPU memory map.
+///
+/// | Range         | Contents                                 |
+/// |---------------|------------------------------------------|
+/// | $0000–$1FFF   | 2 KiB of RAM, mirrored every $0800       |
+/// | $2000–$3FFF   | PPU registers, mirrored every 8 bytes    |
+/// | $4000–$401F   | APU and I/O registers                    |
+/// | $4020–$FFFF   | Cartridge, handled by the mapper         |
+///
+/// There is no PPU or APU yet, so their registers just keep what was last
+/// written to them. No controllers are plugged in either, so $4016 and
+/// $4017 read 0 on the bits they would drive.
+///
+/// Reads nothing answers return the open bus, the last value that was on
+/// the data bus. That's the case of the write-only APU registers, of the
+/// unused bits of $4015–$4017, and of cartridge space the mapper leaves
+/// unmapped.
+pub struct NesBus {
+    ram: [u8; RAM_SIZE],
+    ppu_registers: [u8; 8],
+    io_registers: [u8; 0x20],
+    mapper: Box<dyn Mapper>,
+    open_bus: u8,
+}
+
+impl std::fmt::Debug for NesBus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NesBus")
+            .field("mapper", &self.mapper)
+            .finish_non_exhaustive()
+    }
+}
+
+impl NesBus {
+    pub fn new(mapper: impl Mapper + 'static) -> NesBus {
+        NesBus {
+            ram: [0; RAM_SIZE],
+            ppu_registers: [0; 8],
+            io_registers: [0; 0x20],
+            mapper: Box::new(mapper),
+            open_bus: 0,
+        }
+    }
+
+    /// Plugs `cartridge` in, with its trainer loaded if it has one.
+    pub fn with_cartridge(cartridge: Cartridge) -> Result<NesBus, CartridgeError> {
+        let mut bus = match cartridge.mapper {
+            0 => NesBus::new(Nrom::new(cartridge.prg_rom)),
+            mapper => return Err(CartridgeError::UnsupportedMapper { mapper }),
+        };
+        if let Some(trainer) = cartridge.trainer {
+            for (address, value) in (TRAINER_START..).zip(trainer) {
+                bus.mapper.write(address, value);
+            }
+        }
+
+        Ok(bus)
+    }
+
+    pub fn mapper(&self) -> &dyn Mapper {
+        self.mapper.as_ref()
+    }
+
+    pub fn mapper_mut(&mut self) -> &mut dyn Mapper {
+        self.mapper.as_mut()
+    }
+
+    /// The last value read or written on the bus. Reads of $4015 don't
+    /// count, as they stay inside the CPU.
+    pub fn open_bus(&self) -> u8 {
+        self.open_bus
+    }
+
+    /// APU and I/O reads. Only $4015–$4017 drive the data bus, and only on
+    /// some of their bits.
+    fn read_io_register(&self, address: u16) -> u8 {
+        let value = self.io_registers[(address - IO_REGISTERS_START) as usize];
+        match address {
+            APU_STATUS => (value & 0b1101_1111) | (self.open_bus & 0b0010_0000),
+            // No controllers are plugged in, so they never pull a line up.
+            JOYPAD_1 | JOYPAD_2 => self.open_bus & 0b1110_0000,
+            _ => self.open_bus,
+        }
+    }
+}
+
+impl Bus for NesBus {
+    fn read(&mut self, address: u16) -> u8 {
+        let value = match address {
+            CARTRIDGE_START.. => self.mapper.read(address).unwrap_or(self.open_bus),
+            _ => self.peek(address),
+        };
+        // $4015 is inside the 2A03, so reading it never reaches the data bus.
+        if address != APU_STATUS {
+            self.open_bus = value;
+        }
+        value
+    }
+
+    fn write(&mut self, address: u16, value: u8) {
+        self.open_bus = value;
+        match address {
+            ..=RAM_MIRRORS_END => self.ram[address as usize % RAM_SIZE] = value,
+            PPU_REGISTERS_START..=PPU_REGISTERS_MIRRORS_END => {
+                self.ppu_registers[(address % 8) as usize] = value
+            }
+            IO_REGISTERS_START..=IO_REGISTERS_END => {
+                self.io_registers[(address - IO_REGISTERS_START) as usize] = value
+            }
+            CARTRIDGE_START.. => self.mapper.write(address, value),
+        }
+    }
+
+    fn peek(&self, address: u16) -> u8 {
+        match address {
+            ..=RAM_MIRRORS_END => self.ram[address as usize % RAM_SIZE],
+            PPU_REGISTERS_START..=PPU_REGISTERS_MIRRORS_END => {
+                self.ppu_registers[(address % 8) as usize]
+            }
+            IO_REGISTERS_START..=IO_REGISTERS_END => self.read_io_register(address),
+            CARTRIDGE_START.. => self.mapper.peek(address).unwrap_or(self.open_bus),
+        }
+    }
+
+    fn power_on(&mut self, init: RamInit) {
+        init.fill(&mut self.ram);
+    }
+}
@@ -0,0 +1,184 @@
+use super::*;
+use crate::{
+    cpu::{
+        instruction::opcodes::{LDA_ABSOLUTE, LDX_ABSOLUTE, STA_ABSOLUTE},
+        Cpu, CpuConfig,
+    },
+    nes::mapper::Nrom,
+};
+
+fn nes_bus(prg_rom: &[u8]) -> NesBus {
+    NesBus::new(Nrom::new(prg_rom.to_vec()))
+}
+
+#[test]
+fn ram_mirroring() {
+    let mut bus = nes_bus(&[0]);
+    bus.write(0x0012, 0x01);
+    assert_eq!(bus.read(0x0812), 0x01);
+    assert_eq!(bus.read(0x1012), 0x01);
+    assert_eq!(bus.read(0x1812), 0x01);
+
+    bus.write(0x1fff, 0x02);
+    assert_eq!(bus.read(0x07ff), 0x02);
+}
+
+#[test]
+fn ppu_register_mirroring() {
+    let mut bus = nes_bus(&[0]);
+    bus.write(0x2001, 0x01);
+    assert_eq!(bus.read(0x2009), 0x01);
+    assert_eq!(bus.read(0x3ff9), 0x01);
+
+    bus.write(0x3fff, 0x02);
+    assert_eq!(bus.read(0x2007), 0x02);
+    assert_eq!(bus.read(0x0007), 0x00);
+}
+
+#[test]
+fn io_registers() {
+    let mut bus = nes_bus(&[0]);
+    bus.write(0x4015, 0xff);
+    // Writing $4016 strobes the controllers, it isn't read back.
+    bus.write(0x4016, 0xff);
+    bus.write(0x0000, 0x00);
+    assert_eq!(bus.read(0x4015), 0b1101_1111);
+    assert_eq!(bus.open_bus(), 0x00);
+    assert_eq!(bus.read(0x4016), 0x00);
+
+    bus.write(0x4015, 0x00);
+    bus.write(0x0000, 0b1010_0000);
+    bus.read(0x0000);
+    assert_eq!(bus.read(0x4017), 0b1010_0000);
+    bus.write(0x0000, 0xff);
+    assert_eq!(bus.read(0x4016), 0b1110_0000);
+    bus.read(0x0000);
+    assert_eq!(bus.read(0x4015), 0b0010_0000);
+}
+
+#[test]
+fn open_bus() {
+    let mut bus = nes_bus(&[0x42]);
+    bus.write(0x4000, 0x01);
+    assert_eq!(bus.read(0x4000), 0x01);
+    assert_eq!(bus.read(0x401f), 0x01);
+    assert_eq!(bus.read(0x5000), 0x01);
+    assert_eq!(bus.open_bus(), 0x01);
+
+    bus.read(0x8000);
+    assert_eq!(bus.peek(0x4020), 0x42);
+    assert_eq!(bus.read(0x4020), 0x42);
+
+    bus.write(0x0000, 0x03);
+    assert_eq!(bus.read(0x4018), 0x03);
+}
+
+#[test]
+fn cartridge() {
+    let mut prg_rom = vec![0; 0x4000];
+    prg_rom[0x0000] = 0x01;
+    prg_rom[0x3fff] = 0x02;
+    let mut bus = nes_bus(&prg_rom);
+    assert_eq!(bus.read(0x8000), 0x01);
+    assert_eq!(bus.read(0xbfff), 0x02);
+    assert_eq!(bus.read(0xc000), 0x01);
+    assert_eq!(bus.read(0xffff), 0x02);
+
+    bus.write(0x8000, 0x03);
+    assert_eq!(bus.read(0x8000), 0x01);
+
+    bus.write(0x6000, 0x04);
+    assert_eq!(bus.read(0x6000), 0x04);
+    assert_eq!(bus.mapper().peek(0x6000), Some(0x04));
+}
+
+#[test]
+fn power_on() {
+    let mut bus = nes_bus(&[0]);
+    bus.power_on(RamInit::Fill(0xff));
+    assert_eq!(bus.read(0x0000), 0xff);
+    assert_eq!(bus.read(0x1fff), 0xff);
+    assert_eq!(bus.read(0x2000), 0x00);
+}
+
+#[test]
+fn cpu() {
+    let mut prg_rom = vec![0; 0x4000];
+    prg_rom[..6].copy_from_slice(&[LDA_ABSOLUTE, 0x00, 0xc0, STA_ABSOLUTE, 0x00, 0x08]);
+    // The reset vector at $FFFC, mirrored from $BFFC.
+    prg_rom[0x3ffc..0x3ffe].copy_from_slice(&[0x00, 0x80]);
+
+    let mut cpu = Cpu::with_bus(nes_bus(&prg_rom), CpuConfig::default());
+    cpu.power_on().unwrap();
+    assert_eq!(cpu.program_counter, 0x8000);
+
+    cpu.step().unwrap();
+    cpu.step().unwrap();
+    assert_eq!(cpu.register_a, LDA_ABSOLUTE);
+    assert_eq!(cpu.bus().peek(0x0000), LDA_ABSOLUTE);
+}
+
+#[test]
+fn cpu_open_bus() {
+    let mut prg_rom = vec![0; 0x4000];
+    prg_rom[..9].copy_from_slice(&[
+        LDA_ABSOLUTE,
+        0x00,
+        0x50,
+        LDX_ABSOLUTE,
+        0x15,
+        0x40,
+        STA_ABSOLUTE,
+        0x00,
+        0x40,
+    ]);
+    prg_rom[0x3ffc..0x3ffe].copy_from_slice(&[0x00, 0x80]);
+
+    let mut cpu = Cpu::with_bus(nes_bus(&prg_rom), CpuConfig::default());
+    cpu.power_on().unwrap();
+
+    // The last thing on the bus was the high byte of the operand.
+    cpu.step().unwrap();
+    assert_eq!(cpu.register_a, 0x50);
+    assert_eq!(cpu.bus().open_bus(), 0x50);
+
+    cpu.step().unwrap();
+    assert_eq!(cpu.register_x, 0x00);
+    assert_eq!(cpu.bus().open_bus(), 0x40);
+
+    cpu.step().unwrap();
+    assert_eq!(cpu.bus().open_bus(), 0x50);
+}
+
+#[test]
+fn with_cartridge() {
+    use crate::nes::cartridge::{Cartridge, CartridgeError};
+
+    let mut data = b"NES\x1a".to_vec();
+    data.extend([0x01, 0x00, 0b0000_0100]);
+    data.resize(16, 0);
+    data.extend([0x54; 512]);
+    let mut prg_rom = vec![0; 0x4000];
+    prg_rom[..3].copy_from_slice(&[LDA_ABSOLUTE, 0x00, 0x70]);
+    prg_rom[0x3ffc..0x3ffe].copy_from_slice(&[0x00, 0x80]);
+    data.extend(prg_rom);
+
+    let cartridge = Cartridge::from_ines(&data).unwrap();
+    let bus = NesBus::with_cartridge(cartridge.clone()).unwrap();
+    let mut cpu = Cpu::with_bus(bus, CpuConfig::default());
+    cpu.power_on().unwrap();
+    assert_eq!(cpu.program_counter, 0x8000);
+    cpu.step().unwrap();
+    assert_eq!(cpu.register_a, 0x54);
+    assert_eq!(cpu.bus().peek(0x71ff), 0x54);
+    assert_eq!(cpu.bus().peek(0x7200), 0x00);
+
+    let cartridge = Cartridge {
+        mapper: 1,
+        ..cartridge
+    };
+    assert!(matches!(
+        NesBus::with_cartridge(cartridge),
+        Err(CartridgeError::UnsupportedMapper { mapper: 1 })
+    ));
+}
@@ -0,0 +1,161 @@
+use thiserror::Error;
+
+#[cfg(test)]
+mod tests;
+
+const HEADER_SIZE: usize = 16;
+const MAGIC: [u8; 4] = *b"NES\x1a";
+const TRAINER_SIZE: usize = 512;
+const PRG_ROM_BANK_SIZE: usize = 0x4000;
+const CHR_ROM_BANK_SIZE: usize = 0x2000;
+const CHR_RAM_SIZE: usize = 0x2000;
+
+const FLAGS_6_VERTICAL_MIRRORING: u8 = 0b0000_0001;
+const FLAGS_6_BATTERY: u8 = 0b0000_0010;
+const FLAGS_6_TRAINER: u8 = 0b0000_0100;
+const FLAGS_6_FOUR_SCREEN: u8 = 0b0000_1000;
+const FLAGS_7_VERSION: u8 = 0b0000_1100;
+const FLAGS_7_NES_2_0: u8 = 0b0000_1000;
+
+#[derive(Error, Debug)]
+pub enum CartridgeError {
+    #[error("not an iNES file")]
+    InvalidMagic,
+    #[error("file is {actual} bytes long but the header needs {expected}")]
+    Truncated { expected: usize, actual: usize },
+    #[error("the header declares no PRG ROM")]
+    NoPrgRom,
+    #[error("mapper {mapper} isn't supported")]
+    UnsupportedMapper { mapper: u16 },
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// How the PPU nametables are mirrored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mirroring {
+    Horizontal,
+    Vertical,
+    /// The cartridge brings enough VRAM for four nametables.
+    FourScreen,
+}
+
+/// Pattern table memory of the cartridge.
+#[derive(Clone, PartialEq, Eq)]
+pub enum Chr {
+    Rom(Vec<u8>),
+    /// Boards without CHR ROM come with 8 KiB of CHR RAM.
+    Ram(Vec<u8>),
+}
+
+impl std::fmt::Debug for Chr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Chr::Rom(rom) => write!(f, "Rom([{} bytes])", rom.len()),
+            Chr::Ram(ram) => write!(f, "Ram([{} bytes])", ram.len()),
+        }
+    }
+}
+
+/// The contents of a `.nes` file.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Cartridge {
+    pub prg_rom: Vec<u8>,
+    pub chr: Chr,
+    pub mapper: u16,
+    pub mirroring: Mirroring,
+    /// Whether the PRG RAM is battery backed, and so worth saving.
+    pub battery: bool,
+    /// 512 bytes meant to be loaded at $7000.
+    pub trainer: Option<Vec<u8>>,
+}
+
+impl std::fmt::Debug for Cartridge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Cartridge")
+            .field("prg_rom", &format_args!("[{} bytes]", self.prg_rom.len()))
+            .field("chr", &self.chr)
+            .field("mapper", &self.mapper)
+            .field("mirroring", &self.mirroring)
+            .field("battery", &self.battery)
+            .field("trainer", &self.trainer.is_some())
+            .finish()
+    }
+}
+
+impl Cartridge {
+    pub fn from_file(path: impl AsRef<std::path::Path>) -> Result<Cartridge, CartridgeError> {
+        let data = std::fs::read(path)?;
+        Self::from_ines(&data)
+    }
+
+    /// Parses an iNES file. NES 2.0 headers are read as iNES, except for
+    /// the upper bits of the mapper number.
+    pub fn from_ines(data: &[u8]) -> Result<Cartridge, CartridgeError> {
+        let header = data.get(..HEADER_SIZE).ok_or(CartridgeError::Truncated {
+            expected: HEADER_SIZE,
+            actual: data.len(),
+        })?;
+        if header[..4] != MAGIC {
+            return Err(CartridgeError::InvalidMagic);
+        }
+
+        let prg_rom_size = header[4] as usize * PRG_ROM_BANK_SIZE;
+        let chr_rom_size = header[5] as usize * CHR_ROM_BANK_SIZE;
+        let flags_6 = header[6];
+        let flags_7 = header[7];
+        if prg_rom_size == 0 {
+            return Err(CartridgeError::NoPrgRom);
+        }
+
+        let mut mapper = (flags_6 >> 4) as u16;
+        match flags_7 & FLAGS_7_VERSION {
+            FLAGS_7_NES_2_0 => {
+                mapper |= (flags_7 & 0xf0) as u16;
+                mapper |= ((header[8] & 0x0f) as u16) << 8;
+            }
+            // Old dumps often have junk like "DiskDude!" from byte 7 on,
+            // which would give them a bogus mapper number.
+            _ if header[12..].iter().any(|&byte| byte != 0) => {}
+            _ => mapper |= (flags_7 & 0xf0) as u16,
+        }
+
+        let mirroring = if flags_6 & FLAGS_6_FOUR_SCREEN != 0 {
+            Mirroring::FourScreen
+        } else if flags_6 & FLAGS_6_VERTICAL_MIRRORING != 0 {
+            Mirroring::Vertical
+        } else {
+            Mirroring::Horizontal
+        };
+
+        let trainer_size = if flags_6 & FLAGS_6_TRAINER != 0 {
+            TRAINER_SIZE
+        } else {
+            0
+        };
+        let expected = HEADER_SIZE + trainer_size + prg_rom_size + chr_rom_size;
+        if data.len() < expected {
+            return Err(CartridgeError::Truncated {
+                expected,
+                actual: data.len(),
+            });
+        }
+
+        let (trainer, rest) = data[HEADER_SIZE..].split_at(trainer_size);
+        let (prg_rom, rest) = rest.split_at(prg_rom_size);
+        let chr_rom = &rest[..chr_rom_size];
+
+        Ok(Cartridge {
+            prg_rom: prg_rom.to_vec(),
+            chr: if chr_rom.is_empty() {
+                Chr::Ram(vec![0; CHR_RAM_SIZE])
+            } else {
+                Chr::Rom(chr_rom.to_vec())
+            },
+            mapper,
+            mirroring,
+            battery: flags_6 & FLAGS_6_BATTERY != 0,
+            trainer: (!trainer.is_empty()).then(|| trainer.to_vec()),
+        })
+    }
+}
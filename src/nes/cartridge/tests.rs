@@ -0,0 +1,90 @@
+use super::*;
+
+fn header(prg_banks: u8, chr_banks: u8, flags_6: u8, flags_7: u8) -> Vec<u8> {
+    let mut header = b"NES\x1a".to_vec();
+    header.extend([prg_banks, chr_banks, flags_6, flags_7]);
+    header.resize(HEADER_SIZE, 0);
+    header
+}
+
+fn ines(prg_banks: u8, chr_banks: u8, flags_6: u8, flags_7: u8) -> Vec<u8> {
+    let mut data = header(prg_banks, chr_banks, flags_6, flags_7);
+    if flags_6 & FLAGS_6_TRAINER != 0 {
+        data.extend([0x54; TRAINER_SIZE]);
+    }
+    data.extend(vec![0x50; prg_banks as usize * PRG_ROM_BANK_SIZE]);
+    data.extend(vec![0x43; chr_banks as usize * CHR_ROM_BANK_SIZE]);
+    data
+}
+
+#[test]
+fn from_ines() {
+    let cartridge = Cartridge::from_ines(&ines(2, 1, 0b0001_0001, 0b0010_0000)).unwrap();
+    assert_eq!(cartridge.prg_rom, vec![0x50; 0x8000]);
+    assert_eq!(cartridge.chr, Chr::Rom(vec![0x43; 0x2000]));
+    assert_eq!(cartridge.mapper, 0x21);
+    assert_eq!(cartridge.mirroring, Mirroring::Vertical);
+    assert!(!cartridge.battery);
+    assert_eq!(cartridge.trainer, None);
+}
+
+#[test]
+fn from_ines_flags() {
+    let cartridge = Cartridge::from_ines(&ines(1, 0, 0b0000_1110, 0)).unwrap();
+    assert_eq!(cartridge.prg_rom, vec![0x50; 0x4000]);
+    assert_eq!(cartridge.chr, Chr::Ram(vec![0; 0x2000]));
+    assert_eq!(cartridge.mirroring, Mirroring::FourScreen);
+    assert!(cartridge.battery);
+    assert_eq!(cartridge.trainer, Some(vec![0x54; TRAINER_SIZE]));
+
+    let cartridge = Cartridge::from_ines(&ines(1, 0, 0, 0)).unwrap();
+    assert_eq!(cartridge.mirroring, Mirroring::Horizontal);
+}
+
+#[test]
+fn from_ines_mapper() {
+    let mut data = ines(1, 0, 0b0100_0000, 0b0001_1000);
+    data[8] = 0x02;
+    assert_eq!(Cartridge::from_ines(&data).unwrap().mapper, 0x214);
+
+    let mut data = ines(1, 0, 0b0100_0000, 0b0001_0000);
+    data[7..HEADER_SIZE].copy_from_slice(b"DiskDude!");
+    assert_eq!(Cartridge::from_ines(&data).unwrap().mapper, 0x04);
+}
+
+#[test]
+fn from_ines_errors() {
+    assert!(matches!(
+        Cartridge::from_ines(b"NES\x1a"),
+        Err(CartridgeError::Truncated {
+            expected: 16,
+            actual: 4
+        })
+    ));
+    assert!(matches!(
+        Cartridge::from_ines(&[0; HEADER_SIZE]),
+        Err(CartridgeError::InvalidMagic)
+    ));
+    assert!(matches!(
+        Cartridge::from_ines(&header(0, 0, 0, 0)),
+        Err(CartridgeError::NoPrgRom)
+    ));
+
+    let mut data = ines(1, 1, FLAGS_6_TRAINER, 0);
+    data.pop();
+    assert!(matches!(
+        Cartridge::from_ines(&data),
+        Err(CartridgeError::Truncated {
+            expected: 0x6210,
+            actual: 0x620f
+        })
+    ));
+}
+
+#[test]
+fn from_file() {
+    assert!(matches!(
+        Cartridge::from_file("does/not/exist.nes"),
+        Err(CartridgeError::Io(_))
+    ));
+}
@@ -0,0 +1,61 @@
+/// Cartridge hardware, which sees every CPU access from $4020 up. Reads
+/// give `None` where the cartridge doesn't drive the bus.
+pub trait Mapper: std::fmt::Debug {
+    fn read(&mut self, address: u16) -> Option<u8> {
+        self.peek(address)
+    }
+
+    fn write(&mut self, address: u16, value: u8);
+
+    /// Reads without side effects, like `Bus::peek`.
+    fn peek(&self, address: u16) -> Option<u8>;
+}
+
+const PRG_RAM_START: u16 = 0x6000;
+const PRG_RAM_END: u16 = 0x7fff;
+const PRG_ROM_START: u16 = 0x8000;
+
+/// Mapper 0. PRG ROM sits at $8000, mirrored if it's only 16 KiB, and
+/// there's 8 KiB of PRG RAM at $6000.
+pub struct Nrom {
+    prg_rom: Vec<u8>,
+    prg_ram: [u8; 0x2000],
+}
+
+impl std::fmt::Debug for Nrom {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Nrom")
+            .field("prg_rom", &format_args!("[{} bytes]", self.prg_rom.len()))
+            .finish_non_exhaustive()
+    }
+}
+
+impl Nrom {
+    /// `prg_rom` is mirrored to fill $8000–$FFFF, so it must not be empty.
+    pub fn new(prg_rom: Vec<u8>) -> Nrom {
+        assert!(!prg_rom.is_empty(), "NROM needs PRG ROM");
+        Nrom {
+            prg_rom,
+            prg_ram: [0; 0x2000],
+        }
+    }
+}
+
+impl Mapper for Nrom {
+    fn write(&mut self, address: u16, value: u8) {
+        if let PRG_RAM_START..=PRG_RAM_END = address {
+            self.prg_ram[(address - PRG_RAM_START) as usize] = value;
+        }
+    }
+
+    fn peek(&self, address: u16) -> Option<u8> {
+        match address {
+            PRG_RAM_START..=PRG_RAM_END => Some(self.prg_ram[(address - PRG_RAM_START) as usize]),
+            PRG_ROM_START.. => {
+                let offset = (address - PRG_ROM_START) as usize;
+                Some(self.prg_rom[offset % self.prg_rom.len()])
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod bus;
+pub mod cartridge;
+pub mod mapper;